@@ -0,0 +1,114 @@
+//! Criterion benchmarks comparing the generators and algorithms of this crate.
+//!
+//! Run them with `cargo bench --features bench`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use random::bench::simple_ln;
+use random::{
+    Exponential, Gamma, Normal, Poisson, Quantile, Rng, RngTrait, TabulatedQuantile, Uniform,
+};
+
+/// The number of samples generated per iteration.
+const SAMPLES: usize = 10_000;
+
+/// Compares the raw uniform generator with the standard normal generator, one at a time and in batches.
+fn bench_rng(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("rng");
+    group.throughput(Throughput::Elements(SAMPLES as u64));
+
+    let mut rng: Rng = Rng::new_seed(42_u64);
+    group.bench_function("uniform", |bencher| {
+        bencher.iter(|| {
+            for _ in 0_usize..SAMPLES {
+                black_box(rng.generate());
+            }
+        })
+    });
+
+    let mut rng: Rng = Rng::new_seed(42_u64);
+    group.bench_function("standard_normal_polar", |bencher| {
+        bencher.iter(|| {
+            for _ in 0_usize..SAMPLES {
+                black_box(rng.gen_standard_normal());
+            }
+        })
+    });
+
+    let mut rng: Rng = Rng::new_seed(42_u64);
+    let mut buffer: Vec<f64> = vec![0_f64; SAMPLES];
+    group.bench_function("standard_normal_fill", |bencher| {
+        bencher.iter(|| {
+            rng.fill_normal(&mut buffer);
+            black_box(&buffer);
+        })
+    });
+
+    group.finish();
+}
+
+/// Compares the lookup table approximation `simple_ln` with the exact `f64::ln`.
+fn bench_ln(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("ln");
+    group.throughput(Throughput::Elements(SAMPLES as u64));
+
+    let step: f64 = 1_f64 / SAMPLES as f64;
+    group.bench_function("simple_ln", |bencher| {
+        bencher.iter(|| {
+            for i in 1_usize..=SAMPLES {
+                black_box(simple_ln(black_box(i as f64 * step)));
+            }
+        })
+    });
+    group.bench_function("f64_ln", |bencher| {
+        bencher.iter(|| {
+            for i in 1_usize..=SAMPLES {
+                black_box(f64::ln(black_box(i as f64 * step)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+/// Measures the `generate_multiple` method of a selection of distributions.
+fn bench_distributions(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("distributions");
+    group.throughput(Throughput::Elements(SAMPLES as u64));
+
+    let mut uniform: Uniform = Uniform::new(0_f64, 1_f64).unwrap();
+    group.bench_function("uniform", |bencher| {
+        bencher.iter(|| black_box(uniform.generate_multiple(SAMPLES)))
+    });
+
+    let mut normal: Normal = Normal::standard_normal();
+    group.bench_function("normal", |bencher| {
+        bencher.iter(|| black_box(normal.generate_multiple(SAMPLES)))
+    });
+
+    let mut tabulated: TabulatedQuantile = Normal::standard_normal().tabulate(4_096_usize).unwrap();
+    group.bench_function("normal_tabulated", |bencher| {
+        bencher.iter(|| black_box(tabulated.generate_multiple(SAMPLES)))
+    });
+
+    let mut exponential: Exponential = Exponential::new(1_f64).unwrap();
+    group.bench_function("exponential", |bencher| {
+        bencher.iter(|| black_box(exponential.generate_multiple(SAMPLES)))
+    });
+
+    let mut gamma: Gamma = Gamma::new(5_f64, 1_f64).unwrap();
+    group.bench_function("gamma", |bencher| {
+        bencher.iter(|| black_box(gamma.generate_multiple(SAMPLES)))
+    });
+
+    let mut poisson: Poisson = Poisson::new(4_f64).unwrap();
+    group.bench_function("poisson", |bencher| {
+        bencher.iter(|| black_box(poisson.generate_multiple(SAMPLES)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_rng, bench_ln, bench_distributions);
+criterion_main!(benches);
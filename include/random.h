@@ -0,0 +1,53 @@
+/* C interface of the `random` crate, built with `cargo build --release --features ffi`. */
+#ifndef RANDOM_H
+#define RANDOM_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An opaque handle of a distribution. Every handle must be released by `random_free`. */
+typedef struct RandomDistribution RandomDistribution;
+
+/* Constructors seeded by the system. They return NULL if the parameters are invalid. */
+RandomDistribution *random_bernoulli_new(double probability);
+RandomDistribution *random_beta_new(double alpha, double beta);
+RandomDistribution *random_binomial_new(int32_t n, double p);
+RandomDistribution *random_chi_squared_new(int32_t k);
+RandomDistribution *random_exponential_new(double rate);
+RandomDistribution *random_gamma_new(double shape, double scale);
+RandomDistribution *random_geometric_new(double probability);
+RandomDistribution *random_laplace_new(double location, double scale);
+RandomDistribution *random_lognormal_new(double mean, double variance);
+RandomDistribution *random_normal_new(double mean, double variance);
+RandomDistribution *random_poisson_new(double rate);
+RandomDistribution *random_students_t_new(double k);
+RandomDistribution *random_uniform_new(double a, double b);
+RandomDistribution *random_weibull_new(double shape, double scale);
+
+/* Generates a single value. Returns NaN for a NULL handle. */
+double random_generate(RandomDistribution *handle);
+
+/* Writes `len` values into `buffer`. */
+void random_fill(RandomDistribution *handle, double *buffer, size_t len);
+
+/* Returns the seed, or 0 for a NULL handle. */
+uint64_t random_seed(const RandomDistribution *handle);
+
+/* Sets the seed of the random number generator. */
+void random_set_seed(RandomDistribution *handle, uint64_t seed);
+
+/* Restarts the random number generator from its seed. */
+void random_restart(RandomDistribution *handle);
+
+/* Releases a handle. NULL is ignored. */
+void random_free(RandomDistribution *handle);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* RANDOM_H */
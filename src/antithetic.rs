@@ -0,0 +1,110 @@
+//! This module contains the implementation of the `AntitheticRng` struct and its methods.
+
+use crate::rng::{Rng, UniformSource};
+
+/// A uniformly distributed random number generator producing antithetic pairs.
+///
+/// Every second number is `1 - u`, where `u` is the number before.
+/// Feeding these pairs into a monotone transformation, e.g. through `Quantile::sample`,
+/// results in pairs of negatively correlated samples.
+/// Averaging over such pairs reduces the variance of Monte Carlo estimates of monotone functions (antithetic variates).
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate the first number of every pair.
+/// * `pending` - The first number of the current pair, if its partner has not been returned yet.
+///
+/// # Notes
+///
+/// The variance reduction relies on consecutive samples forming a pair.
+/// Therefore, the number of samples should be even and each sample should consume exactly one uniform number.
+#[derive(Debug, Clone)]
+pub struct AntitheticRng {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The first number of the current pair.
+    pending: Option<f64>,
+}
+
+impl AntitheticRng {
+    /// Creates a new `AntitheticRng` instance using the system time as the seed.
+    ///
+    /// # Returns
+    ///
+    /// A new `AntitheticRng` instance.
+    pub fn new() -> AntitheticRng {
+        AntitheticRng::new_seed(Rng::new().seed())
+    }
+
+    /// Creates a new `AntitheticRng` instance using a specified seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` value used to initialize the underlying `Rng`.
+    ///
+    /// # Returns
+    ///
+    /// A new `AntitheticRng` instance initialized with the given seed.
+    pub fn new_seed(seed: u64) -> AntitheticRng {
+        AntitheticRng {
+            rng: Rng::new_seed(seed),
+            pending: None,
+        }
+    }
+
+    /// Generates a uniformly distributed random number in the range [0, 1].
+    ///
+    /// # Returns
+    ///
+    /// A random `u` for the first number of a pair and `1 - u` for the second one.
+    pub fn generate(&mut self) -> f64 {
+        match self.pending.take() {
+            Some(uniform) => 1_f64 - uniform,
+            None => {
+                let uniform: f64 = self.rng.generate();
+                self.pending = Some(uniform);
+                uniform
+            }
+        }
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number and starts a new pair.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng.set_seed(seed);
+        self.pending = None;
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.rng.restart();
+        self.pending = None;
+    }
+}
+
+impl Default for AntitheticRng {
+    /// Creates a new `AntitheticRng` instance using the system time as the seed.
+    fn default() -> Self {
+        AntitheticRng::new()
+    }
+}
+
+impl UniformSource for AntitheticRng {
+    /// Returns the next uniformly distributed number using `AntitheticRng::generate`.
+    fn next_uniform(&mut self) -> f64 {
+        self.generate()
+    }
+}
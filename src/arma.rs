@@ -0,0 +1,268 @@
+//! This module contains the implementation of the `Ar1` and `Arma` structs and their methods.
+
+use crate::normal::Normal;
+use crate::rng::RngTrait;
+use crate::rng_error::RngError;
+
+/// A struct for generating autoregressive time series of order 1, AR(1).
+///
+/// The series is defined by
+/// ```text
+/// X(t) = φ X(t - 1) + ε(t)
+/// ```
+/// where the innovations `ε(t)` are independently Normal distributed with mean 0 and variance `σ²`.
+/// The series starts in its stationary distribution, which is Normal with mean 0 and variance `σ² / (1 - φ²)`.
+/// Therefore, no burn-in is needed.
+///
+/// # Fields
+///
+/// * `noise` - The Normal distribution of the innovations.
+/// * `phi` - The autoregressive coefficient (φ). Must be between -1 and 1 (exclusive).
+/// * `state` - The last generated value, or `None` if the series has not started yet.
+pub struct Ar1 {
+    /// The distribution of the innovations.
+    noise: Normal,
+
+    /// The autoregressive coefficient (φ).
+    phi: f64,
+
+    /// The last generated value.
+    state: Option<f64>,
+}
+
+impl Ar1 {
+    /// Creates a new `Ar1` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `phi` - A `f64` representing the autoregressive coefficient (φ). It must be between -1 and 1 (exclusive).
+    /// * `noise_variance` - A `f64` representing the variance (σ²) of the innovations. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Ar1)` - Returns an instance of `Ar1` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if `phi` is not between -1 and 1 or a `PositiveError` if `noise_variance` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(phi: f64, noise_variance: f64) -> Result<Ar1, RngError> {
+        RngError::check_finite(phi)?;
+
+        // The series is only stationary for |φ| < 1
+        if phi.abs() >= 1_f64 {
+            return Err(RngError::interval(phi, -1_f64, 1_f64));
+        }
+
+        Ok(Ar1 {
+            noise: Normal::new(0_f64, noise_variance)?,
+            phi,
+            state: None,
+        })
+    }
+
+    /// Generates the next value of the series.
+    ///
+    /// The first value is drawn from the stationary distribution.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the next value of the series.
+    pub fn generate(&mut self) -> f64 {
+        let value: f64 = match self.state {
+            Some(previous) => self.phi * previous + self.noise.generate(),
+            None => self.noise.generate() / (1_f64 - self.phi * self.phi).sqrt(),
+        };
+        self.state = Some(value);
+        value
+    }
+
+    /// Generates the next values of the series.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of values.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `n`.
+    pub fn series(&mut self, n: usize) -> Vec<f64> {
+        (0_usize..n).map(|_| self.generate()).collect()
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.noise.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number and restarts the series.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.noise.set_seed(seed);
+        self.state = None;
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed and restarts the series.
+    pub fn restart(&mut self) {
+        self.noise.restart();
+        self.state = None;
+    }
+}
+
+/// A struct for generating autoregressive moving-average time series, ARMA(p, q).
+///
+/// The series is defined by
+/// ```text
+/// X(t) = φ₁ X(t - 1) + ... + φₚ X(t - p) + ε(t) + θ₁ ε(t - 1) + ... + θ_q ε(t - q)
+/// ```
+/// where the innovations `ε(t)` are independently Normal distributed with mean 0 and variance `σ²`.
+///
+/// # Fields
+///
+/// * `noise` - The Normal distribution of the innovations.
+/// * `ar` - The autoregressive coefficients `φ₁, ..., φₚ`.
+/// * `ma` - The moving-average coefficients `θ₁, ..., θ_q`.
+/// * `values` - The last `p` values of the series, the most recent first.
+/// * `innovations` - The last `q` innovations, the most recent first.
+///
+/// # Notes
+///
+/// The series starts with all past values and innovations equal to 0.
+/// To forget this initial state, the first `BURN_IN` values are discarded when the series is (re)started.
+/// The coefficients are not checked for stationarity. A non-stationary series will diverge.
+pub struct Arma {
+    /// The distribution of the innovations.
+    noise: Normal,
+
+    /// The autoregressive coefficients.
+    ar: Vec<f64>,
+
+    /// The moving-average coefficients.
+    ma: Vec<f64>,
+
+    /// The last `p` values of the series.
+    values: Vec<f64>,
+
+    /// The last `q` innovations.
+    innovations: Vec<f64>,
+}
+
+impl Arma {
+    /// The number of values discarded when the series is (re)started.
+    const BURN_IN: usize = 1_000_usize;
+}
+
+impl Arma {
+    /// Creates a new `Arma` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ar` - A `Vec<f64>` of the autoregressive coefficients `φ₁, ..., φₚ`. It may be empty.
+    /// * `ma` - A `Vec<f64>` of the moving-average coefficients `θ₁, ..., θ_q`. It may be empty.
+    /// * `noise_variance` - A `f64` representing the variance (σ²) of the innovations. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Arma)` - Returns an instance of `Arma` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `noise_variance` is less than or equal to 0.
+    pub fn new(ar: Vec<f64>, ma: Vec<f64>, noise_variance: f64) -> Result<Arma, RngError> {
+        let mut arma: Arma = Arma {
+            noise: Normal::new(0_f64, noise_variance)?,
+            values: vec![0_f64; ar.len()],
+            innovations: vec![0_f64; ma.len()],
+            ar,
+            ma,
+        };
+        arma.burn_in();
+
+        Ok(arma)
+    }
+
+    /// Generates the next value of the series.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the next value of the series.
+    pub fn generate(&mut self) -> f64 {
+        let innovation: f64 = self.noise.generate();
+
+        let autoregression: f64 = self
+            .ar
+            .iter()
+            .zip(self.values.iter())
+            .map(|(phi, x)| phi * x)
+            .sum();
+        let moving_average: f64 = self
+            .ma
+            .iter()
+            .zip(self.innovations.iter())
+            .map(|(theta, e)| theta * e)
+            .sum();
+        let value: f64 = autoregression + innovation + moving_average;
+
+        if !self.values.is_empty() {
+            self.values.rotate_right(1_usize);
+            self.values[0] = value;
+        }
+        if !self.innovations.is_empty() {
+            self.innovations.rotate_right(1_usize);
+            self.innovations[0] = innovation;
+        }
+        value
+    }
+
+    /// Generates the next values of the series.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of values.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `n`.
+    pub fn series(&mut self, n: usize) -> Vec<f64> {
+        (0_usize..n).map(|_| self.generate()).collect()
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.noise.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number and restarts the series.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.noise.set_seed(seed);
+        self.burn_in();
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed and restarts the series.
+    pub fn restart(&mut self) {
+        self.noise.restart();
+        self.burn_in();
+    }
+
+    /// Clears the history and discards the first `BURN_IN` values.
+    fn burn_in(&mut self) {
+        self.values.fill(0_f64);
+        self.innovations.fill(0_f64);
+
+        for _ in 0_usize..Self::BURN_IN {
+            self.generate();
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! This module contains functions to render samples as plain text plots.
+//!
+//! They are meant for a quick look at a distribution in the terminal during development,
+//! e.g. through `RngTrait::preview`, without having to export the samples to a plotting tool.
+
+/// The characters used by `render_sparkline`, from the lowest to the highest bar.
+const SPARK_CHARACTERS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Renders a histogram of samples as ASCII art.
+///
+/// The samples are binned into `width` bins of equal width between the smallest and the largest sample.
+/// Every bin is drawn as a column of `#` whose height is proportional to the number of samples in it,
+/// the highest column being `height` characters high.
+/// Below the columns an axis with the smallest and the largest sample is drawn.
+///
+/// # Arguments
+///
+/// * `samples` - A slice of `f64` samples.
+/// * `width` - A `usize` representing the number of columns.
+/// * `height` - A `usize` representing the number of rows of the columns.
+///
+/// # Returns
+///
+/// A `String` with `height + 2` lines, or an empty `String` if there are no samples, `width` or `height` is 0.
+pub fn render_ascii_histogram(samples: &[f64], width: usize, height: usize) -> String {
+    if samples.is_empty() || width == 0_usize || height == 0_usize {
+        return String::new();
+    }
+
+    let (low, high) = range(samples);
+    let counts: Vec<usize> = bin(samples, low, high, width);
+    let max_count: usize = counts.iter().copied().max().unwrap_or(0_usize);
+
+    // Heights of the columns in rows, at least 1 for non-empty bins
+    let heights: Vec<usize> = counts
+        .iter()
+        .map(|&count| {
+            if count == 0_usize {
+                0_usize
+            } else {
+                ((count as f64 / max_count as f64 * height as f64).round() as usize).max(1_usize)
+            }
+        })
+        .collect();
+
+    let mut output: String = String::new();
+    for row in (1_usize..=height).rev() {
+        let line: String = heights
+            .iter()
+            .map(|&h| if h >= row { '#' } else { ' ' })
+            .collect();
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output.push_str(&"-".repeat(width));
+    output.push('\n');
+
+    let low_label: String = format!("{:.4}", low);
+    let high_label: String = format!("{:.4}", high);
+    let padding: usize = width
+        .saturating_sub(low_label.len() + high_label.len())
+        .max(1_usize);
+    output.push_str(&low_label);
+    output.push_str(&" ".repeat(padding));
+    output.push_str(&high_label);
+    output.push('\n');
+    output
+}
+
+/// Renders a histogram of samples as a single line sparkline using block characters.
+///
+/// # Arguments
+///
+/// * `samples` - A slice of `f64` samples.
+/// * `width` - A `usize` representing the number of bins, i.e. characters.
+///
+/// # Returns
+///
+/// A `String` of `width` characters, or an empty `String` if there are no samples or `width` is 0.
+/// Empty bins are drawn as spaces.
+pub fn render_sparkline(samples: &[f64], width: usize) -> String {
+    if samples.is_empty() || width == 0_usize {
+        return String::new();
+    }
+
+    let (low, high) = range(samples);
+    let counts: Vec<usize> = bin(samples, low, high, width);
+    let max_count: usize = counts.iter().copied().max().unwrap_or(0_usize);
+    let levels: usize = SPARK_CHARACTERS.len();
+
+    counts
+        .into_iter()
+        .map(|count| {
+            if count == 0_usize {
+                ' '
+            } else {
+                let level: usize = count * levels / (max_count + 1_usize);
+                SPARK_CHARACTERS[level.min(levels - 1_usize)]
+            }
+        })
+        .collect()
+}
+
+/// Returns the smallest and the largest sample, widening the range if all samples are equal.
+fn range(samples: &[f64]) -> (f64, f64) {
+    let low: f64 = samples.iter().copied().fold(f64::INFINITY, f64::min);
+    let high: f64 = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    if high > low {
+        (low, high)
+    } else {
+        (low - 0.5_f64, low + 0.5_f64)
+    }
+}
+
+/// Counts the samples in `n_bins` bins of equal width between `low` and `high`.
+fn bin(samples: &[f64], low: f64, high: f64, n_bins: usize) -> Vec<usize> {
+    let width: f64 = (high - low) / n_bins as f64;
+    let mut counts: Vec<usize> = vec![0_usize; n_bins];
+
+    for &sample in samples {
+        let index: usize = (((sample - low) / width) as usize).min(n_bins - 1_usize);
+        counts[index] += 1_usize;
+    }
+    counts
+}
@@ -0,0 +1,260 @@
+//! This module contains the implementation of the `AuditLog` struct and its methods.
+
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A domain separation constant for the seed commitment.
+const COMMITMENT_KEY: u64 = 0xA0761D6478BD642F_u64;
+
+/// A single entry of an `AuditLog`.
+///
+/// # Fields
+///
+/// * `commitment` - The commitment to the seed of the log.
+/// * `counter` - The position of the raw output in the output sequence of the generator, starting at 0.
+/// * `raw` - The raw `u64` output of the generator.
+/// * `range` - The number of possible results of the draw.
+/// * `result` - The mapped result, between 0 and `range - 1`.
+/// * `digest` - The chained digest of all entries up to and including this one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditRecord {
+    /// The commitment to the seed.
+    pub commitment: u64,
+
+    /// The position of the raw output.
+    pub counter: u64,
+
+    /// The raw output of the generator.
+    pub raw: u64,
+
+    /// The number of possible results.
+    pub range: u64,
+
+    /// The mapped result.
+    pub result: u64,
+
+    /// The chained digest.
+    pub digest: u64,
+}
+
+/// A struct for drawing uniformly distributed integers while recording every draw in a verifiable log.
+///
+/// This is meant for games and lotteries, where the fairness of the draws has to be proven afterwards.
+/// Before drawing, the operator publishes the `commitment` of the secret seed.
+/// Every draw appends an `AuditRecord` with the position and the value of the raw generator output and the mapped result.
+/// Afterwards the seed is revealed and anyone can check with `AuditLog::verify` that
+///
+/// * the seed matches the published commitment,
+/// * every raw output is the output of the generator at the recorded position,
+/// * no output was skipped, except the ones rejected to keep the results unbiased,
+/// * every result is the correct mapping of its raw output,
+/// * the digests are chained correctly, so no record was removed, inserted or reordered.
+///
+/// The last digest is a fingerprint of the whole log, which can be published to detect later changes.
+///
+/// # Fields
+///
+/// * `rng` - The `Rng` generating the raw outputs.
+/// * `commitment` - The commitment to the seed.
+/// * `counter` - The number of raw outputs generated so far.
+/// * `records` - The records of all draws.
+///
+/// # Notes
+///
+/// The commitment and the digests are computed with a bijective 64 bit mixing function and are **not cryptographically secure**.
+/// The commitment is binding, since different seeds always have different commitments,
+/// but it does not hide the seed and neither do the raw outputs of the Linear Congruential Generator.
+/// The proof of fairness therefore relies on publishing the commitment before the draws take place.
+pub struct AuditLog {
+    /// The random number generator.
+    rng: Rng,
+
+    /// The commitment to the seed.
+    commitment: u64,
+
+    /// The number of raw outputs generated so far.
+    counter: u64,
+
+    /// The records of all draws.
+    records: Vec<AuditRecord>,
+}
+
+impl AuditLog {
+    /// Creates a new, empty `AuditLog` instance with a given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` representing the seed. It should be kept secret until all draws are done.
+    ///
+    /// # Returns
+    ///
+    /// An `AuditLog` instance.
+    pub fn new(seed: u64) -> AuditLog {
+        AuditLog {
+            rng: Rng::new_seed(seed),
+            commitment: Self::commit(seed),
+            counter: 0_u64,
+            records: Vec::new(),
+        }
+    }
+
+    /// Computes the commitment to a seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` representing the seed.
+    ///
+    /// # Returns
+    ///
+    /// The commitment as a `u64`.
+    pub fn commit(seed: u64) -> u64 {
+        Rng::mix64(Rng::mix64(seed ^ COMMITMENT_KEY))
+    }
+
+    /// Returns the commitment to the seed of the log.
+    ///
+    /// # Returns
+    ///
+    /// The commitment as a `u64`.
+    pub fn commitment(&self) -> u64 {
+        self.commitment
+    }
+
+    /// Returns the seed of the log.
+    ///
+    /// # Returns
+    ///
+    /// The seed as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Returns the records of all draws.
+    ///
+    /// # Returns
+    ///
+    /// A slice of `AuditRecord` in the order of the draws.
+    pub fn records(&self) -> &[AuditRecord] {
+        &self.records
+    }
+
+    /// Returns the digest of the whole log.
+    ///
+    /// # Returns
+    ///
+    /// The digest of the last record as a `u64`, or the commitment if nothing was drawn yet.
+    pub fn digest(&self) -> u64 {
+        self.records
+            .last()
+            .map_or(self.commitment, |record| record.digest)
+    }
+
+    /// Draws a uniformly distributed integer and records it.
+    ///
+    /// Raw outputs in the top `2^64 mod range` values are rejected, so the result
+    /// ```text
+    /// result = raw mod range
+    /// ```
+    /// is exactly uniformly distributed.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - A `u64` representing the number of possible results, e.g. 6 for a die. It must be positive.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - Returns the result between 0 and `range - 1`.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `range` is 0.
+    pub fn draw(&mut self, range: u64) -> Result<u64, RngError> {
+        RngError::check_positive(range as f64)?;
+        let zone: u64 = Self::zone(range);
+
+        let mut raw: u64 = self.next_raw();
+        while raw > zone {
+            raw = self.next_raw();
+        }
+
+        let result: u64 = raw % range;
+        let counter: u64 = self.counter - 1_u64;
+        self.records.push(AuditRecord {
+            commitment: self.commitment,
+            counter,
+            raw,
+            range,
+            result,
+            digest: Self::chain(self.digest(), counter, raw, range, result),
+        });
+        Ok(result)
+    }
+
+    /// Verifies records against a revealed seed.
+    ///
+    /// The whole sequence of raw outputs is regenerated from the seed,
+    /// so every check listed in the description of `AuditLog` is performed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` representing the revealed seed.
+    /// * `records` - A slice of `AuditRecord` in the order of the draws.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If all records are valid.
+    /// * `Err(usize)` - The index of the first invalid record.
+    pub fn verify(seed: u64, records: &[AuditRecord]) -> Result<(), usize> {
+        let mut replay: AuditLog = AuditLog::new(seed);
+
+        for (index, record) in records.iter().enumerate() {
+            if record.range == 0_u64 {
+                return Err(index);
+            }
+            let zone: u64 = Self::zone(record.range);
+
+            // All skipped outputs must have been rejected
+            while replay.counter < record.counter {
+                if replay.next_raw() <= zone {
+                    return Err(index);
+                }
+            }
+
+            let expected: AuditRecord = AuditRecord {
+                commitment: replay.commitment,
+                counter: replay.counter,
+                raw: replay.next_raw(),
+                range: record.range,
+                result: record.raw % record.range,
+                digest: Self::chain(
+                    replay.digest(),
+                    record.counter,
+                    record.raw,
+                    record.range,
+                    record.result,
+                ),
+            };
+            if *record != expected || record.raw > zone {
+                return Err(index);
+            }
+            replay.records.push(expected);
+        }
+        Ok(())
+    }
+
+    /// Generates the next raw output and advances the counter.
+    fn next_raw(&mut self) -> u64 {
+        self.counter += 1_u64;
+        self.rng.next_u64()
+    }
+
+    /// Returns the largest raw output accepted for a given range.
+    fn zone(range: u64) -> u64 {
+        // 2^64 mod range values at the top are rejected
+        u64::MAX - (u64::MAX % range + 1_u64) % range
+    }
+
+    /// Chains the fields of a record to the digest of the previous one.
+    fn chain(previous: u64, counter: u64, raw: u64, range: u64, result: u64) -> u64 {
+        [counter, raw, range, result]
+            .into_iter()
+            .fold(previous, |digest, value| Rng::mix64(digest ^ value))
+    }
+}
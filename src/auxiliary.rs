@@ -1,5 +1,11 @@
 //! This module contains a random assortment of auxiliary functions.
 
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use crate::math;
+use crate::rng_error::RngError;
+use crate::special::erfc;
+
 /// Rounds a floating-point number to the specified number of decimal places.
 ///
 /// This function multiplies the input `number` by 10 raised to the power of `decimals`,
@@ -40,9 +46,9 @@ pub fn round(number: f64, decimals: i32) -> f64 {
 pub fn simple_ln(number: f64) -> f64 {
     // Fall back to true ln if out of range
     if number < lookup_table::LN_MIN {
-        return number.ln();
+        return math::ln(number);
     } else if number > lookup_table::LN_MAX {
-        return number.ln();
+        return math::ln(number);
     }
 
     // Find position in lookup table
@@ -58,6 +64,253 @@ pub fn simple_ln(number: f64) -> f64 {
     y0 + (y1 - y0) * frac
 }
 
+/// Calculates the arithmetic mean of a slice.
+///
+/// # Arguments
+///
+/// * `samples` - A slice of `f64` values.
+///
+/// # Returns
+///
+/// A `f64` representing the mean of the values. For an empty slice this is `NaN`.
+pub fn mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+/// Calculates the (biased) variance of a slice around a given mean.
+///
+/// This uses the maximum likelihood estimator
+/// ```text
+/// 1/n Σ (x_i - mean)²
+/// ```
+///
+/// # Arguments
+///
+/// * `samples` - A slice of `f64` values.
+/// * `mean` - The mean of the values, usually calculated with `mean`.
+///
+/// # Returns
+///
+/// A `f64` representing the variance of the values.
+pub fn variance(samples: &[f64], mean: f64) -> f64 {
+    samples.iter().map(|x| (x - mean).powi(2_i32)).sum::<f64>() / samples.len() as f64
+}
+
+/// Calculates the density φ(x) of the standard Normal distribution.
+///
+/// # Arguments
+///
+/// * `x` - A `f64` value.
+///
+/// # Returns
+///
+/// A `f64` representing `exp(-x²/2) / sqrt(2π)`.
+pub fn standard_normal_pdf(x: f64) -> f64 {
+    math::exp(ln_standard_normal_pdf(x))
+}
+
+/// Calculates the logarithm of the density φ(x) of the standard Normal distribution.
+///
+/// Unlike `standard_normal_pdf(x).ln()` this does not underflow to negative infinity for large `|x|`.
+///
+/// # Arguments
+///
+/// * `x` - A `f64` value.
+///
+/// # Returns
+///
+/// A `f64` representing `-x²/2 - ln(sqrt(2π))`.
+pub fn ln_standard_normal_pdf(x: f64) -> f64 {
+    -0.5_f64 * x * x - LN_SQRT_2PI
+}
+
+/// Calculates the cumulative distribution function Φ(x) of the standard Normal distribution.
+///
+/// It is evaluated with the complementary error function
+/// ```text
+/// Φ(x) = erfc(-x / sqrt(2)) / 2
+/// ```
+/// so the lower tail keeps its relative accuracy instead of cancelling to 0.
+///
+/// # Arguments
+///
+/// * `x` - A `f64` value.
+///
+/// # Returns
+///
+/// A `f64` approximating Φ(x) with a relative error of about 1e-15.
+pub fn standard_normal_cdf(x: f64) -> f64 {
+    0.5_f64 * erfc(-x * FRAC_1_SQRT_2)
+}
+
+/// Calculates the quantile function Φ⁻¹(p) of the standard Normal distribution.
+///
+/// This uses the rational approximation of Acklam,
+/// with one rational function for the central region `0.02425 <= p <= 0.97575` and one for each tail.
+/// Its relative error of 1.15e-9 is reduced to the accuracy of `standard_normal_cdf` by one step of Halley's method
+/// ```text
+/// u = (Φ(x) - p) / φ(x)
+/// x = x - u / (1 + x u / 2)
+/// ```
+///
+/// # Arguments
+///
+/// * `p` - A `f64` between 0 and 1.
+///
+/// # Returns
+///
+/// A `f64` approximating Φ⁻¹(p) with a relative error of about 1e-15.
+/// For `p = 0` and `p = 1` this is negative and positive infinity and for `p` outside of [0, 1] it is `NaN`.
+pub fn standard_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1_f64,
+        2.209460984245205e2_f64,
+        -2.759285104469687e2_f64,
+        1.38357751867269e2_f64,
+        -3.066479806614716e1_f64,
+        2.506628277459239_f64,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1_f64,
+        1.615858368580409e2_f64,
+        -1.556989798598866e2_f64,
+        6.680131188771972e1_f64,
+        -1.328068155288572e1_f64,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3_f64,
+        -3.223964580411365e-1_f64,
+        -2.400758277161838_f64,
+        -2.549732539343734_f64,
+        4.374664141464968_f64,
+        2.938163982698783_f64,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3_f64,
+        3.224671290700398e-1_f64,
+        2.445134137142996_f64,
+        3.754408661907416_f64,
+    ];
+    const P_LOW: f64 = 0.02425_f64;
+
+    if !(0_f64..=1_f64).contains(&p) {
+        return f64::NAN;
+    } else if p == 0_f64 {
+        return f64::NEG_INFINITY;
+    } else if p == 1_f64 {
+        return f64::INFINITY;
+    }
+
+    // Tails, using the symmetry Φ⁻¹(1 - p) = -Φ⁻¹(p) for the upper one
+    let tail = |q: f64| -> f64 {
+        let q: f64 = (-2_f64 * math::ln(q)).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1_f64)
+    };
+
+    let x: f64 = if p < P_LOW {
+        tail(p)
+    } else if p > 1_f64 - P_LOW {
+        -tail(1_f64 - p)
+    } else {
+        let q: f64 = p - 0.5_f64;
+        let r: f64 = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1_f64)
+    };
+
+    let u: f64 = (standard_normal_cdf(x) - p) / standard_normal_pdf(x);
+    x - u / (1_f64 + 0.5_f64 * x * u)
+}
+
+/// Fits a three-point estimate to a location-scale family with one shape parameter `m` between 0 and 1.
+///
+/// The quantile function of the family is assumed to be `Q(p) = a + w g(p, m)`.
+/// The ratio `(Q(0.5) - Q(0.1)) / (Q(0.9) - Q(0.1))` only depends on `m`,
+/// so `m` is found by bisection, assuming that the ratio is increasing in `m`.
+/// Then `w` and `a` follow from the P10 and P90 values.
+///
+/// # Arguments
+///
+/// * `p10`, `p50`, `p90` - The P10, P50 and P90 estimates. They must be strictly increasing.
+/// * `g` - The standardized quantile function `g(p, m)`.
+///
+/// # Returns
+///
+/// * `Ok((f64, f64, f64))` - The location `a`, the width `w` and the shape `m`.
+/// * `Err(RngError)` - Returns an `OrderError` if the estimates are not increasing or an `IntervalError` if the ratio can not be reached by the family.
+pub(crate) fn fit_three_point<G: Fn(f64, f64) -> f64>(
+    p10: f64,
+    p50: f64,
+    p90: f64,
+    g: G,
+) -> Result<(f64, f64, f64), RngError> {
+    RngError::check_order(p10, p50)?;
+    RngError::check_order(p50, p90)?;
+
+    let ratio =
+        |m: f64| -> f64 { (g(0.5_f64, m) - g(0.1_f64, m)) / (g(0.9_f64, m) - g(0.1_f64, m)) };
+    let target: f64 = (p50 - p10) / (p90 - p10);
+    RngError::check_interval(target, ratio(0_f64), ratio(1_f64))?;
+
+    let mut low: f64 = 0_f64;
+    let mut high: f64 = 1_f64;
+    while high - low > BISECTION_TOLERANCE {
+        let middle: f64 = 0.5_f64 * (low + high);
+        if ratio(middle) < target {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    let m: f64 = 0.5_f64 * (low + high);
+    let width: f64 = (p90 - p10) / (g(0.9_f64, m) - g(0.1_f64, m));
+    Ok((p10 - width * g(0.1_f64, m), width, m))
+}
+
+/// Calculates the Cholesky decomposition `A = L Lᵀ` of a symmetric positive definite matrix.
+///
+/// Only the lower triangle of the matrix is read.
+///
+/// # Arguments
+///
+/// * `matrix` - A square matrix `A` in row-major order.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Vec<f64>>)` - The lower triangular matrix `L` in row-major order.
+/// * `Err(RngError)` - Returns a `PositiveError` if the matrix is not positive definite.
+pub fn cholesky(matrix: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, RngError> {
+    let n: usize = matrix.len();
+    let mut lower: Vec<Vec<f64>> = vec![vec![0_f64; n]; n];
+
+    for i in 0_usize..n {
+        for j in 0_usize..=i {
+            let dot: f64 = lower[i][..j]
+                .iter()
+                .zip(lower[j][..j].iter())
+                .map(|(x, y)| x * y)
+                .sum();
+            let value: f64 = matrix[i][j] - dot;
+
+            if i == j {
+                RngError::check_positive(value)?;
+                lower[i][i] = value.sqrt();
+            } else {
+                lower[i][j] = value / lower[j][j];
+            }
+        }
+    }
+    Ok(lower)
+}
+
+/// The width of the interval at which the bisections stop.
+pub(crate) const BISECTION_TOLERANCE: f64 = 1e-15_f64;
+
+/// The natural logarithm of `sqrt(2π)`.
+pub(crate) const LN_SQRT_2PI: f64 = 0.918_938_533_204_672_7_f64;
+
 /// This module contains the constants for the approximation of the natural logarithm.
 ///
 /// The values of the table were calculated using the following function
@@ -0,0 +1,62 @@
+//! This module contains helpers for measuring the speed of the distributions.
+//!
+//! It is only compiled with the `bench` feature and is used by the criterion benchmarks in `benches/`.
+//! The helpers are public so that users can compare the algorithms on their own hardware.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use crate::rng::RngTrait;
+
+pub use crate::auxiliary::simple_ln;
+
+/// Measures how many samples per second a distribution generates.
+///
+/// This generates `n` samples using the `generate_multiple` method and divides `n` by the elapsed time.
+/// The samples are passed through `black_box` so that the compiler can not optimize the generation away.
+///
+/// # Arguments
+///
+/// * `distribution` - A mutable reference to any distribution implementing `RngTrait`.
+/// * `n` - A `usize` representing the number of samples to generate.
+///
+/// # Returns
+///
+/// A `f64` representing the number of generated samples per second.
+///
+/// # Notes
+///
+/// The result includes the allocation of the `Vec` returned by `generate_multiple`.
+/// For small `n` the measurement is dominated by noise, so `n` should be at least in the order of 100 000.
+pub fn throughput<D: RngTrait>(distribution: &mut D, n: usize) -> f64 {
+    let start: Instant = Instant::now();
+    black_box(distribution.generate_multiple(n));
+    let elapsed: f64 = start.elapsed().as_secs_f64();
+
+    n as f64 / elapsed
+}
+
+/// Measures how many evaluations per second a function of one `f64` performs.
+///
+/// This is used to compare approximations such as `simple_ln` against their exact counterparts like `f64::ln`.
+/// The function is evaluated on `n` equidistant points in (0, 1].
+///
+/// # Arguments
+///
+/// * `function` - The function to evaluate.
+/// * `n` - A `usize` representing the number of evaluations.
+///
+/// # Returns
+///
+/// A `f64` representing the number of evaluations per second.
+pub fn function_throughput<F: Fn(f64) -> f64>(function: F, n: usize) -> f64 {
+    let step: f64 = 1_f64 / n as f64;
+
+    let start: Instant = Instant::now();
+    for i in 1_usize..=n {
+        black_box(function(black_box(i as f64 * step)));
+    }
+    let elapsed: f64 = start.elapsed().as_secs_f64();
+
+    n as f64 / elapsed
+}
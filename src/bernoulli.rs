@@ -1,6 +1,13 @@
 //! This module contains the implementation of the `Bernoulli` struct and its methods.
 
+use std::ops::RangeInclusive;
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::mean;
+use crate::discrete_distribution::DiscreteDistribution;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -15,6 +22,7 @@ use crate::rng_error::RngError;
 ///
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `probability` - The probability of success. Must be between 0 and 1.
+#[derive(Debug, Clone)]
 pub struct Bernoulli {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -24,6 +32,9 @@ pub struct Bernoulli {
 }
 
 auto_rng_trait!(Bernoulli);
+auto_builder!(Bernoulli, BernoulliBuilder { probability: f64 });
+auto_params!(Bernoulli { probability: f64 });
+auto_display!(Bernoulli { "p" = probability });
 
 impl Bernoulli {
     /// Creates a new `Bernoulli` instance with a specified probability.
@@ -38,9 +49,9 @@ impl Bernoulli {
     /// # Returns
     ///
     /// * `Ok(Bernoulli)` - Returns an instance of `Bernoulli` if the probability is valid.
-    /// * `Err(RngError)` - Returns an `IntervalError` if the probability is outside the range [0, 1].
+    /// * `Err(RngError)` - Returns a `ProbabilityError` if the probability is outside the range [0, 1].
     pub fn new(probability: f64) -> Result<Bernoulli, RngError> {
-        RngError::check_interval(probability, 0_f64, 1_f64)?;
+        RngError::check_probability(probability)?;
 
         Ok(Bernoulli {
             rng: Rng::new(),
@@ -71,11 +82,47 @@ impl Bernoulli {
     /// * `1` - If the randomly generated number is less than the specified probability.
     /// * `0` - Otherwise.
     pub fn generate(&mut self) -> u32 {
-        if self.rng.generate() < self.probability {
-            1_u32
-        } else {
-            0_u32
+        u32::from(self.generate_bool())
+    }
+
+    /// Generates a random value from the Bernoulli distribution as a `bool`.
+    ///
+    /// This consumes the same uniformly distributed number as `generate`, so both generate the same sequence.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the randomly generated number is less than the specified probability.
+    /// * `false` - Otherwise.
+    pub fn generate_bool(&mut self) -> bool {
+        self.rng.generate() < self.probability
+    }
+
+    /// Generates a given number of random values from the Bernoulli distribution as `bool`s, e.g. for random bit masks.
+    ///
+    /// For a fair coin with the probability 0.5, every raw `u64` of the generator provides 64 values,
+    /// starting with the least significant bit.
+    /// The raw values only use the high bits of the generator, see `Rng::next_u64`.
+    /// Otherwise, every value is generated by `generate_bool`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of values.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `n` random `bool`s, which are `true` with the specified probability.
+    pub fn bits(&mut self, n: usize) -> Vec<bool> {
+        if self.probability != 0.5_f64 {
+            return (0_usize..n).map(|_| self.generate_bool()).collect();
         }
+
+        let mut bits: Vec<bool> = Vec::with_capacity(n);
+        while bits.len() < n {
+            let word: u64 = self.rng.next_u64();
+            let count: usize = (n - bits.len()).min(u64::BITS as usize);
+            bits.extend((0_usize..count).map(|i| (word >> i) & 1_u64 == 1_u64));
+        }
+        bits
     }
 
     /// Changes the probability of the Bernoulli distribution.
@@ -90,11 +137,58 @@ impl Bernoulli {
     /// # Returns
     ///
     /// * `Ok(())` - if the probability is valid and the update is successful.
-    /// * `Err(RngError)` - if the probability is outside the valid range [0.0, 1.0], with an error message.
+    /// * `Err(RngError)` - Returns a `ProbabilityError` if the probability is outside the valid range [0.0, 1.0].
     pub fn change_probability(&mut self, probability: f64) -> Result<(), RngError> {
-        RngError::check_interval(probability, 0_f64, 1_f64)?;
+        RngError::check_probability(probability)?;
 
         self.probability = probability;
         Ok(())
     }
+
+    /// Estimates the probability of a `Bernoulli` distribution from samples using maximum likelihood.
+    ///
+    /// The estimator is the relative frequency of ones, i.e. the sample mean.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values, which should be 0 or 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Bernoulli)` - Returns the fitted instance of `Bernoulli`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `ProbabilityError` if the mean is not a probability.
+    pub fn fit(samples: &[f64]) -> Result<Bernoulli, RngError> {
+        RngError::check_empty(samples)?;
+
+        Bernoulli::new(mean(samples))
+    }
+}
+
+impl Default for Bernoulli {
+    /// Creates a `Bernoulli` instance of a fair coin with the probability 0.5.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Bernoulli::new(0.5_f64).unwrap()
+    }
+}
+
+impl DiscreteDistribution for Bernoulli {
+    /// Evaluates the probability mass function of the Bernoulli distribution.
+    ///
+    /// It is `p` for 1 and `1 - p` for 0.
+    fn pmf(&self, k: i32) -> f64 {
+        match k {
+            0_i32 => 1_f64 - self.probability,
+            1_i32 => self.probability,
+            _ => 0_f64,
+        }
+    }
+
+    /// Returns the support `0, 1` of the Bernoulli distribution.
+    fn support(&self) -> RangeInclusive<i32> {
+        0_i32..=1_i32
+    }
 }
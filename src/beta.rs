@@ -1,6 +1,10 @@
 //! This module contains the implementation of the `Beta` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::{mean, variance};
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -15,18 +19,31 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `alpha` - The alpha (α) of the Beta distribution. Must be a positive number.
 /// * `beta` - The beta (β) of the Beta distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Beta {
     /// The uniformly distributed random number generator.
     rng: Rng,
 
     /// The alpha (α) of the distribution.
-    alpha: i32,
+    alpha: f64,
 
     /// The beta (β) of the distribution.
-    beta: i32,
+    beta: f64,
 }
 
 auto_rng_trait!(Beta);
+auto_builder!(
+    Beta,
+    BetaBuilder {
+        alpha: f64,
+        beta: f64
+    }
+);
+auto_params!(Beta {
+    alpha: f64,
+    beta: f64
+});
+auto_display!(Beta { "α" = alpha, "β" = beta });
 
 impl Beta {
     /// Creates a new `Beta` instance with a given alpha and beta.
@@ -42,10 +59,12 @@ impl Beta {
     /// # Returns
     ///
     /// * `Ok(Beta)` - Returns an instance of `Beta` if the alpha and beta are valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the alpha or beta are less than or equal to 0.
-    pub fn new(alpha: i32, beta: i32) -> Result<Self, RngError> {
-        RngError::check_positive(alpha as f64)?;
-        RngError::check_positive(beta as f64)?;
+    /// * `Err(RngError)` - Returns a `PositiveError` if the alpha or beta are less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(alpha: f64, beta: f64) -> Result<Self, RngError> {
+        RngError::check_finite(alpha)?;
+        RngError::check_finite(beta)?;
+        RngError::check_positive(alpha)?;
+        RngError::check_positive(beta)?;
 
         Ok(Beta {
             rng: Rng::new(),
@@ -58,36 +77,59 @@ impl Beta {
     ///
     /// This uses the fact that
     /// ```text
-    /// Beta(α, β) = Gamma(α, θ) / (Gamma(β, θ) * Gamma(α, θ))
+    /// Beta(α, β) = Gamma(α, θ) / (Gamma(α, θ) + Gamma(β, θ))
     /// ```
     /// with `θ > 0`.
+    /// The Gamma values with scale 1 are generated by `Rng::gen_standard_gamma`, which takes `O(1)` time for every shape.
     ///
     /// # Returns
     ///
     /// A `f64` value generated from the Beta distribution.
     pub fn generate(&mut self) -> f64 {
-        let x: f64 = self.get_gamma(self.alpha);
-        let y: f64 = self.get_gamma(self.beta);
+        let x: f64 = self.rng.gen_standard_gamma(self.alpha);
+        let y: f64 = self.rng.gen_standard_gamma(self.beta);
 
         x / (x + y)
     }
 
-    /// Generates a random value from the Gamma distribution with scale of 1.
+    /// Estimates the parameters of a `Beta` distribution from samples using the method of moments.
     ///
-    /// This uses the fact that Gamma(1, 1) ~ Exp(1) and
+    /// With the sample mean `m` and the sample variance `v` the estimators are
     /// ```text
-    /// Gamma(n, 1) = Exp(1) + ... + Exp(1)
+    /// α = m (m (1 - m) / v - 1)
+    /// β = (1 - m) (m (1 - m) / v - 1)
     /// ```
     ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values in (0, 1) the distribution is fitted to.
+    ///
     /// # Returns
     ///
-    /// A `f64` value generated from the Gamma distribution.
-    fn get_gamma(&mut self, shape: i32) -> f64 {
-        let mut prod: f64 = 1_f64;
+    /// * `Ok(Beta)` - Returns the fitted instance of `Beta`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty, an `IntervalError` if the mean is not in [0, 1] or a `PositiveError` if the variance is too large or 0.
+    pub fn fit_moments(samples: &[f64]) -> Result<Beta, RngError> {
+        RngError::check_empty(samples)?;
+
+        let sample_mean: f64 = mean(samples);
+        RngError::check_interval(sample_mean, 0_f64, 1_f64)?;
+        let sample_variance: f64 = variance(samples, sample_mean);
+        RngError::check_positive(sample_variance)?;
 
-        for _ in 0_usize..(shape as usize) {
-            prod *= self.rng.generate();
-        }
-        -prod.ln()
+        let common: f64 = sample_mean * (1_f64 - sample_mean) / sample_variance - 1_f64;
+        RngError::check_positive(common)?;
+
+        Beta::new(sample_mean * common, (1_f64 - sample_mean) * common)
+    }
+}
+
+impl Default for Beta {
+    /// Creates a `Beta` instance with both shapes 1, which is uniformly distributed on [0, 1].
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Beta::new(1_f64, 1_f64).unwrap()
     }
 }
@@ -0,0 +1,112 @@
+//! This module contains the implementation of the `BetaBinomial` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a Beta-Binomial distribution.
+///
+/// The Beta-Binomial distribution is a Binomial distribution whose probability of success is Beta distributed.
+/// It models counts of successes that vary more than a Binomial distribution allows (overdispersion),
+/// e.g. when the success probability differs between groups.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `n` - The number of trials. Must be a positive integer.
+/// * `alpha` - The alpha (α) of the Beta distribution of the probability of success. Must be a positive number.
+/// * `beta` - The beta (β) of the Beta distribution of the probability of success. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct BetaBinomial {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The number of trials.
+    n: i32,
+
+    /// The alpha (α) of the Beta distribution.
+    alpha: f64,
+
+    /// The beta (β) of the Beta distribution.
+    beta: f64,
+}
+
+auto_rng_trait!(BetaBinomial);
+auto_builder!(
+    BetaBinomial,
+    BetaBinomialBuilder {
+        n: i32,
+        alpha: f64,
+        beta: f64
+    }
+);
+auto_params!(BetaBinomial {
+    n: i32,
+    alpha: f64,
+    beta: f64
+});
+auto_display!(BetaBinomial { "n" = n, "α" = alpha, "β" = beta });
+
+impl BetaBinomial {
+    /// Creates a new `BetaBinomial` instance with a given number of trials, alpha and beta.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - An `i32` representing the number of trials. It must be a positive integer.
+    /// * `alpha` - A `f64` representing the alpha (α) of the Beta distribution. It must be a positive number.
+    /// * `beta` - A `f64` representing the beta (β) of the Beta distribution. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BetaBinomial)` - Returns an instance of `BetaBinomial` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `n`, `alpha` or `beta` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(n: i32, alpha: f64, beta: f64) -> Result<BetaBinomial, RngError> {
+        RngError::check_finite(alpha)?;
+        RngError::check_finite(beta)?;
+        RngError::check_positive(n as f64)?;
+        RngError::check_positive(alpha)?;
+        RngError::check_positive(beta)?;
+
+        Ok(BetaBinomial {
+            rng: Rng::new(),
+            n,
+            alpha,
+            beta,
+        })
+    }
+
+    /// Generates a random value from the Beta-Binomial distribution.
+    ///
+    /// This uses the definition
+    /// ```text
+    /// P ~ Beta(α, β) = G₁ / (G₁ + G₂)
+    /// X ~ Binomial(n, P)
+    /// ```
+    /// where `G₁` is Gamma(α, 1) and `G₂` is Gamma(β, 1) distributed.
+    /// All draws are taken from the same random number generator.
+    ///
+    /// # Returns
+    ///
+    /// An `i32` value between 0 and `n`.
+    pub fn generate(&mut self) -> i32 {
+        let x: f64 = self.rng.gen_standard_gamma(self.alpha);
+        let y: f64 = self.rng.gen_standard_gamma(self.beta);
+        let p: f64 = x / (x + y);
+
+        self.rng.gen_binomial(self.n as u64, p) as i32
+    }
+}
+
+impl Default for BetaBinomial {
+    /// Creates a `BetaBinomial` instance with one trial and both shapes 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        BetaBinomial::new(1_i32, 1_f64, 1_f64).unwrap()
+    }
+}
@@ -0,0 +1,279 @@
+//! A command line interface for generating samples of the distributions of this crate.
+//!
+//! ```text
+//! random-gen <distribution> [--<parameter> <value>]... [-n <number>] [--seed <seed>] [--format <format>]
+//! ```
+//!
+//! For example `random-gen normal --mean 0 --var 1 -n 1e6 --seed 42 --format csv`
+//! prints a million standard normal distributed values as CSV.
+//! The samples are generated in chunks and streamed to the standard output, so `-n` is not limited by the memory.
+//! With the same seed the same values are printed on every platform.
+//! `random-gen list` prints all distributions with their parameters.
+
+use std::collections::HashMap;
+use std::io::{BufWriter, Write};
+use std::process::ExitCode;
+
+use random::*;
+
+/// The number of samples generated at once before they are written.
+const CHUNK_SIZE: usize = 4_096_usize;
+
+/// The parameters of a distribution with their default values.
+type Parameters = &'static [(&'static str, Option<f64>)];
+
+/// The distributions with their parameters and default values.
+///
+/// A parameter without a default value is required.
+// One distribution per line keeps the table readable, although some lines are longer than rustfmt allows
+#[rustfmt::skip]
+const DISTRIBUTIONS: [(&str, Parameters); 22] = [
+    ("bernoulli", &[("p", None)]),
+    ("beta", &[("alpha", None), ("beta", None)]),
+    ("binomial", &[("n", None), ("p", None)]),
+    ("chi-squared", &[("k", None)]),
+    ("exponential", &[("rate", Some(1_f64))]),
+    ("fisher", &[("m", None), ("n", None)]),
+    ("frechet", &[("location", Some(0_f64)), ("shape", None), ("scale", Some(1_f64))]),
+    ("gamma", &[("shape", None), ("scale", Some(1_f64))]),
+    ("geometric", &[("p", None)]),
+    ("gumbel", &[("location", Some(0_f64)), ("scale", Some(1_f64))]),
+    ("laplace", &[("location", Some(0_f64)), ("scale", Some(1_f64))]),
+    ("logistic", &[("location", Some(0_f64)), ("scale", Some(1_f64))]),
+    ("lognormal", &[("mean", Some(0_f64)), ("var", Some(1_f64))]),
+    ("normal", &[("mean", Some(0_f64)), ("var", Some(1_f64))]),
+    ("pareto", &[("scale", Some(1_f64)), ("shape", None)]),
+    ("pert", &[("min", None), ("max", None), ("mode", None)]),
+    ("poisson", &[("rate", None)]),
+    ("rayleigh", &[("scale", Some(1_f64))]),
+    ("students-t", &[("k", None)]),
+    ("triangle", &[("min", None), ("max", None), ("mode", None)]),
+    ("uniform", &[("min", Some(0_f64)), ("max", Some(1_f64))]),
+    ("weibull", &[("shape", None), ("scale", Some(1_f64))]),
+];
+
+/// The parameters that must be integers in the range of `i32`, as pairs of distribution and parameter.
+const INTEGER_PARAMETERS: [(&str, &str); 2] = [("binomial", "n"), ("chi-squared", "k")];
+
+/// The output formats of the samples.
+enum Format {
+    /// One value per line.
+    Lines,
+
+    /// A CSV file with the header `value`.
+    Csv,
+
+    /// A JSON array, with `null` for infinite and NaN values, which JSON cannot represent.
+    Json,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1_usize).collect();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            eprintln!("{}", usage());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the arguments, generates the samples and prints them.
+fn run(args: &[String]) -> Result<(), String> {
+    let name: &str = args.first().ok_or("missing distribution")?;
+    if name == "list" {
+        print_list();
+        return Ok(());
+    } else if name == "help" || name == "--help" || name == "-h" {
+        println!("{}", usage());
+        return Ok(());
+    }
+
+    let parameters: Parameters = DISTRIBUTIONS
+        .iter()
+        .find(|(distribution, _)| *distribution == name)
+        .map(|(_, parameters)| *parameters)
+        .ok_or_else(|| format!("unknown distribution `{}`", name))?;
+
+    let mut options: HashMap<String, String> = HashMap::new();
+    let mut iter = args[1_usize..].iter();
+    while let Some(flag) = iter.next() {
+        let key: &str = match flag.as_str() {
+            "-n" => "number",
+            _ => flag
+                .strip_prefix("--")
+                .ok_or_else(|| format!("unexpected argument `{}`", flag))?,
+        };
+        let value: &String = iter
+            .next()
+            .ok_or_else(|| format!("missing value for `{}`", flag))?;
+        options.insert(key.to_string(), value.clone());
+    }
+
+    let number: usize = match options.remove("number") {
+        Some(value) => {
+            parse_number(&value).ok_or_else(|| format!("invalid number of samples `{}`", value))?
+        }
+        None => 1_usize,
+    };
+    let seed: Option<u64> = match options.remove("seed") {
+        Some(value) => Some(
+            value
+                .parse()
+                .map_err(|_| format!("invalid seed `{}`", value))?,
+        ),
+        None => None,
+    };
+    let format: Format = match options.remove("format").as_deref() {
+        None | Some("lines") => Format::Lines,
+        Some("csv") => Format::Csv,
+        Some("json") => Format::Json,
+        Some(other) => return Err(format!("unknown format `{}`", other)),
+    };
+
+    let mut values: Vec<f64> = Vec::with_capacity(parameters.len());
+    for (parameter, default) in parameters {
+        let value: f64 = match options.remove(*parameter) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("invalid value `{}` for `--{}`", value, parameter))?,
+            None => default.ok_or_else(|| format!("missing parameter `--{}`", parameter))?,
+        };
+        if INTEGER_PARAMETERS.contains(&(name, *parameter)) && !is_integer(value) {
+            return Err(format!(
+                "`--{}` must be an integer, got `{}`",
+                parameter, value
+            ));
+        }
+        values.push(value);
+    }
+    if let Some(key) = options.keys().next() {
+        return Err(format!("unknown option `--{}` for `{}`", key, name));
+    }
+
+    let mut distribution: Box<dyn RngTrait> =
+        build(name, &values).map_err(|error| error.to_string())?;
+    if let Some(seed) = seed {
+        distribution.set_seed(seed);
+    }
+
+    // A closed pipe, e.g. of `random-gen normal -n 1e9 | head`, ends the output normally
+    match write_samples(distribution.as_mut(), number, format) {
+        Err(error) if error.kind() == std::io::ErrorKind::BrokenPipe => Ok(()),
+        result => result.map_err(|error| error.to_string()),
+    }
+}
+
+/// Parses a number of samples, either as an integer or in scientific notation like `1e6`.
+fn parse_number(value: &str) -> Option<usize> {
+    if let Ok(number) = value.parse::<usize>() {
+        return Some(number);
+    }
+
+    let number: f64 = value.parse().ok()?;
+    if number >= 0_f64 && number.fract() == 0_f64 && number <= usize::MAX as f64 {
+        Some(number as usize)
+    } else {
+        None
+    }
+}
+
+/// Checks that a value is an integer in the range of `i32`, so it is converted exactly.
+fn is_integer(value: f64) -> bool {
+    value.fract() == 0_f64 && value >= i32::MIN as f64 && value <= i32::MAX as f64
+}
+
+/// Creates the distribution with the given parameter values in the order of `DISTRIBUTIONS`.
+fn build(name: &str, v: &[f64]) -> Result<Box<dyn RngTrait>, RngError> {
+    Ok(match name {
+        "bernoulli" => Box::new(Bernoulli::new(v[0])?),
+        "beta" => Box::new(Beta::new(v[0], v[1])?),
+        "binomial" => Box::new(Binomial::new(v[0] as i32, v[1])?),
+        "chi-squared" => Box::new(ChiSquared::new(v[0] as i32)?),
+        "exponential" => Box::new(Exponential::new(v[0])?),
+        "fisher" => Box::new(Fisher::new(v[0], v[1])?),
+        "frechet" => Box::new(Frechet::new(v[0], v[1], v[2])?),
+        "gamma" => Box::new(Gamma::new(v[0], v[1])?),
+        "geometric" => Box::new(Geometric::new(v[0])?),
+        "gumbel" => Box::new(Gumbel::new(v[0], v[1])?),
+        "laplace" => Box::new(Laplace::new(v[0], v[1])?),
+        "logistic" => Box::new(Logistic::new(v[0], v[1])?),
+        "lognormal" => Box::new(LogNormal::new(v[0], v[1])?),
+        "normal" => Box::new(Normal::new(v[0], v[1])?),
+        "pareto" => Box::new(Pareto::new(v[0], v[1])?),
+        "pert" => Box::new(Pert::new(v[0], v[1], v[2])?),
+        "poisson" => Box::new(Poisson::new(v[0])?),
+        "rayleigh" => Box::new(Rayleigh::new(v[0])?),
+        "students-t" => Box::new(StudentsT::new(v[0])?),
+        "triangle" => Box::new(Triangle::new(v[0], v[1], v[2])?),
+        "uniform" => Box::new(Uniform::new(v[0], v[1])?),
+        _ => Box::new(Weibull::new(v[0], v[1])?),
+    })
+}
+
+/// Generates the samples in chunks and streams them to the standard output in the given format.
+fn write_samples(
+    distribution: &mut dyn RngTrait,
+    number: usize,
+    format: Format,
+) -> std::io::Result<()> {
+    let mut out = BufWriter::new(std::io::stdout().lock());
+    let mut buffer: Vec<f64> = vec![0_f64; CHUNK_SIZE.min(number)];
+
+    match format {
+        Format::Lines => {}
+        Format::Csv => writeln!(out, "value")?,
+        Format::Json => write!(out, "[")?,
+    }
+
+    let mut written: usize = 0_usize;
+    while written < number {
+        let chunk: &mut [f64] = &mut buffer[..(number - written).min(CHUNK_SIZE)];
+        distribution.fill(chunk);
+
+        for sample in chunk.iter() {
+            match format {
+                Format::Lines | Format::Csv => writeln!(out, "{}", sample)?,
+                Format::Json => {
+                    if written > 0_usize {
+                        write!(out, ",")?;
+                    }
+                    if sample.is_finite() {
+                        write!(out, "{}", sample)?;
+                    } else {
+                        write!(out, "null")?;
+                    }
+                }
+            }
+            written += 1_usize;
+        }
+    }
+
+    if let Format::Json = format {
+        writeln!(out, "]")?;
+    }
+    out.flush()
+}
+
+/// Prints all distributions with their parameters and default values.
+fn print_list() {
+    for (name, parameters) in DISTRIBUTIONS.iter() {
+        let parameters: Vec<String> = parameters
+            .iter()
+            .map(|(parameter, default)| match default {
+                Some(default) => format!("--{} {}", parameter, default),
+                None => format!("--{} <value>", parameter),
+            })
+            .collect();
+        println!("{:<12} {}", name, parameters.join(" "));
+    }
+}
+
+/// Returns the usage message.
+fn usage() -> String {
+    String::from(
+        "usage: random-gen <distribution> [--<parameter> <value>]... [-n <number>] [--seed <seed>] [--format lines|csv|json]\n       random-gen list",
+    )
+}
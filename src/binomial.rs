@@ -1,30 +1,60 @@
 //! This module contains the implementation of the `Binomial` struct and its methods.
 
+use std::ops::RangeInclusive;
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::discrete_distribution::DiscreteDistribution;
+use crate::math;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
+use crate::special::ln_gamma;
+use crate::tie_breaking::TieBreaking;
+
+/// The algorithms available to generate values of a `Binomial` distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinomialAlgorithm {
+    /// Chooses the fastest algorithm for the parameters: `Btpe` from `n min(p, 1 - p) = Binomial::CROSSOVER` on,
+    /// below it `Table` if `n` is below `Binomial::TABLE_LIMIT` and `Inversion` otherwise.
+    Auto,
+
+    /// A binary search in the precomputed cumulative distribution function. It takes `O(log n)` time.
+    Table,
+
+    /// A sequential search in the cumulative distribution function of the less likely outcome, starting at 0.
+    /// It takes `O(n min(p, 1 - p))` time and no memory.
+    Inversion,
+
+    /// The triangle, parallelogram and exponential rejection algorithm (BTPE) of Kachitvichyanukul and Schmeiser. It takes `O(1)` time.
+    Btpe,
+}
 
 /// A struct for generating random variables from a Binomial distribution.
 ///
 /// The binomial distribution models the number of successes in `n` independent Bernoulli trials,
 /// each with a success probability of `p`.
 ///
-/// This implementation precomputes a cumulative distribution function (CDF) for efficient sampling.
+/// Small values of `n min(p, 1 - p)` are sampled with a lookup table of the cumulative distribution function (CDF)
+/// or, for a large `n`, by a sequential search in it, bigger ones with the BTPE algorithm in constant time,
+/// see `BinomialAlgorithm`.
 ///
 /// # Fields
 ///
 /// * `rng` - A `Uniform` random number generator used to generate uniformly distributed random numbers.
 /// * `n` - The number of trials of the Binomial distribution. Must be a positive integer.
 /// * `p` - The probability of success of the Binomial distribution. Must be a number between 0 and 1.
-/// * `cdf` - The cumulative distribution function.
+/// * `cdf` - The cumulative distribution function, which is only tabulated for the `Table` algorithm.
+/// * `algorithm` - The algorithm used to generate values.
+/// * `tie_breaking` - The rule for a uniform number on a step of the cumulative distribution function.
 ///
 /// # Notes
 ///
-/// Because we need to calculate the binomial coefficient and therefore the factorial of a number,
-/// there is a cap how big `n` can be.
-/// If `n` is 129 its factorial is 170141183460469231731687303715884105728.
-/// For 130 this number does not fit into an `u128`.
-/// So the maximum allowed `n` is 128.
+/// The lookup table has `n + 1` entries, so it is built on the first value generated by the `Table` algorithm.
+/// `BinomialAlgorithm::Auto` only uses it for `n` below `Binomial::TABLE_LIMIT`,
+/// but forcing `BinomialAlgorithm::Table` for a huge `n` allocates the whole table.
+#[derive(Debug, Clone)]
 pub struct Binomial {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -39,9 +69,27 @@ pub struct Binomial {
     ///
     /// This is a precomputed vector storing cumulative probabilities for optimizing the random sampling process.
     cdf: Vec<f64>,
+
+    /// The algorithm used to generate values.
+    algorithm: BinomialAlgorithm,
+
+    /// The rule for a uniform number on a step of the cumulative distribution function.
+    tie_breaking: TieBreaking,
 }
 
 auto_rng_trait!(Binomial);
+auto_builder!(Binomial, BinomialBuilder { n: i32, p: f64 });
+auto_params!(Binomial { n: i32, p: f64 } keep algorithm, tie_breaking);
+auto_display!(Binomial { "n" = n, "p" = p });
+
+impl Binomial {
+    /// The value of `n min(p, 1 - p)` from which on `BinomialAlgorithm::Auto` uses `Btpe` instead of `Table`.
+    pub const CROSSOVER: f64 = 30_f64;
+
+    /// The value of `n` from which on `BinomialAlgorithm::Auto` uses `Inversion` instead of `Table`,
+    /// so the table never has more than this many entries.
+    pub const TABLE_LIMIT: i32 = 1024_i32;
+}
 
 impl Binomial {
     /// Creates a new `Binomial` instance with a given number of trials and probability of success.
@@ -58,37 +106,276 @@ impl Binomial {
     /// # Returns
     ///
     /// * `Ok(Binomial)` - Returns an instance of `Binomial` if the `n` and `p` are valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` or `IntervalError` if `n` is less than or equal to 0
-    /// or bigger than 128 or if `p` is not a probability.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `n` is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `ProbabilityError` if `p` is not a probability.
     pub fn new(n: i32, p: f64) -> Result<Binomial, RngError> {
         RngError::check_positive(n as f64)?;
-        RngError::check_interval(n as f64, 0_f64, 128_f64)?;
-        RngError::check_interval(p, 0_f64, 1_f64)?;
+        RngError::check_probability(p)?;
 
-        let cdf: Vec<f64> = Self::get_cdf(n, p);
         Ok(Binomial {
             rng: Rng::new(),
             n,
             p,
-            cdf,
+            cdf: Vec::new(),
+            algorithm: BinomialAlgorithm::Auto,
+            tie_breaking: TieBreaking::Upper,
         })
     }
 
+    /// Sets the algorithm used to generate values.
+    ///
+    /// By default `BinomialAlgorithm::Auto` chooses the algorithm by the parameters.
+    /// Overriding it is useful to benchmark the algorithms or to compare their results.
+    /// `BinomialAlgorithm::Btpe` falls back to `Table` if `p` is 0 or 1, where it is not valid.
+    /// `BinomialAlgorithm::Inversion` falls back to `Btpe` if `(1 - min(p, 1 - p))^n` underflows to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - The `BinomialAlgorithm` to use.
+    pub fn set_algorithm(&mut self, algorithm: BinomialAlgorithm) {
+        self.algorithm = algorithm;
+    }
+
+    /// Returns the algorithm used to generate values.
+    ///
+    /// # Returns
+    ///
+    /// The `BinomialAlgorithm` that is used for the parameters, which is never `Auto`.
+    pub fn algorithm(&self) -> BinomialAlgorithm {
+        let spread: f64 = self.n as f64 * self.p.min(1_f64 - self.p);
+        match self.algorithm {
+            BinomialAlgorithm::Table => BinomialAlgorithm::Table,
+            BinomialAlgorithm::Inversion => BinomialAlgorithm::Inversion,
+            BinomialAlgorithm::Btpe if spread > 0_f64 => BinomialAlgorithm::Btpe,
+            BinomialAlgorithm::Auto if spread >= Self::CROSSOVER => BinomialAlgorithm::Btpe,
+            BinomialAlgorithm::Auto if self.n >= Self::TABLE_LIMIT => BinomialAlgorithm::Inversion,
+            _ => BinomialAlgorithm::Table,
+        }
+    }
+
+    /// Sets the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// By default `TieBreaking::Upper` generates the next value, see `TieBreaking` for the bias of both rules.
+    /// It applies to `BinomialAlgorithm::Table` and `Inversion`, as `Btpe` does not invert the cumulative distribution function.
+    /// For `p > 0.5` `Inversion` counts the failures, so the rule applies to their distribution function instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `tie_breaking` - The `TieBreaking` rule to use.
+    pub fn set_tie_breaking(&mut self, tie_breaking: TieBreaking) {
+        self.tie_breaking = tie_breaking;
+    }
+
+    /// Returns the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// # Returns
+    ///
+    /// The `TieBreaking` rule in use.
+    pub fn tie_breaking(&self) -> TieBreaking {
+        self.tie_breaking
+    }
+
     /// Generates a random value from the Binomial distribution.
     ///
-    /// This method generates a random variate according to the Binomial distribution using the cumulative distribution function as a lookup table.
+    /// The algorithm is chosen by `Binomial::algorithm`.
     ///
     /// # Returns
     ///
     /// A `i32` value generated from the Binomial distribution.
     pub fn generate(&mut self) -> i32 {
+        match self.algorithm() {
+            BinomialAlgorithm::Btpe => self.generate_btpe(),
+            BinomialAlgorithm::Inversion => self.generate_inversion(),
+            _ => self.generate_table(),
+        }
+    }
+
+    /// Generates a random value using the cumulative distribution function as a lookup table.
+    ///
+    /// The value is the smallest `k` with `P(X <= k) > U`, which is found by a binary search.
+    /// With `TieBreaking::Lower` it is the smallest `k` with `P(X <= k) >= U` instead,
+    /// skipping the values of probability 0 for `U = 0`, e.g. all values below `n` for `p = 1`.
+    /// If rounding leaves the last entry below `U`, the value is `n`.
+    /// The table is built on the first call.
+    fn generate_table(&mut self) -> i32 {
+        if self.cdf.is_empty() {
+            self.cdf = Self::get_cdf(self.n, self.p);
+        }
+
         let uniform: f64 = self.rng.generate();
-        for k in 0_usize..=self.n as usize {
-            if self.cdf[k] > uniform {
-                return k as i32;
+        let k: usize = self
+            .cdf
+            .partition_point(|&cdf| cdf == 0_f64 || self.tie_breaking.precedes(cdf, uniform));
+        (k as i32).min(self.n)
+    }
+
+    /// Generates a random value by a sequential search in the cumulative distribution function of `Binomial(n, r)`
+    /// with `r = min(p, 1 - p)`.
+    ///
+    /// Starting at `P(X = 0) = (1 - r)^n`, the probabilities are subtracted from `U` until it falls below one of them,
+    /// using the recursion `P(X = k + 1) = P(X = k) (n - k) / (k + 1) r / (1 - r)`.
+    /// For `p > 0.5` the result is mirrored.
+    /// If `(1 - r)^n` underflows to 0, the value is generated by `generate_btpe` instead.
+    fn generate_inversion(&mut self) -> i32 {
+        let n: f64 = self.n as f64;
+        let r: f64 = self.p.min(1_f64 - self.p);
+        let odds: f64 = r / (1_f64 - r);
+        let mut probability: f64 = math::exp(n * math::ln_1p(-r));
+        if probability == 0_f64 {
+            return self.generate_btpe();
+        }
+
+        let mut uni: f64 = self.rng.generate();
+        let mut k: i32 = 0_i32;
+        while k < self.n && self.tie_breaking.precedes(probability, uni) {
+            uni -= probability;
+            probability *= (n - k as f64) / (k as f64 + 1_f64) * odds;
+            k += 1_i32;
+
+            // Rounding can leave a remainder beyond the mode
+            if probability == 0_f64 {
+                break;
+            }
+        }
+
+        if self.p > 0.5_f64 { self.n - k } else { k }
+    }
+
+    /// Generates a random value with the BTPE algorithm of Kachitvichyanukul and Schmeiser.
+    ///
+    /// The distribution of `Binomial(n, r)` with `r = min(p, 1 - p)` is covered by a hat consisting of
+    /// a triangle around the mode, two parallelograms next to it and two exponential tails.
+    /// A candidate from the hat is accepted by comparing with the probability of the candidate relative to the mode,
+    /// which is calculated recursively close to the mode and with Stirling's formula far from it.
+    /// For `p > 0.5` the result is mirrored.
+    fn generate_btpe(&mut self) -> i32 {
+        let n: f64 = self.n as f64;
+        let r: f64 = self.p.min(1_f64 - self.p);
+        let q: f64 = 1_f64 - r;
+        let nrq: f64 = n * r * q;
+
+        // The setup of the hat
+        let fm: f64 = n * r + r;
+        let m: f64 = fm.floor();
+        let p1: f64 = (2.195_f64 * nrq.sqrt() - 4.6_f64 * q).floor() + 0.5_f64;
+        let xm: f64 = m + 0.5_f64;
+        let xl: f64 = xm - p1;
+        let xr: f64 = xm + p1;
+        let c: f64 = 0.134_f64 + 20.5_f64 / (15.3_f64 + m);
+        let al: f64 = (fm - xl) / (fm - xl * r);
+        let lambda_l: f64 = al * (1_f64 + 0.5_f64 * al);
+        let ar: f64 = (xr - fm) / (xr * q);
+        let lambda_r: f64 = ar * (1_f64 + 0.5_f64 * ar);
+        let p2: f64 = p1 * (1_f64 + 2_f64 * c);
+        let p3: f64 = p2 + c / lambda_l;
+        let p4: f64 = p3 + c / lambda_r;
+
+        let y: f64 = loop {
+            let u: f64 = self.rng.generate() * p4;
+            let mut v: f64 = self.rng.generate();
+
+            // The triangle is accepted immediately
+            if u <= p1 {
+                break (xm - p1 * v + u).floor();
+            }
+
+            let y: f64 = if u <= p2 {
+                // The parallelograms
+                let x: f64 = xl + (u - p1) / c;
+                v = v * c + 1_f64 - (m - x + 0.5_f64).abs() / p1;
+                if v > 1_f64 {
+                    continue;
+                }
+                x.floor()
+            } else if u <= p3 {
+                // The left exponential tail
+                let y: f64 = (xl + math::ln(v) / lambda_l).floor();
+                if y < 0_f64 {
+                    continue;
+                }
+                v *= (u - p2) * lambda_l;
+                y
+            } else {
+                // The right exponential tail
+                let y: f64 = (xr - math::ln(v) / lambda_r).floor();
+                if y > n {
+                    continue;
+                }
+                v *= (u - p3) * lambda_r;
+                y
+            };
+
+            let k: f64 = (y - m).abs();
+            if k <= 20_f64 || k >= 0.5_f64 * nrq - 1_f64 {
+                // The probability relative to the mode is calculated recursively
+                let s: f64 = r / q;
+                let a: f64 = s * (n + 1_f64);
+                let mut f: f64 = 1_f64;
+                if m < y {
+                    let mut i: f64 = m + 1_f64;
+                    while i <= y {
+                        f *= a / i - s;
+                        i += 1_f64;
+                    }
+                } else if m > y {
+                    let mut i: f64 = y + 1_f64;
+                    while i <= m {
+                        f /= a / i - s;
+                        i += 1_f64;
+                    }
+                }
+                if v <= f {
+                    break y;
+                }
+                continue;
+            }
+
+            // Squeeze with bounds of the logarithm of the relative probability
+            let rho: f64 =
+                (k / nrq) * ((k * (k / 3_f64 + 0.625_f64) + 1_f64 / 6_f64) / nrq + 0.5_f64);
+            let t: f64 = -k * k / (2_f64 * nrq);
+            let ln_v: f64 = math::ln(v);
+            if ln_v < t - rho {
+                break y;
+            }
+            if ln_v > t + rho {
+                continue;
+            }
+
+            // The final comparison with Stirling's formula
+            let x1: f64 = y + 1_f64;
+            let f1: f64 = m + 1_f64;
+            let z: f64 = n + 1_f64 - m;
+            let w: f64 = n - y + 1_f64;
+            let bound: f64 = xm * math::ln(f1 / x1)
+                + (n - m + 0.5_f64) * math::ln(z / w)
+                + (y - m) * math::ln(w * r / (x1 * q))
+                + Self::stirling_correction(f1)
+                + Self::stirling_correction(z)
+                + Self::stirling_correction(x1)
+                + Self::stirling_correction(w);
+            if ln_v <= bound {
+                break y;
             }
+        };
+
+        if self.p > 0.5_f64 {
+            (n - y) as i32
+        } else {
+            y as i32
         }
-        self.n
+    }
+
+    /// Computes the correction term of Stirling's formula used by `generate_btpe`.
+    ///
+    /// ```text
+    /// (13860 - (462 - (132 - (99 - 140 / x²) / x²) / x²) / x²) / x / 166320
+    /// ```
+    fn stirling_correction(x: f64) -> f64 {
+        let x2: f64 = x * x;
+        (13860_f64 - (462_f64 - (132_f64 - (99_f64 - 140_f64 / x2) / x2) / x2) / x2)
+            / x
+            / 166320_f64
     }
 
     /// Computes the cumulative distribution function (CDF) for a binomial distribution.
@@ -129,34 +416,130 @@ impl Binomial {
     /// # Returns
     ///
     /// The probability of observing exactly `k` successes.
+    ///
+    /// # Notes
+    ///
+    /// The powers are calculated with the functions of `crate::math`, unlike `f64::powi`, whose precision is not specified,
+    /// so the lookup table is bit-identical with the `strict-reproducible` feature.
+    /// For a binomial coefficient exactly representable as a `f64`, the probabilities of parameters like `p = 0.5`
+    /// are exact, so the steps of the cumulative distribution function lie exactly on the grid of the uniform numbers.
+    /// Bigger coefficients and powers would overflow or underflow, so the probability is calculated from its logarithm instead.
     fn binomial_probability(n: i32, k: i32, p: f64) -> f64 {
-        Self::binomial_coefficient(n, k) as f64 * p.powi(k) * (1f64 - p).powi(n - k)
+        match Self::binomial_coefficient(n, k) {
+            Some(coefficient) => {
+                coefficient * math::powf(p, k as f64) * math::powf(1_f64 - p, (n - k) as f64)
+            }
+            None => math::exp(Self::ln_binomial_probability(n, k, p)),
+        }
     }
 
-    /// Computes the binomial coefficient (n choose k).
+    /// Computes the binomial coefficient (n choose k) if it is exactly representable as a `f64`.
     ///
-    /// The binomial coefficient represents the number of ways to choose `k` elements from `n` without considering order.
+    /// The coefficient is calculated with the recursion `(n choose i + 1) = (n choose i) (n - i) / (i + 1)` in integers,
+    /// which stops as soon as the coefficient exceeds `2^53`.
     ///
     /// # Parameters
     ///
-    /// * `n` -  The total number of elements.
-    /// * `k` - The number of elements to choose.
+    /// * `n` - The total number of elements.
+    /// * `k` - The number of elements to choose, between 0 and `n`.
     ///
     /// # Returns
     ///
-    /// The binomial coefficient value.
-    fn binomial_coefficient(n: i32, k: i32) -> u128 {
-        Self::factorial(n) / Self::factorial(k) / Self::factorial(n - k)
+    /// The binomial coefficient, or `None` if it is bigger than `2^53`.
+    fn binomial_coefficient(n: i32, k: i32) -> Option<f64> {
+        const EXACT: u128 = 1_u128 << 53_u32;
+        let k: i32 = k.min(n - k);
+        let mut coefficient: u128 = 1_u128;
+        for i in 0_i32..k {
+            // The product is below 2^84 and the division is exact
+            coefficient = coefficient * (n - i) as u128 / (i + 1_i32) as u128;
+            if coefficient > EXACT {
+                return None;
+            }
+        }
+        Some(coefficient as f64)
+    }
+
+    /// Computes the logarithm of the probability mass function of a binomial distribution.
+    ///
+    /// ```text
+    /// ln P(X = k) = ln Γ(n + 1) - ln Γ(k + 1) - ln Γ(n - k + 1) + k ln p + (n - k) ln(1 - p)
+    /// ```
+    ///
+    /// # Parameters
+    /// * `n` - The number of trials.
+    /// * `k` - The number of successes, between 0 and `n`.
+    /// * `p` - The probability of success in each trial.
+    ///
+    /// # Returns
+    ///
+    /// The logarithm of the probability of observing exactly `k` successes, which is negative infinity for `p` 0 or 1
+    /// if `k` is impossible.
+    fn ln_binomial_probability(n: i32, k: i32, p: f64) -> f64 {
+        let n: f64 = n as f64;
+        let k: f64 = k as f64;
+        // The terms with a zero factor vanish even if the logarithm is negative infinity
+        let successes: f64 = if k > 0_f64 { k * math::ln(p) } else { 0_f64 };
+        let failures: f64 = if k < n {
+            (n - k) * math::ln_1p(-p)
+        } else {
+            0_f64
+        };
+
+        ln_gamma(n + 1_f64) - ln_gamma(k + 1_f64) - ln_gamma(n - k + 1_f64) + successes + failures
     }
 
     /// Computes the factorial of a number.
     ///
+    /// It is no longer used by `Binomial`, as the factorials overflow a `u128` for `num > 34`.
+    ///
     /// # Parameters
     /// * `num` - The integer whose factorial is to be computed.
     ///
     /// # Returns
     /// The factorial of `num` as a `u128`.
+    #[deprecated(note = "overflows for `num > 34` and is not used by `Binomial` anymore")]
     pub fn factorial(num: i32) -> u128 {
         (1u128..=num as u128).product()
     }
 }
+
+impl Default for Binomial {
+    /// Creates a `Binomial` instance of a single fair coin toss.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Binomial::new(1_i32, 0.5_f64).unwrap()
+    }
+}
+
+impl DiscreteDistribution for Binomial {
+    /// Evaluates the probability mass function of the Binomial distribution.
+    ///
+    /// ```text
+    /// P(X = k) = (n choose k) pᵏ (1 - p)ⁿ⁻ᵏ
+    /// ```
+    fn pmf(&self, k: i32) -> f64 {
+        if !(0_i32..=self.n).contains(&k) {
+            return 0_f64;
+        }
+        Self::binomial_probability(self.n, k, self.p)
+    }
+
+    /// Evaluates the logarithm of the probability mass function of the Binomial distribution.
+    ///
+    /// ```text
+    /// ln P(X = k) = ln Γ(n + 1) - ln Γ(k + 1) - ln Γ(n - k + 1) + k ln p + (n - k) ln(1 - p)
+    /// ```
+    fn ln_pmf(&self, k: i32) -> f64 {
+        if !(0_i32..=self.n).contains(&k) {
+            return f64::NEG_INFINITY;
+        }
+        Self::ln_binomial_probability(self.n, k, self.p)
+    }
+
+    /// Returns the support `0, ..., n` of the Binomial distribution.
+    fn support(&self) -> RangeInclusive<i32> {
+        0_i32..=self.n
+    }
+}
@@ -0,0 +1,136 @@
+//! This module contains bootstrap resampling utilities.
+//!
+//! The bootstrap estimates the sampling distribution of a statistic by recomputing it on resamples,
+//! which are drawn uniformly with replacement from the data.
+//! The functions take the random number generator as an argument, so that the results are reproducible by seeding it.
+
+use crate::auxiliary::mean;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A percentile bootstrap confidence interval.
+///
+/// # Fields
+///
+/// * `estimate` - The statistic of the original data.
+/// * `lower` - The lower bound of the interval.
+/// * `upper` - The upper bound of the interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceInterval {
+    /// The statistic of the original data.
+    pub estimate: f64,
+
+    /// The lower bound of the interval.
+    pub lower: f64,
+
+    /// The upper bound of the interval.
+    pub upper: f64,
+}
+
+/// Draws resamples with replacement from the data.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `data` - A slice of at least two `f64` values.
+/// * `n_resamples` - A `usize` representing the number of resamples.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Vec<f64>>)` - Returns `n_resamples` resamples, each of the same length as `data`.
+/// * `Err(RngError)` - Returns an `EmptyError` if `data` is empty or an `OrderError` if it contains only one value.
+pub fn resample(
+    rng: &mut Rng,
+    data: &[f64],
+    n_resamples: usize,
+) -> Result<Vec<Vec<f64>>, RngError> {
+    check_data(data)?;
+
+    Ok((0_usize..n_resamples).map(|_| draw(rng, data)).collect())
+}
+
+/// Calculates a percentile bootstrap confidence interval of an arbitrary statistic.
+///
+/// The statistic is evaluated on `n_resamples` resamples.
+/// The bounds of the interval are the `(1 - confidence) / 2` and `(1 + confidence) / 2` quantiles of these values,
+/// linearly interpolated between the sorted values.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `data` - A slice of at least two `f64` values.
+/// * `statistic` - A function calculating the statistic of a sample.
+/// * `n_resamples` - A `usize` representing the number of resamples. It must be a positive integer.
+/// * `confidence` - A `f64` representing the confidence level, e.g. `0.95`. It must be between 0 and 1.
+///
+/// # Returns
+///
+/// * `Ok(ConfidenceInterval)` - Returns the estimate and the confidence interval.
+/// * `Err(RngError)` - Returns an `EmptyError` if `data` is empty, an `OrderError` if it contains only one value, a `PositiveError` if `n_resamples` is 0 or an `IntervalError` if `confidence` is not between 0 and 1.
+pub fn bootstrap_statistic<F: Fn(&[f64]) -> f64>(
+    rng: &mut Rng,
+    data: &[f64],
+    statistic: F,
+    n_resamples: usize,
+    confidence: f64,
+) -> Result<ConfidenceInterval, RngError> {
+    RngError::check_positive(n_resamples as f64)?;
+    RngError::check_interval(confidence, 0_f64, 1_f64)?;
+    check_data(data)?;
+
+    let mut values: Vec<f64> = (0_usize..n_resamples)
+        .map(|_| statistic(&draw(rng, data)))
+        .collect();
+    values.sort_by(f64::total_cmp);
+
+    let alpha: f64 = 1_f64 - confidence;
+    Ok(ConfidenceInterval {
+        estimate: statistic(data),
+        lower: percentile(&values, 0.5_f64 * alpha),
+        upper: percentile(&values, 1_f64 - 0.5_f64 * alpha),
+    })
+}
+
+/// Calculates a percentile bootstrap confidence interval of the mean.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `data` - A slice of at least two `f64` values.
+/// * `n_resamples` - A `usize` representing the number of resamples. It must be a positive integer.
+/// * `confidence` - A `f64` representing the confidence level, e.g. `0.95`. It must be between 0 and 1.
+///
+/// # Returns
+///
+/// * `Ok(ConfidenceInterval)` - Returns the sample mean and its confidence interval.
+/// * `Err(RngError)` - Returns an `EmptyError` if `data` is empty, an `OrderError` if it contains only one value, a `PositiveError` if `n_resamples` is 0 or an `IntervalError` if `confidence` is not between 0 and 1.
+pub fn bootstrap_mean_ci(
+    rng: &mut Rng,
+    data: &[f64],
+    n_resamples: usize,
+    confidence: f64,
+) -> Result<ConfidenceInterval, RngError> {
+    bootstrap_statistic(rng, data, mean, n_resamples, confidence)
+}
+
+/// Checks that the data contain at least two values, so that a resample can differ from the data.
+fn check_data(data: &[f64]) -> Result<(), RngError> {
+    RngError::check_empty(data)?;
+    RngError::check_order(0_f64, (data.len() - 1_usize) as f64)
+}
+
+/// Draws a single resample with replacement.
+fn draw(rng: &mut Rng, data: &[f64]) -> Vec<f64> {
+    (0_usize..data.len())
+        .map(|_| data[rng.gen_below(data.len() as u64) as usize])
+        .collect()
+}
+
+/// Calculates the `q`-quantile of sorted values by linear interpolation.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let position: f64 = q * (sorted.len() - 1_usize) as f64;
+    let below: usize = position.floor() as usize;
+    let above: usize = position.ceil() as usize;
+
+    sorted[below] + (position - below as f64) * (sorted[above] - sorted[below])
+}
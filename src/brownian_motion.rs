@@ -0,0 +1,264 @@
+//! This module contains the implementation of the `BrownianMotion` and `GeometricBrownianMotion` structs and their methods.
+
+use crate::auto_rng_trait;
+use crate::math;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating paths of a Brownian motion (Wiener process) with drift.
+///
+/// The process is observed on the grid `0, dt, 2 dt, ...` and starts at 0.
+/// Its increments are independently Normal distributed with mean `μ dt` and variance `σ² dt`.
+/// The `generate` method generates one such increment.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `drift` - The drift (μ) of the process.
+/// * `volatility` - The volatility (σ) of the process. Must be a positive number.
+/// * `dt` - The time step. Must be a positive number.
+/// * `mean_step` - The mean of an increment `μ dt`, pre-computed to optimize performance.
+/// * `std_step` - The standard deviation of an increment `σ sqrt(dt)`, pre-computed to optimize performance.
+#[derive(Debug, Clone)]
+pub struct BrownianMotion {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The drift (μ) of the process.
+    drift: f64,
+
+    /// The volatility (σ) of the process.
+    volatility: f64,
+
+    /// The time step.
+    dt: f64,
+
+    /// The mean of an increment.
+    mean_step: f64,
+
+    /// The standard deviation of an increment.
+    std_step: f64,
+}
+
+auto_rng_trait!(BrownianMotion);
+
+impl BrownianMotion {
+    /// Creates a new `BrownianMotion` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `drift` - A `f64` representing the drift (μ) of the process.
+    /// * `volatility` - A `f64` representing the volatility (σ) of the process. It must be a positive number.
+    /// * `dt` - A `f64` representing the time step. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BrownianMotion)` - Returns an instance of `BrownianMotion` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `volatility` or `dt` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(drift: f64, volatility: f64, dt: f64) -> Result<BrownianMotion, RngError> {
+        RngError::check_finite(drift)?;
+        RngError::check_finite(volatility)?;
+        RngError::check_finite(dt)?;
+        RngError::check_positive(volatility)?;
+        RngError::check_positive(dt)?;
+
+        Ok(BrownianMotion {
+            rng: Rng::new(),
+            drift,
+            volatility,
+            dt,
+            mean_step: drift * dt,
+            std_step: volatility * dt.sqrt(),
+        })
+    }
+
+    /// Creates a new standard `BrownianMotion` instance with drift 0, volatility 1 and a given time step.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - A `f64` representing the time step. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BrownianMotion)` - Returns an instance of `BrownianMotion` if `dt` is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `dt` is less than or equal to 0.
+    pub fn standard(dt: f64) -> Result<BrownianMotion, RngError> {
+        BrownianMotion::new(0_f64, 1_f64, dt)
+    }
+
+    /// Generates a random increment of the process.
+    ///
+    /// This method generates a random variate using the formula:
+    /// ```text
+    /// X = μ dt + σ sqrt(dt) Z
+    /// ```
+    /// where `Z` is standard normal distributed.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the change of the process during one time step.
+    pub fn generate(&mut self) -> f64 {
+        self.mean_step + self.std_step * self.rng.gen_standard_normal()
+    }
+
+    /// Generates a path of the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_steps` - A `usize` representing the number of time steps.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `n_steps + 1` containing the values at the times `0, dt, ..., n_steps dt`, starting with 0.
+    pub fn path(&mut self, n_steps: usize) -> Vec<f64> {
+        let mut path: Vec<f64> = Vec::with_capacity(n_steps + 1_usize);
+        path.push(0_f64);
+        path.extend(self.iter().take(n_steps));
+        path
+    }
+
+    /// Returns an infinite iterator over the values of the process at the times `dt, 2 dt, ...`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding the values of the process after each time step.
+    pub fn iter(&mut self) -> impl Iterator<Item = f64> + '_ {
+        let mut value: f64 = 0_f64;
+
+        std::iter::from_fn(move || {
+            value += self.generate();
+            Some(value)
+        })
+    }
+}
+
+/// A struct for generating paths of a geometric Brownian motion.
+///
+/// This is the model of the Black-Scholes option pricing formula.
+/// The process is observed on the grid `0, dt, 2 dt, ...` and starts at `start`.
+/// It is simulated using the exact discretization
+/// ```text
+/// S(t + dt) = S(t) exp((μ - σ² / 2) dt + σ sqrt(dt) Z)
+/// ```
+/// where `Z` is standard normal distributed.
+/// The `generate` method generates one growth factor `S(t + dt) / S(t)`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `drift` - The drift (μ) of the process.
+/// * `volatility` - The volatility (σ) of the process. Must be a positive number.
+/// * `dt` - The time step. Must be a positive number.
+/// * `start` - The initial value of the process. Must be a positive number.
+/// * `mean_step` - The mean of a log increment `(μ - σ² / 2) dt`, pre-computed to optimize performance.
+/// * `std_step` - The standard deviation of a log increment `σ sqrt(dt)`, pre-computed to optimize performance.
+#[derive(Debug, Clone)]
+pub struct GeometricBrownianMotion {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The drift (μ) of the process.
+    drift: f64,
+
+    /// The volatility (σ) of the process.
+    volatility: f64,
+
+    /// The time step.
+    dt: f64,
+
+    /// The initial value of the process.
+    start: f64,
+
+    /// The mean of a log increment.
+    mean_step: f64,
+
+    /// The standard deviation of a log increment.
+    std_step: f64,
+}
+
+auto_rng_trait!(GeometricBrownianMotion);
+
+impl GeometricBrownianMotion {
+    /// Creates a new `GeometricBrownianMotion` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `drift` - A `f64` representing the drift (μ) of the process.
+    /// * `volatility` - A `f64` representing the volatility (σ) of the process. It must be a positive number.
+    /// * `dt` - A `f64` representing the time step. It must be a positive number.
+    /// * `start` - A `f64` representing the initial value, e.g. the spot price. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GeometricBrownianMotion)` - Returns an instance of `GeometricBrownianMotion` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `volatility`, `dt` or `start` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(
+        drift: f64,
+        volatility: f64,
+        dt: f64,
+        start: f64,
+    ) -> Result<GeometricBrownianMotion, RngError> {
+        RngError::check_finite(drift)?;
+        RngError::check_finite(volatility)?;
+        RngError::check_finite(dt)?;
+        RngError::check_finite(start)?;
+        RngError::check_positive(volatility)?;
+        RngError::check_positive(dt)?;
+        RngError::check_positive(start)?;
+
+        Ok(GeometricBrownianMotion {
+            rng: Rng::new(),
+            drift,
+            volatility,
+            dt,
+            start,
+            mean_step: (drift - 0.5_f64 * volatility * volatility) * dt,
+            std_step: volatility * dt.sqrt(),
+        })
+    }
+
+    /// Generates a random growth factor of the process.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the ratio `S(t + dt) / S(t)` of one time step.
+    pub fn generate(&mut self) -> f64 {
+        math::exp(self.mean_step + self.std_step * self.rng.gen_standard_normal())
+    }
+
+    /// Generates a path of the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_steps` - A `usize` representing the number of time steps.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `n_steps + 1` containing the values at the times `0, dt, ..., n_steps dt`, starting with `start`.
+    pub fn path(&mut self, n_steps: usize) -> Vec<f64> {
+        let mut path: Vec<f64> = Vec::with_capacity(n_steps + 1_usize);
+        path.push(self.start);
+        path.extend(self.iter().take(n_steps));
+        path
+    }
+
+    /// Returns an infinite iterator over the values of the process at the times `dt, 2 dt, ...`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding the values of the process after each time step.
+    pub fn iter(&mut self) -> impl Iterator<Item = f64> + '_ {
+        let mut value: f64 = self.start;
+
+        std::iter::from_fn(move || {
+            value *= self.generate();
+            Some(value)
+        })
+    }
+}
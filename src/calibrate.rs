@@ -0,0 +1,233 @@
+//! This module contains functions to calibrate distributions to target quantiles.
+//!
+//! In expert elicitation the parameters of a distribution are often not known,
+//! but estimates of some quantiles are, e.g. "with 10 % probability the cost is below 5, with 90 % below 20".
+//! `to_quantiles` finds the parameters whose quantile function matches such targets.
+//!
+//! For every supported family the (possibly transformed) quantile function is linear in the transformed parameters,
+//! e.g. `Q(p) = μ + σ Φ⁻¹(p)` for the Normal distribution.
+//! Therefore, the parameters are the least squares solution of a linear regression,
+//! which matches the targets exactly if there are as many targets as parameters.
+
+use crate::auxiliary::standard_normal_quantile;
+use crate::exponential::Exponential;
+use crate::gumbel::Gumbel;
+use crate::laplace::Laplace;
+use crate::logistic::Logistic;
+use crate::lognormal::LogNormal;
+use crate::math;
+use crate::normal::Normal;
+use crate::pareto::Pareto;
+use crate::quantile::Quantile;
+use crate::rayleigh::Rayleigh;
+use crate::rng_error::RngError;
+use crate::uniform::Uniform;
+use crate::weibull::Weibull;
+
+/// A trait for distribution families which can be calibrated to target quantiles.
+pub trait Calibrate: Quantile + Sized {
+    /// Creates the distribution of the family whose quantiles best match the targets.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets` - A slice of `(p, x)` pairs, where `x` is the target `p`-quantile. Every `p` must be strictly between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - Returns the calibrated distribution.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no targets, an `IntervalError` if a probability is not strictly between 0 and 1, or a `PositiveError` if the targets do not determine valid parameters.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Self, RngError>;
+}
+
+/// Calibrates a distribution family to target quantiles.
+///
+/// # Arguments
+///
+/// * `targets` - A slice of `(p, x)` pairs, where `x` is the target `p`-quantile. Every `p` must be strictly between 0 and 1.
+///
+/// # Returns
+///
+/// * `Ok(D)` - Returns the calibrated distribution.
+/// * `Err(RngError)` - Returns an `EmptyError` if there are no targets, an `IntervalError` if a probability is not strictly between 0 and 1, or a `PositiveError` if the targets do not determine valid parameters.
+///
+/// # Examples
+///
+/// ```
+/// use random::calibrate::to_quantiles;
+/// use random::{LogNormal, Quantile};
+///
+/// let cost: LogNormal = to_quantiles(&[(0.1_f64, 5_f64), (0.9_f64, 20_f64)]).unwrap();
+/// assert!((cost.quantile(0.9_f64) - 20_f64).abs() < 1e-6_f64);
+/// ```
+pub fn to_quantiles<D: Calibrate>(targets: &[(f64, f64)]) -> Result<D, RngError> {
+    D::from_quantiles(targets)
+}
+
+/// Checks that there are targets and every probability is strictly between 0 and 1.
+fn check_targets(targets: &[(f64, f64)]) -> Result<(), RngError> {
+    RngError::check_empty(targets)?;
+
+    for &(p, _) in targets.iter() {
+        if p <= 0_f64 || p >= 1_f64 {
+            return Err(RngError::interval(p, 0_f64, 1_f64));
+        }
+    }
+    Ok(())
+}
+
+/// Fits `y = intercept + slope z` by least squares and checks that the slope is positive.
+///
+/// # Returns
+///
+/// * `Ok((f64, f64))` - The intercept and the slope.
+/// * `Err(RngError)` - Returns a `PositiveError` if all `z` are equal or the slope is not positive.
+fn regression(points: &[(f64, f64)]) -> Result<(f64, f64), RngError> {
+    let n: f64 = points.len() as f64;
+    let mean_z: f64 = points.iter().map(|(z, _)| z).sum::<f64>() / n;
+    let mean_y: f64 = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let sxx: f64 = points.iter().map(|(z, _)| (z - mean_z).powi(2_i32)).sum();
+    let sxy: f64 = points
+        .iter()
+        .map(|(z, y)| (z - mean_z) * (y - mean_y))
+        .sum();
+    RngError::check_positive(sxx)?;
+
+    let slope: f64 = sxy / sxx;
+    RngError::check_positive(slope)?;
+
+    Ok((mean_y - slope * mean_z, slope))
+}
+
+/// Fits `y = slope z` by least squares and checks that the slope is positive.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The slope.
+/// * `Err(RngError)` - Returns a `PositiveError` if the slope is not positive.
+fn proportional(points: &[(f64, f64)]) -> Result<f64, RngError> {
+    let szz: f64 = points.iter().map(|(z, _)| z * z).sum();
+    let szy: f64 = points.iter().map(|(z, y)| z * y).sum();
+
+    let slope: f64 = szy / szz;
+    RngError::check_positive(slope)?;
+    Ok(slope)
+}
+
+/// Transforms the targets into regression points `(z(p), y(x))`.
+///
+/// The values `x` are checked to be positive if `y` is the natural logarithm.
+fn transform(
+    targets: &[(f64, f64)],
+    z: impl Fn(f64) -> f64,
+    logarithmic: bool,
+) -> Result<Vec<(f64, f64)>, RngError> {
+    check_targets(targets)?;
+
+    let mut points: Vec<(f64, f64)> = Vec::with_capacity(targets.len());
+    for &(p, x) in targets.iter() {
+        if logarithmic {
+            RngError::check_positive(x)?;
+            points.push((z(p), math::ln(x)));
+        } else {
+            points.push((z(p), x));
+        }
+    }
+    Ok(points)
+}
+
+impl Calibrate for Normal {
+    /// Calibrates a `Normal` distribution using `x = μ + σ Φ⁻¹(p)`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Normal, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, standard_normal_quantile, false)?;
+        let (mean, std): (f64, f64) = regression(&points)?;
+        Normal::new(mean, std * std)
+    }
+}
+
+impl Calibrate for LogNormal {
+    /// Calibrates a `LogNormal` distribution using `ln x = μ + σ Φ⁻¹(p)`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<LogNormal, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, standard_normal_quantile, true)?;
+        let (mean, std): (f64, f64) = regression(&points)?;
+        LogNormal::new(mean, std * std)
+    }
+}
+
+impl Calibrate for Logistic {
+    /// Calibrates a `Logistic` distribution using `x = location + scale ln(p / (1 - p))`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Logistic, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, |p| math::ln(p / (1_f64 - p)), false)?;
+        let (location, scale): (f64, f64) = regression(&points)?;
+        Logistic::new(location, scale)
+    }
+}
+
+impl Calibrate for Gumbel {
+    /// Calibrates a `Gumbel` distribution using `x = location - scale ln(-ln(p))`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Gumbel, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, |p| -math::ln(-math::ln(p)), false)?;
+        let (location, scale): (f64, f64) = regression(&points)?;
+        Gumbel::new(location, scale)
+    }
+}
+
+impl Calibrate for Laplace {
+    /// Calibrates a `Laplace` distribution using the quantile function of the standard Laplace distribution.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Laplace, RngError> {
+        let z = |p: f64| -> f64 {
+            if p < 0.5_f64 {
+                math::ln(2_f64 * p)
+            } else {
+                -math::ln(2_f64 - 2_f64 * p)
+            }
+        };
+        let points: Vec<(f64, f64)> = transform(targets, z, false)?;
+        let (location, scale): (f64, f64) = regression(&points)?;
+        Laplace::new(location, scale)
+    }
+}
+
+impl Calibrate for Uniform {
+    /// Calibrates a `Uniform` distribution using `x = a + (b - a) p`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Uniform, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, |p| p, false)?;
+        let (a, width): (f64, f64) = regression(&points)?;
+        Uniform::new(a, a + width)
+    }
+}
+
+impl Calibrate for Exponential {
+    /// Calibrates an `Exponential` distribution using `x = -ln(1 - p) / rate`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Exponential, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, |p| -math::ln(1_f64 - p), false)?;
+        Exponential::new(1_f64 / proportional(&points)?)
+    }
+}
+
+impl Calibrate for Rayleigh {
+    /// Calibrates a `Rayleigh` distribution using `x = scale sqrt(-2 ln(1 - p))`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Rayleigh, RngError> {
+        let points: Vec<(f64, f64)> =
+            transform(targets, |p| (-2_f64 * math::ln(1_f64 - p)).sqrt(), false)?;
+        Rayleigh::new(proportional(&points)?)
+    }
+}
+
+impl Calibrate for Weibull {
+    /// Calibrates a `Weibull` distribution using `ln x = ln(scale) + ln(-ln(1 - p)) / shape`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Weibull, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, |p| math::ln(-math::ln(1_f64 - p)), true)?;
+        let (ln_scale, inverse_shape): (f64, f64) = regression(&points)?;
+        Weibull::new(1_f64 / inverse_shape, math::exp(ln_scale))
+    }
+}
+
+impl Calibrate for Pareto {
+    /// Calibrates a `Pareto` distribution using `ln x = ln(scale) - ln(1 - p) / shape`.
+    fn from_quantiles(targets: &[(f64, f64)]) -> Result<Pareto, RngError> {
+        let points: Vec<(f64, f64)> = transform(targets, |p| -math::ln(1_f64 - p), true)?;
+        let (ln_scale, inverse_shape): (f64, f64) = regression(&points)?;
+        Pareto::new(math::exp(ln_scale), 1_f64 / inverse_shape)
+    }
+}
@@ -0,0 +1,171 @@
+//! This module contains the implementation of the `Categorical` struct and its methods.
+
+use std::fmt::{Display, Formatter};
+
+use crate::auto_rng_trait;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a Categorical distribution.
+///
+/// The Categorical distribution takes the values `0, ..., k - 1` with the given probabilities.
+///
+/// This implementation precomputes the cumulative distribution function (CDF) and samples by binary search.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `probabilities` - The probabilities of the categories. Must be non-negative and sum up to 1.
+/// * `cdf` - The cumulative distribution function.
+/// * `last` - The largest category with a positive probability.
+#[derive(Debug, Clone)]
+pub struct Categorical {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The probabilities of the categories.
+    probabilities: Vec<f64>,
+
+    /// The cumulative distribution function (CDF) used for sampling.
+    cdf: Vec<f64>,
+
+    /// The largest category with a positive probability.
+    ///
+    /// This guards against rounding in the CDF when the uniform number is (almost) 1.
+    last: usize,
+}
+
+auto_rng_trait!(Categorical);
+
+impl Display for Categorical {
+    /// Formats the distribution with the probabilities of the categories, e.g. `Categorical(p=[0.2, 0.8])`.
+    ///
+    /// A precision given to the formatter, e.g. `{:.3}`, is applied to every probability.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        let probabilities: Vec<String> = self
+            .probabilities
+            .iter()
+            .map(|probability| match format.precision() {
+                Some(precision) => format!("{:.*}", precision, probability),
+                None => format!("{}", probability),
+            })
+            .collect();
+        write!(format, "Categorical(p=[{}])", probabilities.join(", "))
+    }
+}
+
+impl Categorical {
+    /// The tolerance for the sum of the probabilities to deviate from 1.
+    const TOLERANCE: f64 = 1e-9_f64;
+}
+
+impl Categorical {
+    /// Creates a new `Categorical` instance with given probabilities.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `probabilities` - A `Vec<f64>` of the probabilities of the categories. They must be non-negative and sum up to 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Categorical)` - Returns an instance of `Categorical` if the probabilities are valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no probabilities, a `NonNegativeError` if a probability is negative or an `IntervalError` if they do not sum up to 1.
+    pub fn new(probabilities: Vec<f64>) -> Result<Categorical, RngError> {
+        RngError::check_empty(&probabilities)?;
+        for &probability in probabilities.iter() {
+            RngError::check_non_negative(probability)?;
+        }
+
+        let sum: f64 = probabilities.iter().sum();
+        RngError::check_interval(sum, 1_f64 - Self::TOLERANCE, 1_f64 + Self::TOLERANCE)?;
+
+        let cdf: Vec<f64> = Self::get_cdf(&probabilities);
+        let last: usize = probabilities
+            .iter()
+            .rposition(|&probability| probability > 0_f64)
+            .unwrap_or(probabilities.len() - 1_usize);
+
+        Ok(Categorical {
+            rng: Rng::new(),
+            probabilities,
+            cdf,
+            last,
+        })
+    }
+
+    /// Creates a new `Categorical` instance with probabilities proportional to given weights.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - A `Vec<f64>` of non-negative weights of the categories.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Categorical)` - Returns an instance of `Categorical` if the weights are valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no weights, a `NonNegativeError` if a weight is negative or a `PositiveError` if all weights are 0.
+    pub fn from_weights(weights: Vec<f64>) -> Result<Categorical, RngError> {
+        RngError::check_empty(&weights)?;
+        for &weight in weights.iter() {
+            RngError::check_non_negative(weight)?;
+        }
+
+        let sum: f64 = weights.iter().sum();
+        RngError::check_positive(sum)?;
+
+        Categorical::new(weights.iter().map(|weight| weight / sum).collect())
+    }
+
+    /// Generates a random category.
+    ///
+    /// # Returns
+    ///
+    /// A `usize` between 0 and `k - 1`.
+    pub fn generate(&mut self) -> usize {
+        let uniform: f64 = self.rng.generate();
+        self.index_of(uniform)
+    }
+
+    /// Returns the probabilities of the categories.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the probabilities.
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// Maps a uniformly distributed number to a category using the CDF.
+    ///
+    /// This is the inverse transform of the distribution.
+    /// Categories with probability 0 are never returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `uniform` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// The smallest category whose CDF value is bigger than `uniform`.
+    pub(crate) fn index_of(&self, uniform: f64) -> usize {
+        self.cdf
+            .partition_point(|&value| value <= uniform)
+            .min(self.last)
+    }
+
+    /// Computes the cumulative distribution function (CDF) from the probabilities.
+    fn get_cdf(probabilities: &[f64]) -> Vec<f64> {
+        let mut sum: f64 = 0_f64;
+
+        probabilities
+            .iter()
+            .map(|probability| {
+                sum += probability;
+                sum
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,105 @@
+//! This module contains the implementation of the `ConsistentSample` struct and its methods.
+
+use crate::hashing::fnv1a;
+use crate::rng::Rng;
+
+/// A struct for coordinated sampling of entities identified by keys.
+///
+/// Every key is mapped to a deterministic priority between 0 and 1 by hashing it together with a seed.
+/// The priorities behave like independently uniformly distributed random numbers,
+/// but the same key always receives the same priority for the same seed, on every run, machine and service.
+/// This allows consistent decisions without storing or communicating them:
+/// * `include` samples every key independently with a given rate. Keys sampled at a rate are also sampled at every higher rate.
+/// * `bottom_k` selects the `k` keys with the smallest priorities, a uniformly distributed subset of size `k`.
+///
+/// # Fields
+///
+/// * `seed` - The seed mixed into every hash. Different seeds result in independent samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsistentSample {
+    /// The seed mixed into every hash.
+    seed: u64,
+}
+
+impl ConsistentSample {
+    /// The factor `2^(-53)` to map the upper 53 bits of a hash to [0, 1).
+    const INV_2_POW_53: f64 = 1_f64 / (1_u64 << 53_u32) as f64;
+}
+
+impl ConsistentSample {
+    /// Creates a new `ConsistentSample` instance with a given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` mixed into every hash.
+    ///
+    /// # Returns
+    ///
+    /// A `ConsistentSample` instance.
+    pub fn new(seed: u64) -> ConsistentSample {
+        ConsistentSample { seed }
+    }
+
+    /// Returns the seed mixed into every hash.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Calculates the priority of a key.
+    ///
+    /// The key is hashed with FNV-1a, combined with the seed and mixed with the SplitMix64 finalizer.
+    /// The upper 53 bits of the result are mapped to [0, 1).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key, e.g. a `&str` or a byte slice.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` in [0, 1).
+    pub fn priority<K: AsRef<[u8]>>(&self, key: K) -> f64 {
+        let hash: u64 = Rng::mix64(fnv1a(key.as_ref()) ^ Rng::mix64(self.seed));
+        (hash >> 11_u32) as f64 * Self::INV_2_POW_53
+    }
+
+    /// Decides if a key is part of the sample.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key, e.g. a `&str` or a byte slice.
+    /// * `rate` - A `f64` representing the sampling rate between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the priority of the key is smaller than `rate`, which happens with probability `rate`.
+    pub fn include<K: AsRef<[u8]>>(&self, key: K, rate: f64) -> bool {
+        self.priority(key) < rate
+    }
+
+    /// Selects the `k` keys with the smallest priorities (bottom-k sample).
+    ///
+    /// If a key occurs multiple times, all occurrences are treated as separate elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to sample from.
+    /// * `k` - A `usize` representing the size of the sample.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of at most `k` keys, sorted by increasing priority.
+    pub fn bottom_k<K: AsRef<[u8]>, I: IntoIterator<Item = K>>(&self, keys: I, k: usize) -> Vec<K> {
+        let mut prioritized: Vec<(f64, K)> = keys
+            .into_iter()
+            .map(|key| (self.priority(&key), key))
+            .collect();
+
+        prioritized.sort_by(|a, b| a.0.total_cmp(&b.0));
+        prioritized.truncate(k);
+        prioritized.into_iter().map(|(_, key)| key).collect()
+    }
+}
@@ -0,0 +1,130 @@
+//! This module contains the implementation of the `GaussianCopula` struct and its methods.
+
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::auto_seed_methods;
+use crate::auxiliary::{cholesky, standard_normal_cdf};
+use crate::quantile::Quantile;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating correlated random vectors with given marginal distributions using a Gaussian copula.
+///
+/// A vector is generated by
+/// ```text
+/// Z = L N
+/// Uᵢ = Φ(Zᵢ)
+/// Xᵢ = Qᵢ(Uᵢ)
+/// ```
+/// where `N` is a vector of independently standard normal distributed random variables,
+/// `L` is the Cholesky factor of the correlation matrix, `Φ` is the distribution function of the standard Normal distribution
+/// and `Qᵢ` is the quantile function of the i-th marginal distribution.
+/// Therefore, every component follows its marginal distribution, while the dependence is given by the correlation matrix.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `cholesky` - The Cholesky factor `L` of the correlation matrix.
+/// * `marginals` - The marginal distributions.
+///
+/// # Notes
+///
+/// The correlation matrix describes the correlation of the underlying normal variables `Z`.
+/// The (Pearson) correlation of the components `X` is in general slightly smaller in absolute value,
+/// while the rank correlations are preserved.
+#[derive(Clone)]
+pub struct GaussianCopula {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The Cholesky factor of the correlation matrix.
+    cholesky: Vec<Vec<f64>>,
+
+    /// The marginal distributions.
+    marginals: Vec<Rc<dyn Quantile>>,
+}
+
+auto_seed_methods!(GaussianCopula);
+
+impl Debug for GaussianCopula {
+    /// Formats the parameters of the `GaussianCopula`, leaving out the marginal distributions.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("GaussianCopula")
+            .field("rng", &self.rng)
+            .field("cholesky", &self.cholesky)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GaussianCopula {
+    /// Creates a new `GaussianCopula` instance with a given correlation matrix and marginal distributions.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `correlation` - A symmetric positive definite `d x d` matrix with ones on the diagonal.
+    /// * `marginals` - A `Vec` of `d` distributions implementing `Quantile`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GaussianCopula)` - Returns an instance of `GaussianCopula` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no marginals, an `IntervalError` if the dimensions do not match, a diagonal entry is not 1 or the matrix is not symmetric, or a `PositiveError` if the matrix is not positive definite.
+    pub fn new(
+        correlation: Vec<Vec<f64>>,
+        marginals: Vec<Box<dyn Quantile>>,
+    ) -> Result<GaussianCopula, RngError> {
+        RngError::check_empty(&marginals)?;
+
+        let d: f64 = marginals.len() as f64;
+        RngError::check_interval(correlation.len() as f64, d, d)?;
+        for (i, row) in correlation.iter().enumerate() {
+            RngError::check_interval(row.len() as f64, d, d)?;
+            RngError::check_interval(row[i], 1_f64, 1_f64)?;
+            for (j, &entry) in row.iter().enumerate().take(i) {
+                RngError::check_interval(entry, correlation[j][i], correlation[j][i])?;
+            }
+        }
+
+        Ok(GaussianCopula {
+            rng: Rng::new(),
+            cholesky: cholesky(&correlation)?,
+            marginals: marginals.into_iter().map(Rc::from).collect(),
+        })
+    }
+
+    /// Generates a random vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `d`, where the i-th component follows the i-th marginal distribution.
+    pub fn generate(&mut self) -> Vec<f64> {
+        let normals: Vec<f64> = (0_usize..self.marginals.len())
+            .map(|_| self.rng.gen_standard_normal())
+            .collect();
+
+        self.cholesky
+            .iter()
+            .zip(self.marginals.iter())
+            .map(|(row, marginal)| {
+                let z: f64 = row.iter().zip(normals.iter()).map(|(l, n)| l * n).sum();
+                marginal.quantile(standard_normal_cdf(z))
+            })
+            .collect()
+    }
+
+    /// Generates multiple random vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` representing the number of vectors.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Vec<f64>>` of `number` random vectors.
+    pub fn generate_multiple(&mut self, number: usize) -> Vec<Vec<f64>> {
+        (0_usize..number).map(|_| self.generate()).collect()
+    }
+}
@@ -0,0 +1,100 @@
+//! This module contains the implementation of the `CorrelatedCounts` struct and its methods.
+
+use crate::copula::GaussianCopula;
+use crate::poisson::Poisson;
+use crate::quantile::Quantile;
+use crate::rng_error::RngError;
+
+/// A struct for generating vectors of correlated Poisson distributed counts.
+///
+/// The counts are generated by a `GaussianCopula` with `Poisson` marginals,
+/// i.e. correlated standard normal variables are mapped through the Poisson quantile functions.
+/// Every component is exactly Poisson distributed with its rate, while the counts are positively or negatively dependent.
+///
+/// # Fields
+///
+/// * `copula` - The Gaussian copula with Poisson marginals.
+///
+/// # Notes
+///
+/// The correlation matrix describes the correlation of the underlying normal variables.
+/// The correlation of the counts is smaller in absolute value, especially for small rates.
+pub struct CorrelatedCounts {
+    /// The Gaussian copula with Poisson marginals.
+    copula: GaussianCopula,
+}
+
+impl CorrelatedCounts {
+    /// Creates a new `CorrelatedCounts` instance with given rates and correlation matrix.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rates` - A `Vec<f64>` of the rates (λ) of the `d` counts. They must be positive numbers.
+    /// * `correlation` - A symmetric positive definite `d x d` matrix with ones on the diagonal.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CorrelatedCounts)` - Returns an instance of `CorrelatedCounts` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no rates, a `PositiveError` if a rate is not positive or the matrix is not positive definite, or an `IntervalError` if the dimensions do not match, a diagonal entry is not 1 or the matrix is not symmetric.
+    pub fn new(rates: Vec<f64>, correlation: Vec<Vec<f64>>) -> Result<CorrelatedCounts, RngError> {
+        let mut marginals: Vec<Box<dyn Quantile>> = Vec::with_capacity(rates.len());
+        for rate in rates {
+            marginals.push(Box::new(Poisson::new(rate)?));
+        }
+
+        Ok(CorrelatedCounts {
+            copula: GaussianCopula::new(correlation, marginals)?,
+        })
+    }
+
+    /// Generates a random vector of counts.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<i32>` of length `d`, where the i-th count is Poisson distributed with the i-th rate.
+    pub fn generate(&mut self) -> Vec<i32> {
+        self.copula
+            .generate()
+            .into_iter()
+            .map(|count| count as i32)
+            .collect()
+    }
+
+    /// Generates multiple random vectors of counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` representing the number of vectors.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Vec<i32>>` of `number` random vectors.
+    pub fn generate_multiple(&mut self, number: usize) -> Vec<Vec<i32>> {
+        (0_usize..number).map(|_| self.generate()).collect()
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.copula.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.copula.set_seed(seed);
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.copula.restart();
+    }
+}
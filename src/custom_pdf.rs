@@ -0,0 +1,137 @@
+//! This module contains the implementation of the `CustomPdf` struct and its methods.
+
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::auto_display;
+use crate::auto_rng_trait;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from an arbitrary density given by a closure.
+///
+/// The values are generated by rejection sampling with a uniform envelope:
+/// A candidate `X` is uniformly distributed on the support [a, b] and accepted if
+/// ```text
+/// U M <= f(X)
+/// ```
+/// where `U` is uniformly distributed on [0, 1] and `M` is the envelope bound.
+/// Otherwise, a new candidate is generated.
+///
+/// The density does not need to be normalized.
+/// On average `M (b - a) / ∫f` candidates are needed for one value,
+/// so the bound should be as close to the maximum of `f` as possible.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `pdf` - The (unnormalized) density. Must be non-negative on the support.
+/// * `a` - The lower bound of the support.
+/// * `b` - The upper bound of the support. Must be bigger than a.
+/// * `bound` - The envelope bound `M`. Must be a positive number.
+///
+/// # Notes
+///
+/// If the density exceeds the bound somewhere, the generated values are not distributed according to the density.
+/// If the density is 0 almost everywhere on the support, `generate` never returns.
+#[derive(Clone)]
+pub struct CustomPdf {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The (unnormalized) density.
+    pdf: Rc<dyn Fn(f64) -> f64>,
+
+    /// The lower bound of the support.
+    a: f64,
+
+    /// The upper bound of the support.
+    b: f64,
+
+    /// The envelope bound.
+    bound: f64,
+}
+
+auto_rng_trait!(CustomPdf);
+auto_display!(CustomPdf { "a" = a, "b" = b });
+
+impl Debug for CustomPdf {
+    /// Formats the parameters of the `CustomPdf`, leaving out the density.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("CustomPdf")
+            .field("rng", &self.rng)
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("bound", &self.bound)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CustomPdf {
+    /// Creates a new `CustomPdf` instance with a given density, support and envelope bound.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pdf` - A closure representing the (unnormalized) density. It must be non-negative on the support.
+    /// * `a` - A `f64` representing the lower bound of the support.
+    /// * `b` - A `f64` representing the upper bound of the support. It must be bigger than a.
+    /// * `bound` - A `f64` representing the envelope bound. It must be at least the maximum of the density on the support.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CustomPdf)` - Returns an instance of `CustomPdf` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `OrderError` if `a` is not smaller than `b` or a `PositiveError` if the bound is less than or equal to 0.
+    pub fn new<F: Fn(f64) -> f64 + 'static>(
+        pdf: F,
+        a: f64,
+        b: f64,
+        bound: f64,
+    ) -> Result<CustomPdf, RngError> {
+        RngError::check_order(a, b)?;
+        RngError::check_positive(bound)?;
+
+        Ok(CustomPdf {
+            rng: Rng::new(),
+            pdf: Rc::new(pdf),
+            a,
+            b,
+            bound,
+        })
+    }
+
+    /// Generates a random value from the distribution with the given density.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value between `a` and `b`.
+    pub fn generate(&mut self) -> f64 {
+        loop {
+            let x: f64 = self.a + (self.b - self.a) * self.rng.generate();
+            let y: f64 = self.bound * self.rng.generate();
+
+            if y <= (self.pdf)(x) {
+                return x;
+            }
+        }
+    }
+
+    /// Evaluates the (unnormalized) density.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`, which is 0 outside of the support.
+    pub fn pdf(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            0_f64
+        } else {
+            (self.pdf)(x)
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! This module contains the implementation of the `Degenerate` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a degenerate distribution, i.e. a constant.
+///
+/// Every generated value is `c`.
+/// This makes it possible to switch off the randomness of a parameter in generic simulation code,
+/// e.g. a fixed lead time in place of a random one, without special-casing it.
+/// The random number generator is never advanced, but the struct has one to implement `RngTrait`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng`, which is kept for the seed methods only.
+/// * `value` - The constant value (c) of the distribution.
+#[derive(Debug, Clone)]
+pub struct Degenerate {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The constant value (c) of the distribution.
+    value: f64,
+}
+
+auto_rng_trait!(Degenerate);
+auto_builder!(Degenerate, DegenerateBuilder { value: f64 });
+auto_params!(Degenerate { value: f64 });
+auto_display!(Degenerate { "c" = value });
+
+impl Degenerate {
+    /// Creates a new `Degenerate` instance with a given constant.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A `f64` representing the constant value (c) of the distribution.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Degenerate)` - Returns an instance of `Degenerate` if the value is finite.
+    /// * `Err(RngError)` - Returns a `NotFiniteError` if the value is infinite or NaN.
+    pub fn new(value: f64) -> Result<Degenerate, RngError> {
+        RngError::check_finite(value)?;
+
+        Ok(Degenerate {
+            rng: Rng::new(),
+            value,
+        })
+    }
+
+    /// Generates a value from the degenerate distribution.
+    ///
+    /// # Returns
+    ///
+    /// The constant value `c` as a `f64`.
+    pub fn generate(&mut self) -> f64 {
+        self.value
+    }
+}
+
+impl Default for Degenerate {
+    /// Creates a `Degenerate` instance with the constant 0.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Degenerate::new(0_f64).unwrap()
+    }
+}
+
+impl Quantile for Degenerate {
+    /// Evaluates the quantile function of the degenerate distribution, which is `c` for every probability.
+    fn quantile(&self, _p: f64) -> f64 {
+        self.value
+    }
+}
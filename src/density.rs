@@ -0,0 +1,238 @@
+//! This module contains the `Density` trait and the data structures used to plot distributions.
+
+use crate::math;
+use crate::quantile::Quantile;
+
+/// The probability left out in each tail by `Density::plot_range`.
+const PLOT_TAIL: f64 = 1e-3_f64;
+
+/// The number of quadrature points used by `Density::entropy`.
+pub(crate) const QUADRATURE_POINTS: usize = 100_000_usize;
+
+/// The points of the density and the distribution function of a distribution, ready to be plotted.
+///
+/// # Fields
+///
+/// * `pdf` - The points `(x, f(x))` of the density.
+/// * `cdf` - The points `(x, F(x))` of the distribution function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlotData {
+    /// The points of the density.
+    pub pdf: Vec<(f64, f64)>,
+
+    /// The points of the distribution function.
+    pub cdf: Vec<(f64, f64)>,
+}
+
+/// A bin of a histogram together with the density of the distribution on it.
+///
+/// # Fields
+///
+/// * `left` - The left edge of the bin.
+/// * `right` - The right edge of the bin.
+/// * `count` - The number of samples in the bin.
+/// * `empirical_density` - The height of the normalized histogram, i.e. `count / (n (right - left))`.
+/// * `expected_density` - The average density of the distribution on the bin, i.e. `(F(right) - F(left)) / (right - left)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramBin {
+    /// The left edge of the bin.
+    pub left: f64,
+
+    /// The right edge of the bin.
+    pub right: f64,
+
+    /// The number of samples in the bin.
+    pub count: usize,
+
+    /// The height of the normalized histogram.
+    pub empirical_density: f64,
+
+    /// The average density of the distribution on the bin.
+    pub expected_density: f64,
+}
+
+/// A histogram of samples overlaid with the density of a distribution.
+///
+/// # Fields
+///
+/// * `bins` - The bins of equal width between the smallest and the largest sample.
+/// * `density` - The points `(x, f(x))` of the density over the range of the histogram.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramOverlay {
+    /// The bins of the histogram.
+    pub bins: Vec<HistogramBin>,
+
+    /// The points of the density.
+    pub density: Vec<(f64, f64)>,
+}
+
+/// A trait for continuous distributions with a known density and distribution function.
+///
+/// Besides the evaluation of the functions, it provides point series for plotting frontends,
+/// so they can visualize any distribution without reimplementing the formulas.
+pub trait Density: Quantile {
+    /// Evaluates the probability density function (PDF).
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A non-negative `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64;
+
+    /// Evaluates the cumulative distribution function (CDF).
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` between 0 and 1 representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Evaluates the natural logarithm of the probability density function.
+    ///
+    /// Far in the tails the density underflows to 0, although its logarithm is a moderate number.
+    /// This is needed for likelihoods of many samples, e.g. when fitting or in Markov chain Monte Carlo.
+    /// Distributions with a closed form of the logarithm override the default `pdf(x).ln()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`. It is negative infinity outside the support.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        math::ln(self.pdf(x))
+    }
+
+    /// Calculates the differential entropy in nats.
+    ///
+    /// Substituting `x = Q(u)` turns the entropy into an integral over the probabilities
+    /// ```text
+    /// H = -∫ f(x) ln f(x) dx = -∫₀¹ ln f(Q(u)) du
+    /// ```
+    /// which is approximated by the midpoint rule, so unbounded supports need no truncation.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the entropy. It can be negative for concentrated distributions.
+    fn entropy(&self) -> f64 {
+        -quadrature(|u| self.ln_pdf(self.quantile(u)))
+    }
+
+    /// Returns a range containing the bulk of the distribution.
+    ///
+    /// This is the interval between the 0.1 % and the 99.9 % quantile, so heavy tails do not squeeze the plot.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(low, high)` of the bounds of the range.
+    fn plot_range(&self) -> (f64, f64) {
+        (self.quantile(PLOT_TAIL), self.quantile(1_f64 - PLOT_TAIL))
+    }
+
+    /// Evaluates the density and the distribution function on equidistant points of the plot range.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_points` - A `usize` representing the number of points of each series.
+    ///
+    /// # Returns
+    ///
+    /// A `PlotData` with the points of the density and the distribution function.
+    fn plot_data(&self, n_points: usize) -> PlotData {
+        let (low, high) = self.plot_range();
+        let xs: Vec<f64> = grid(low, high, n_points);
+
+        PlotData {
+            pdf: xs.iter().map(|&x| (x, self.pdf(x))).collect(),
+            cdf: xs.iter().map(|&x| (x, self.cdf(x))).collect(),
+        }
+    }
+
+    /// Bins samples into a normalized histogram and compares it with the density.
+    ///
+    /// The bins have equal width and span the range from the smallest to the largest sample.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` samples.
+    /// * `n_bins` - A `usize` representing the number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A `HistogramOverlay` with the bins and the density evaluated at `4 n_bins` points.
+    /// If there are no samples or no bins, both are empty.
+    fn histogram_overlay(&self, samples: &[f64], n_bins: usize) -> HistogramOverlay {
+        if samples.is_empty() || n_bins == 0_usize {
+            return HistogramOverlay {
+                bins: Vec::new(),
+                density: Vec::new(),
+            };
+        }
+
+        let low: f64 = samples.iter().copied().fold(f64::INFINITY, f64::min);
+        let mut high: f64 = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if high <= low {
+            high = low + 1_f64;
+        }
+        let width: f64 = (high - low) / n_bins as f64;
+
+        let mut counts: Vec<usize> = vec![0_usize; n_bins];
+        for &sample in samples {
+            let bin: usize = (((sample - low) / width) as usize).min(n_bins - 1_usize);
+            counts[bin] += 1_usize;
+        }
+
+        let bins: Vec<HistogramBin> = counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let left: f64 = low + i as f64 * width;
+                let right: f64 = left + width;
+                HistogramBin {
+                    left,
+                    right,
+                    count,
+                    empirical_density: count as f64 / (samples.len() as f64 * width),
+                    expected_density: (self.cdf(right) - self.cdf(left)) / width,
+                }
+            })
+            .collect();
+
+        HistogramOverlay {
+            bins,
+            density: grid(low, high, 4_usize * n_bins)
+                .into_iter()
+                .map(|x| (x, self.pdf(x)))
+                .collect(),
+        }
+    }
+}
+
+/// Integrates a function over [0, 1] with the midpoint rule on `QUADRATURE_POINTS` points.
+pub(crate) fn quadrature<F: Fn(f64) -> f64>(function: F) -> f64 {
+    let n: f64 = QUADRATURE_POINTS as f64;
+
+    (0_usize..QUADRATURE_POINTS)
+        .map(|i| function((i as f64 + 0.5_f64) / n))
+        .sum::<f64>()
+        / n
+}
+
+/// Returns `n` equidistant points from `low` to `high`, including both.
+fn grid(low: f64, high: f64, n: usize) -> Vec<f64> {
+    match n {
+        0_usize => Vec::new(),
+        1_usize => vec![0.5_f64 * (low + high)],
+        _ => {
+            let step: f64 = (high - low) / (n - 1_usize) as f64;
+            (0_usize..n).map(|i| low + i as f64 * step).collect()
+        }
+    }
+}
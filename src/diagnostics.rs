@@ -0,0 +1,295 @@
+//! This module contains online health checks for streams of random numbers.
+//!
+//! A misconfigured seed or a broken generator backend often produces output that is obviously degenerate,
+//! e.g. constant, periodic or restricted to a few values.
+//! The `EntropyMonitor` consumes such a stream byte by byte, without storing it,
+//! and reports the statistics of the `ent` test suite together with alerts for suspicious values.
+//! It is no replacement for a full test battery, but cheap enough to run alongside a simulation.
+
+use crate::auxiliary::standard_normal_cdf;
+use crate::math;
+use crate::rng::Rng;
+
+/// The number of bytes needed before the statistics are judged.
+const MIN_BYTES: u64 = 4_096_u64;
+
+/// The entropy in bits per byte below which an alert is raised.
+const MIN_ENTROPY: f64 = 7.5_f64;
+
+/// The number of standard errors the serial correlation may deviate from 0 before an alert is raised.
+const MAX_CORRELATION_ERRORS: f64 = 5_f64;
+
+/// The p-value of the chi-square test below which an alert is raised.
+const MIN_P_VALUE: f64 = 1e-6_f64;
+
+/// A suspicious property of a stream found by an `EntropyMonitor`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HealthAlert {
+    /// Fewer bytes than needed for a reliable judgement were consumed.
+    InsufficientData { n_bytes: u64 },
+
+    /// The entropy in bits per byte is too low.
+    LowEntropy { entropy: f64 },
+
+    /// Consecutive bytes are correlated.
+    SerialCorrelation { correlation: f64 },
+
+    /// The frequencies of the byte values are not uniform.
+    ChiSquare { statistic: f64, p_value: f64 },
+}
+
+/// The statistics of a stream reported by an `EntropyMonitor`.
+///
+/// # Fields
+///
+/// * `n_bytes` - The number of consumed bytes.
+/// * `entropy` - The empirical entropy in bits per byte. It is 8 for a perfect stream.
+/// * `serial_correlation` - The correlation of consecutive bytes. It is close to 0 for a perfect stream.
+/// * `chi_square` - The chi-square statistic of the byte frequencies with 255 degrees of freedom.
+/// * `p_value` - The probability of a chi-square statistic at least as big for a perfect stream.
+/// * `alerts` - The suspicious properties of the stream. It is empty for a healthy stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthReport {
+    /// The number of consumed bytes.
+    pub n_bytes: u64,
+
+    /// The empirical entropy in bits per byte.
+    pub entropy: f64,
+
+    /// The correlation of consecutive bytes.
+    pub serial_correlation: f64,
+
+    /// The chi-square statistic of the byte frequencies.
+    pub chi_square: f64,
+
+    /// The p-value of the chi-square statistic.
+    pub p_value: f64,
+
+    /// The suspicious properties of the stream.
+    pub alerts: Vec<HealthAlert>,
+}
+
+impl HealthReport {
+    /// Returns whether the stream looks healthy.
+    ///
+    /// # Returns
+    ///
+    /// `true` if there are no alerts.
+    pub fn is_healthy(&self) -> bool {
+        self.alerts.is_empty()
+    }
+}
+
+/// A struct for monitoring the health of a stream of random bytes online.
+///
+/// Raw generator output, arbitrary bytes and samples from [0, 1] can be consumed in any mix.
+/// Only the frequencies of the byte values and a few sums are stored, so the memory is constant.
+///
+/// # Fields
+///
+/// * `counts` - The number of occurrences of every byte value.
+/// * `n_bytes` - The number of consumed bytes.
+/// * `sum` - The sum of the bytes.
+/// * `sum_squares` - The sum of the squared bytes.
+/// * `sum_products` - The sum of the products of consecutive bytes.
+/// * `first` - The first byte.
+/// * `last` - The last byte.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntropyMonitor {
+    /// The number of occurrences of every byte value.
+    counts: [u64; 256],
+
+    /// The number of consumed bytes.
+    n_bytes: u64,
+
+    /// The sum of the bytes.
+    sum: f64,
+
+    /// The sum of the squared bytes.
+    sum_squares: f64,
+
+    /// The sum of the products of consecutive bytes.
+    sum_products: f64,
+
+    /// The first byte.
+    first: u8,
+
+    /// The last byte.
+    last: u8,
+}
+
+impl Default for EntropyMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntropyMonitor {
+    /// Creates a new `EntropyMonitor` instance which has not consumed anything.
+    ///
+    /// # Returns
+    ///
+    /// An `EntropyMonitor` instance.
+    pub fn new() -> EntropyMonitor {
+        EntropyMonitor {
+            counts: [0_u64; 256],
+            n_bytes: 0_u64,
+            sum: 0_f64,
+            sum_squares: 0_f64,
+            sum_products: 0_f64,
+            first: 0_u8,
+            last: 0_u8,
+        }
+    }
+
+    /// Consumes bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - A slice of bytes of the stream.
+    pub fn update_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let value: f64 = byte as f64;
+
+            if self.n_bytes == 0_u64 {
+                self.first = byte;
+            } else {
+                self.sum_products += self.last as f64 * value;
+            }
+            self.counts[byte as usize] += 1_u64;
+            self.n_bytes += 1_u64;
+            self.sum += value;
+            self.sum_squares += value * value;
+            self.last = byte;
+        }
+    }
+
+    /// Consumes a raw 64 bit output as eight bytes in little-endian order.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A `u64` of the stream.
+    pub fn update_u64(&mut self, value: u64) {
+        self.update_bytes(&value.to_le_bytes());
+    }
+
+    /// Consumes samples from [0, 1], each quantized to one byte by `floor(256 x)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` between 0 and 1. Values outside are clamped.
+    pub fn update_samples(&mut self, samples: &[f64]) {
+        let bytes: Vec<u8> = samples
+            .iter()
+            .map(|&sample| (256_f64 * sample).clamp(0_f64, 255_f64) as u8)
+            .collect();
+        self.update_bytes(&bytes);
+    }
+
+    /// Consumes the raw output of a random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator. It is advanced by the consumed values.
+    /// * `n` - A `usize` of the number of 64 bit values to consume.
+    pub fn update_rng(&mut self, rng: &mut Rng, n: usize) {
+        for _ in 0_usize..n {
+            self.update_u64(rng.next_u64());
+        }
+    }
+
+    /// Returns the number of consumed bytes.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes as a `u64`.
+    pub fn n_bytes(&self) -> u64 {
+        self.n_bytes
+    }
+
+    /// Calculates the statistics of the consumed stream and judges them.
+    ///
+    /// With the frequencies `c_k` of the byte values `k` and `n` bytes the statistics are
+    /// ```text
+    /// entropy = - Σ c_k / n log₂(c_k / n)
+    /// chi_square = Σ (c_k - n / 256)² / (n / 256)
+    /// serial_correlation = (n Σ x_i x_{i+1} - (Σ x_i)²) / (n Σ x_i² - (Σ x_i)²)
+    /// ```
+    /// where the last byte is paired with the first one.
+    /// The p-value uses the Wilson-Hilferty approximation of the chi-square distribution.
+    ///
+    /// # Returns
+    ///
+    /// A `HealthReport` with the statistics and alerts.
+    pub fn report(&self) -> HealthReport {
+        let n: f64 = self.n_bytes as f64;
+        let expected: f64 = n / 256_f64;
+
+        let mut entropy: f64 = 0_f64;
+        let mut chi_square: f64 = 0_f64;
+        for &count in self.counts.iter() {
+            if count > 0_u64 {
+                let frequency: f64 = count as f64 / n;
+                entropy -= frequency * math::log2(frequency);
+            }
+            let deviation: f64 = count as f64 - expected;
+            chi_square += deviation * deviation;
+        }
+        if self.n_bytes > 0_u64 {
+            chi_square /= expected;
+        }
+
+        let sum_products: f64 = self.sum_products + self.last as f64 * self.first as f64;
+        let denominator: f64 = n * self.sum_squares - self.sum * self.sum;
+        let serial_correlation: f64 = if denominator > 0_f64 {
+            (n * sum_products - self.sum * self.sum) / denominator
+        } else {
+            // A constant stream is perfectly correlated
+            1_f64
+        };
+
+        let p_value: f64 = chi_square_p_value(chi_square, 255_f64);
+
+        let mut alerts: Vec<HealthAlert> = Vec::new();
+        if self.n_bytes < MIN_BYTES {
+            alerts.push(HealthAlert::InsufficientData {
+                n_bytes: self.n_bytes,
+            });
+        } else {
+            if entropy < MIN_ENTROPY {
+                alerts.push(HealthAlert::LowEntropy { entropy });
+            }
+            if serial_correlation.abs() > MAX_CORRELATION_ERRORS / n.sqrt() {
+                alerts.push(HealthAlert::SerialCorrelation {
+                    correlation: serial_correlation,
+                });
+            }
+            if p_value < MIN_P_VALUE {
+                alerts.push(HealthAlert::ChiSquare {
+                    statistic: chi_square,
+                    p_value,
+                });
+            }
+        }
+
+        HealthReport {
+            n_bytes: self.n_bytes,
+            entropy,
+            serial_correlation,
+            chi_square,
+            p_value,
+            alerts,
+        }
+    }
+}
+
+/// Approximates the upper tail probability of the chi-square distribution.
+///
+/// By Wilson and Hilferty `(X / k)^(1/3)` is approximately Normal distributed
+/// with mean `1 - 2 / (9 k)` and variance `2 / (9 k)`.
+fn chi_square_p_value(statistic: f64, degrees_of_freedom: f64) -> f64 {
+    let variance: f64 = 2_f64 / (9_f64 * degrees_of_freedom);
+    let z: f64 =
+        (math::cbrt(statistic / degrees_of_freedom) - (1_f64 - variance)) / variance.sqrt();
+    1_f64 - standard_normal_cdf(z)
+}
@@ -0,0 +1,115 @@
+//! This module contains the `DiscreteDistribution` trait.
+
+use std::ops::RangeInclusive;
+
+use crate::math;
+
+/// The probability mass left out in the tail of an unbounded support by `DiscreteDistribution::pmf_table`.
+const TABLE_TAIL: f64 = 1e-12_f64;
+
+/// A trait for discrete distributions on the integers with a known probability mass function.
+///
+/// Generic code, e.g. computing the entropy or exact tests, can work on the table of probabilities
+/// of any implementing distribution without knowing its formulas.
+pub trait DiscreteDistribution {
+    /// Evaluates the probability mass function (PMF).
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - An `i32` at which the probability is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` between 0 and 1 representing `P(X = k)`. It is 0 outside the support.
+    fn pmf(&self, k: i32) -> f64;
+
+    /// Returns the smallest range containing all values with a positive probability.
+    ///
+    /// # Returns
+    ///
+    /// A `RangeInclusive<i32>` of the support. For unbounded supports the upper end is `i32::MAX`.
+    fn support(&self) -> RangeInclusive<i32>;
+
+    /// Evaluates the natural logarithm of the probability mass function.
+    ///
+    /// Probabilities far in the tails underflow to 0, although their logarithm is a moderate number.
+    /// Distributions with a closed form of the logarithm override the default `pmf(k).ln()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - An `i32` at which the logarithm of the probability is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln P(X = k)`. It is negative infinity outside the support.
+    fn ln_pmf(&self, k: i32) -> f64 {
+        math::ln(self.pmf(k))
+    }
+
+    /// Evaluates the cumulative distribution function (CDF) by summing up the probabilities.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - An `i32` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` between 0 and 1 representing `P(X <= k)`.
+    fn cdf(&self, k: i32) -> f64 {
+        let support: RangeInclusive<i32> = self.support();
+        let end: i32 = k.min(*support.end());
+
+        (*support.start()..=end)
+            .map(|j| self.pmf(j))
+            .sum::<f64>()
+            .min(1_f64)
+    }
+
+    /// Calculates the entropy in nats.
+    ///
+    /// ```text
+    /// H = -Σ P(X = k) ln P(X = k)
+    /// ```
+    /// The sum runs over the values of `pmf_table`.
+    ///
+    /// # Returns
+    ///
+    /// A non-negative `f64` representing the entropy.
+    fn entropy(&self) -> f64 {
+        -self
+            .pmf_table()
+            .iter()
+            .filter(|(_, probability)| *probability > 0_f64)
+            .map(|(_, probability)| probability * math::ln(*probability))
+            .sum::<f64>()
+    }
+
+    /// Computes the table of the probabilities on the support.
+    ///
+    /// The table is computed on demand and not stored by the distribution.
+    /// For unbounded supports the table ends as soon as the remaining probability is below `1e-12`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(i32, f64)>` of the values `k` in increasing order together with `P(X = k)`.
+    fn pmf_table(&self) -> Vec<(i32, f64)> {
+        let support: RangeInclusive<i32> = self.support();
+        let bounded: bool = *support.end() < i32::MAX;
+
+        let mut table: Vec<(i32, f64)> = Vec::new();
+        let mut total: f64 = 0_f64;
+        for k in support {
+            let probability: f64 = self.pmf(k);
+            table.push((k, probability));
+            total += probability;
+
+            // Beyond the bulk the sum can not grow anymore due to rounding
+            if !bounded
+                && (total >= 1_f64 - TABLE_TAIL || (probability == 0_f64 && total > 0.5_f64))
+            {
+                break;
+            }
+        }
+        table
+    }
+}
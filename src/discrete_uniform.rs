@@ -0,0 +1,187 @@
+//! This module contains the implementation of the `DiscreteUniform` struct and its methods.
+
+use std::fmt::{Display, Formatter};
+
+use crate::auto_builder;
+use crate::auto_rng_trait;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a discrete uniform distribution on an equidistant grid.
+///
+/// The values
+/// ```text
+/// low, low + step, low + 2 step, ..., low + (n - 1) step
+/// ```
+/// with `n = floor((high - low) / step) + 1` all have the probability `1 / n`.
+/// The upper bound `high` is included, if it lies on the grid.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `low` - The smallest value.
+/// * `step` - The distance between two neighbouring values. Must be a positive number.
+/// * `n` - The number of values.
+#[derive(Debug, Clone)]
+pub struct DiscreteUniform {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The smallest value.
+    low: f64,
+
+    /// The distance between two neighbouring values.
+    step: f64,
+
+    /// The number of values.
+    n: u64,
+}
+
+auto_rng_trait!(DiscreteUniform);
+auto_builder!(
+    DiscreteUniform,
+    DiscreteUniformBuilder {
+        low: f64,
+        high: f64,
+        step: f64
+    }
+);
+
+impl DiscreteUniform {
+    /// The relative tolerance for `high` to lie on the grid despite rounding errors.
+    const TOLERANCE: f64 = 1e-9_f64;
+}
+
+impl DiscreteUniform {
+    /// Creates a new `DiscreteUniform` instance with given bounds and step.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - A `f64` representing the smallest value.
+    /// * `high` - A `f64` representing the upper bound. It must be bigger than `low`.
+    /// * `step` - A `f64` representing the distance between two neighbouring values. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DiscreteUniform)` - Returns an instance of `DiscreteUniform` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `OrderError` if `low` is not smaller than `high` or a `PositiveError` if `step` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(low: f64, high: f64, step: f64) -> Result<DiscreteUniform, RngError> {
+        RngError::check_finite(low)?;
+        RngError::check_finite(high)?;
+        RngError::check_finite(step)?;
+        RngError::check_order(low, high)?;
+        RngError::check_positive(step)?;
+
+        let n: u64 = ((high - low) / step + Self::TOLERANCE).floor() as u64 + 1_u64;
+
+        Ok(DiscreteUniform {
+            rng: Rng::new(),
+            low,
+            step,
+            n,
+        })
+    }
+
+    /// Generates a random value from the discrete uniform distribution.
+    ///
+    /// The index of the value is generated by rejection from the raw output of the generator,
+    /// so all values have exactly the same probability.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value of the grid.
+    pub fn generate(&mut self) -> f64 {
+        let index: u64 = self.rng.gen_below(self.n);
+        self.low + index as f64 * self.step
+    }
+
+    /// Returns the number of values of the grid.
+    ///
+    /// # Returns
+    ///
+    /// The number of values as a `u64`.
+    pub fn n_values(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the parameters of the distribution in the order of the `new` constructor.
+    ///
+    /// The upper bound is the largest value of the grid, which can be smaller than the `high` given to `new`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of `(low, high, step)`.
+    pub fn params(&self) -> (f64, f64, f64) {
+        let high: f64 = self.low + (self.n - 1_u64) as f64 * self.step;
+        (self.low, high, self.step)
+    }
+
+    /// Changes the parameters of the distribution.
+    ///
+    /// The random number generator keeps its state, so parameters can be swept
+    /// without rebuilding the distribution and reseeding in the middle of a run.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - A `f64` representing the new smallest value.
+    /// * `high` - A `f64` representing the new upper bound. It must be bigger than `low`.
+    /// * `step` - A `f64` representing the new distance between two neighbouring values. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the parameters were changed.
+    /// * `Err(RngError)` - Returns the error of `DiscreteUniform::new` if a parameter is invalid. The parameters are not changed.
+    pub fn set_params(&mut self, low: f64, high: f64, step: f64) -> Result<(), RngError> {
+        let mut updated: DiscreteUniform = DiscreteUniform::new(low, high, step)?;
+
+        std::mem::swap(&mut updated.rng, &mut self.rng);
+        *self = updated;
+        Ok(())
+    }
+}
+
+impl Display for DiscreteUniform {
+    /// Formats the distribution with its smallest and largest value and the step, e.g. `DiscreteUniform(low=0, high=1, step=0.25)`.
+    ///
+    /// A precision given to the formatter, e.g. `{:.3}`, is applied to every value.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        let (low, high, step): (f64, f64, f64) = self.params();
+
+        match format.precision() {
+            Some(precision) => write!(
+                format,
+                "DiscreteUniform(low={:.*}, high={:.*}, step={:.*})",
+                precision, low, precision, high, precision, step
+            ),
+            None => write!(
+                format,
+                "DiscreteUniform(low={}, high={}, step={})",
+                low, high, step
+            ),
+        }
+    }
+}
+
+impl Quantile for DiscreteUniform {
+    /// Evaluates the quantile function of the discrete uniform distribution.
+    ///
+    /// The quantile is the smallest value `x` with `P(X <= x) >= p`, i.e.
+    /// ```text
+    /// Q(p) = low + (ceil(n p) - 1) step
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        let index: f64 = ((self.n as f64 * p).ceil() - 1_f64).clamp(0_f64, (self.n - 1_u64) as f64);
+        self.low + index * self.step
+    }
+}
@@ -0,0 +1,245 @@
+//! This module contains generators for random matrix ensembles.
+//!
+//! All matrices are returned as `Vec<Vec<_>>` in row-major order.
+//! The functions take the random number generator as an argument, so that the results are reproducible by seeding it.
+
+use crate::math;
+use crate::rng::Rng;
+
+/// The maximal number of row and column normalizations used by `doubly_stochastic`.
+const SINKHORN_ITERATIONS: usize = 10_000_usize;
+
+/// The maximal deviation of the row and column sums from 1 at which `doubly_stochastic` stops.
+const SINKHORN_TOLERANCE: f64 = 1e-12_f64;
+
+/// Generates a matrix from the Gaussian orthogonal ensemble (GOE).
+///
+/// The matrix is calculated as
+/// ```text
+/// H = (A + Aᵀ) / 2
+/// ```
+/// where the entries of `A` are independently standard normal distributed.
+/// Therefore, the diagonal entries have variance 1 and the off-diagonal entries have variance 1/2.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `n` - A `usize` representing the dimension of the matrix.
+///
+/// # Returns
+///
+/// A symmetric `n x n` matrix.
+pub fn goe(rng: &mut Rng, n: usize) -> Vec<Vec<f64>> {
+    let a: Vec<Vec<f64>> = gaussian_matrix(rng, n);
+
+    (0_usize..n)
+        .map(|i| {
+            (0_usize..n)
+                .map(|j| 0.5_f64 * (a[i][j] + a[j][i]))
+                .collect()
+        })
+        .collect()
+}
+
+/// Generates a matrix from the Gaussian unitary ensemble (GUE).
+///
+/// The matrix is calculated as
+/// ```text
+/// H = (A + A*) / 2
+/// ```
+/// where the real and imaginary parts of the entries of `A` are independently standard normal distributed.
+/// Therefore, the diagonal entries are real with variance 1
+/// and the real and imaginary parts of the off-diagonal entries have variance 1/2.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `n` - A `usize` representing the dimension of the matrix.
+///
+/// # Returns
+///
+/// A hermitian `n x n` matrix, where every entry is a tuple `(real part, imaginary part)`.
+pub fn gue(rng: &mut Rng, n: usize) -> Vec<Vec<(f64, f64)>> {
+    let re: Vec<Vec<f64>> = gaussian_matrix(rng, n);
+    let im: Vec<Vec<f64>> = gaussian_matrix(rng, n);
+
+    (0_usize..n)
+        .map(|i| {
+            (0_usize..n)
+                .map(|j| {
+                    (
+                        0.5_f64 * (re[i][j] + re[j][i]),
+                        0.5_f64 * (im[i][j] - im[j][i]),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Generates a uniformly (Haar) distributed orthogonal matrix.
+///
+/// A matrix with independently standard normal distributed entries is decomposed into `A = QR`.
+/// `Q` is Haar distributed if the decomposition is chosen such that the diagonal of `R` is positive.
+/// This sign correction is built into the modified Gram-Schmidt process used here,
+/// because the diagonal entries of `R` are the norms of the orthogonalized columns.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `n` - A `usize` representing the dimension of the matrix.
+///
+/// # Returns
+///
+/// An orthogonal `n x n` matrix.
+pub fn haar_orthogonal(rng: &mut Rng, n: usize) -> Vec<Vec<f64>> {
+    // Columns of the matrix, orthonormalized in place
+    let mut columns: Vec<Vec<f64>> = gaussian_matrix(rng, n);
+
+    for j in 0_usize..n {
+        let (done, rest) = columns.split_at_mut(j);
+        let column: &mut Vec<f64> = &mut rest[0];
+
+        for basis in done.iter() {
+            let projection: f64 = column.iter().zip(basis.iter()).map(|(x, y)| x * y).sum();
+            for (value, basis_value) in column.iter_mut().zip(basis.iter()) {
+                *value -= projection * basis_value;
+            }
+        }
+
+        let norm: f64 = column.iter().map(|x| x * x).sum::<f64>().sqrt();
+        for value in column.iter_mut() {
+            *value /= norm;
+        }
+    }
+
+    (0_usize..n)
+        .map(|i| (0_usize..n).map(|j| columns[j][i]).collect())
+        .collect()
+}
+
+/// Generates a uniformly distributed permutation of `0, ..., n - 1`.
+///
+/// This uses the Fisher-Yates shuffle.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `n` - A `usize` representing the number of elements.
+///
+/// # Returns
+///
+/// A `Vec<usize>` where the i-th entry is the image of `i`.
+pub fn permutation(rng: &mut Rng, n: usize) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0_usize..n).collect();
+
+    for i in (1_usize..n).rev() {
+        let j: usize = rng.gen_below(i as u64 + 1_u64) as usize;
+        permutation.swap(i, j);
+    }
+    permutation
+}
+
+/// Generates a uniformly distributed permutation matrix.
+///
+/// The entry `(i, j)` is 1 if the permutation maps `i` to `j` and 0 otherwise.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `n` - A `usize` representing the dimension of the matrix.
+///
+/// # Returns
+///
+/// A `n x n` permutation matrix.
+pub fn permutation_matrix(rng: &mut Rng, n: usize) -> Vec<Vec<f64>> {
+    let permutation: Vec<usize> = permutation(rng, n);
+
+    permutation
+        .into_iter()
+        .map(|image| {
+            let mut row: Vec<f64> = vec![0_f64; n];
+            row[image] = 1_f64;
+            row
+        })
+        .collect()
+}
+
+/// Generates a random (row) stochastic matrix.
+///
+/// Every row is uniformly distributed on the probability simplex.
+/// This is achieved by normalizing independently Exponential(1) distributed entries.
+/// The result can directly be used as the transition matrix of a `MarkovChain`.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `n` - A `usize` representing the dimension of the matrix.
+///
+/// # Returns
+///
+/// A `n x n` matrix with non-negative entries whose rows sum up to 1.
+pub fn stochastic_matrix(rng: &mut Rng, n: usize) -> Vec<Vec<f64>> {
+    (0_usize..n)
+        .map(|_| {
+            let row: Vec<f64> = (0_usize..n).map(|_| -math::ln(rng.generate())).collect();
+            let sum: f64 = row.iter().sum();
+            row.into_iter().map(|value| value / sum).collect()
+        })
+        .collect()
+}
+
+/// Generates a random doubly stochastic matrix using the Sinkhorn-Knopp algorithm.
+///
+/// A matrix with independently uniformly distributed entries is alternately normalized by its row and column sums,
+/// until all row and column sums deviate from 1 by less than `SINKHORN_TOLERANCE`
+/// or `SINKHORN_ITERATIONS` iterations are reached.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `n` - A `usize` representing the dimension of the matrix.
+///
+/// # Returns
+///
+/// A `n x n` matrix with non-negative entries whose rows and columns sum up to 1.
+///
+/// # Notes
+///
+/// The result is not uniformly distributed on the Birkhoff polytope,
+/// but it is the standard choice for benchmarking assignment and optimal transport problems.
+pub fn doubly_stochastic(rng: &mut Rng, n: usize) -> Vec<Vec<f64>> {
+    let mut matrix: Vec<Vec<f64>> = (0_usize..n)
+        .map(|_| (0_usize..n).map(|_| rng.generate()).collect())
+        .collect();
+
+    for _ in 0_usize..SINKHORN_ITERATIONS {
+        for row in matrix.iter_mut() {
+            let sum: f64 = row.iter().sum();
+            for value in row.iter_mut() {
+                *value /= sum;
+            }
+        }
+
+        let mut max_deviation: f64 = 0_f64;
+        for j in 0_usize..n {
+            let sum: f64 = matrix.iter().map(|row| row[j]).sum();
+            max_deviation = max_deviation.max((sum - 1_f64).abs());
+            for row in matrix.iter_mut() {
+                row[j] /= sum;
+            }
+        }
+
+        if max_deviation < SINKHORN_TOLERANCE {
+            break;
+        }
+    }
+    matrix
+}
+
+/// Generates a `n x n` matrix with independently standard normal distributed entries.
+fn gaussian_matrix(rng: &mut Rng, n: usize) -> Vec<Vec<f64>> {
+    (0_usize..n)
+        .map(|_| (0_usize..n).map(|_| rng.gen_standard_normal()).collect())
+        .collect()
+}
@@ -0,0 +1,121 @@
+//! This module contains the implementation of the `ExactDiscrete` struct and its methods.
+
+use crate::auto_rng_trait;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random indices with exactly proportional probabilities using integer arithmetic only.
+///
+/// The index `i` is generated with probability `wᵢ / W`, where `W` is the sum of the integer weights.
+/// Other than `Categorical`, no floating point numbers are involved, so there is no rounding of the probabilities.
+/// This is required for applications like lotteries and games of chance, where the odds have to be exact.
+///
+/// A uniformly distributed integer `R` in [0, W) is drawn from the raw `u64` output of the generator.
+/// To avoid the bias of `x mod W`, outputs above the largest multiple of `W` are rejected (and redrawn).
+/// Then the index is found by a binary search in the cumulative weights.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` providing the raw `u64` values.
+/// * `cumulative` - The cumulative sums of the weights.
+/// * `zone` - The largest accepted raw value.
+///
+/// # Notes
+///
+/// The exactness is relative to the underlying generator: the probabilities are exact if the raw `u64` values are uniformly distributed.
+#[derive(Debug, Clone)]
+pub struct ExactDiscrete {
+    /// The generator of the raw `u64` values.
+    rng: Rng,
+
+    /// The cumulative sums of the weights.
+    cumulative: Vec<u64>,
+
+    /// The largest accepted raw value.
+    zone: u64,
+}
+
+auto_rng_trait!(ExactDiscrete);
+
+impl ExactDiscrete {
+    /// Creates a new `ExactDiscrete` instance with given integer weights.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - A slice of `u64` weights. Their sum must be positive and must not exceed `u64::MAX`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ExactDiscrete)` - Returns an instance of `ExactDiscrete` if the weights are valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no weights, a `PositiveError` if all weights are 0 or an `IntervalError` if the sum overflows.
+    pub fn new(weights: &[u64]) -> Result<ExactDiscrete, RngError> {
+        RngError::check_empty(weights)?;
+
+        let mut cumulative: Vec<u64> = Vec::with_capacity(weights.len());
+        let mut total: u64 = 0_u64;
+        for &weight in weights {
+            total = total.checked_add(weight).ok_or_else(|| {
+                RngError::interval(total as f64 + weight as f64, 0_f64, u64::MAX as f64)
+            })?;
+            cumulative.push(total);
+        }
+        RngError::check_positive(total as f64)?;
+
+        // 2^64 mod total values at the top are rejected
+        let remainder: u64 = (u64::MAX % total + 1_u64) % total;
+
+        Ok(ExactDiscrete {
+            rng: Rng::new(),
+            cumulative,
+            zone: u64::MAX - remainder,
+        })
+    }
+
+    /// Generates a random index.
+    ///
+    /// # Returns
+    ///
+    /// A `usize` between 0 and the number of weights minus 1. Indices with weight 0 are never returned.
+    pub fn generate(&mut self) -> usize {
+        let total: u64 = self.total();
+
+        let mut raw: u64 = self.rng.next_u64();
+        while raw > self.zone {
+            raw = self.rng.next_u64();
+        }
+
+        let value: u64 = raw % total;
+        self.cumulative.partition_point(|&sum| sum <= value)
+    }
+
+    /// Returns the sum of the weights.
+    ///
+    /// # Returns
+    ///
+    /// The sum `W` of the weights as a `u64`.
+    pub fn total(&self) -> u64 {
+        self.cumulative[self.cumulative.len() - 1_usize]
+    }
+
+    /// Returns the exact probability of an index as a fraction.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - A `usize` representing the index.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(wᵢ, W)` of the numerator and denominator of the probability, or `(0, W)` if the index is out of range.
+    pub fn probability(&self, index: usize) -> (u64, u64) {
+        let weight: u64 = match index {
+            0_usize => self.cumulative[0_usize],
+            _ if index < self.cumulative.len() => {
+                self.cumulative[index] - self.cumulative[index - 1_usize]
+            }
+            _ => 0_u64,
+        };
+        (weight, self.total())
+    }
+}
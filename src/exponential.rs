@@ -1,6 +1,13 @@
 //! This module contains the implementation of the `Exponential` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::mean;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -15,6 +22,7 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `rate` - The rate (λ) of the Exponential distribution. Must be a positive number.
 /// * `inverse_rate` - The inverse of the `rate` value, pre-computed to optimize performance by avoiding repeated division.
+#[derive(Debug, Clone)]
 pub struct Exponential {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -28,6 +36,9 @@ pub struct Exponential {
 }
 
 auto_rng_trait!(Exponential);
+auto_builder!(Exponential, ExponentialBuilder { rate: f64 });
+auto_params!(Exponential { rate: f64 });
+auto_display!(Exponential { "λ" = rate });
 impl Exponential {
     /// Creates a new `Exponential` instance with a given rate.
     ///
@@ -41,8 +52,9 @@ impl Exponential {
     /// # Returns
     ///
     /// * `Ok(Exponential)` - Returns an instance of `Exponential` if the `rate` is valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the `rate` is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `rate` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(rate: f64) -> Result<Exponential, RngError> {
+        RngError::check_finite(rate)?;
         RngError::check_positive(rate)?;
 
         Ok(Exponential {
@@ -64,6 +76,217 @@ impl Exponential {
     ///
     /// A `f64` value generated from the Exponential distribution.
     pub fn generate(&mut self) -> f64 {
-        -f64::ln(self.rng.generate()) * self.inverse_rate
+        -math::ln(self.rng.generate()) * self.inverse_rate
+    }
+
+    /// Returns the rate of the distribution.
+    ///
+    /// # Returns
+    ///
+    /// The rate (λ) as a `f64`.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Changes the rate of the distribution.
+    ///
+    /// The random number generator keeps its state, so a simulation can adjust the rate on the fly
+    /// without rebuilding the distribution and reseeding in the middle of a run.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - A `f64` representing the new rate (λ). It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the rate was changed.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `rate` is less than or equal to 0 or a `NotFiniteError` if it is infinite or NaN. The rate is not changed.
+    pub fn set_rate(&mut self, rate: f64) -> Result<(), RngError> {
+        RngError::check_finite(rate)?;
+        RngError::check_positive(rate)?;
+
+        self.rate = rate;
+        self.inverse_rate = 1_f64 / rate;
+        Ok(())
+    }
+
+    /// Generates a random value measured in units of a time horizon.
+    ///
+    /// This is `X / T`, which is Exponential distributed with rate `λ T`.
+    /// It is useful if the clock of a simulation runs in a different unit than the rate, e.g. days instead of hours.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_horizon` - A `f64` representing the unit `T`. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - Returns the scaled value.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `time_horizon` is less than or equal to 0.
+    pub fn generate_scaled(&mut self, time_horizon: f64) -> Result<f64, RngError> {
+        RngError::check_positive(time_horizon)?;
+
+        Ok(self.generate() / time_horizon)
+    }
+
+    /// Counts the events of a Poisson process with this rate until a given time.
+    ///
+    /// The waiting times between the events are generated from this distribution and summed up until they exceed `t`.
+    /// Therefore, the count is Poisson distributed with mean `λ t`.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - A `f64` representing the end of the time interval [0, t]. It must be non-negative.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - Returns the number of events.
+    /// * `Err(RngError)` - Returns a `NonNegativeError` if `t` is negative. Returns a `NotFiniteError` if `t` is infinite or NaN.
+    pub fn count_events_until(&mut self, t: f64) -> Result<usize, RngError> {
+        RngError::check_finite(t)?;
+        RngError::check_non_negative(t)?;
+
+        let mut count: usize = 0_usize;
+        let mut time: f64 = self.generate();
+        while time <= t {
+            count += 1_usize;
+            time += self.generate();
+        }
+        Ok(count)
+    }
+
+    /// Estimates the rate of an `Exponential` distribution from samples using maximum likelihood.
+    ///
+    /// The estimator is
+    /// ```text
+    /// λ = n / Σ x_i
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of non-negative `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Exponential)` - Returns the fitted instance of `Exponential`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty, a `NonNegativeError` if a sample is negative or a `PositiveError` if all samples are 0.
+    pub fn fit(samples: &[f64]) -> Result<Exponential, RngError> {
+        RngError::check_empty(samples)?;
+        for &sample in samples {
+            RngError::check_non_negative(sample)?;
+        }
+
+        let sample_mean: f64 = mean(samples);
+        RngError::check_positive(sample_mean)?;
+
+        Exponential::new(1_f64 / sample_mean)
+    }
+}
+
+impl Default for Exponential {
+    /// Creates a standard `Exponential` instance with rate 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Exponential::new(1_f64).unwrap()
+    }
+}
+
+impl Quantile for Exponential {
+    /// Evaluates the quantile function of the Exponential distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = -ln(1 - p) / rate
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        -math::ln(1_f64 - p) * self.inverse_rate
+    }
+}
+
+impl Density for Exponential {
+    /// Evaluates the density of the Exponential distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = λ exp(-λ x)    for x >= 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            0_f64
+        } else {
+            self.rate * math::exp(-self.rate * x)
+        }
+    }
+
+    /// Evaluates the logarithm of the density of the Exponential distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln λ - λ x
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            f64::NEG_INFINITY
+        } else {
+            math::ln(self.rate) - self.rate * x
+        }
+    }
+
+    /// Evaluates the distribution function of the Exponential distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 1 - exp(-λ x)    for x >= 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            0_f64
+        } else {
+            1_f64 - math::exp(-self.rate * x)
+        }
+    }
+
+    /// Calculates the differential entropy of the Exponential distribution in nats.
+    ///
+    /// ```text
+    /// H = 1 - ln λ
+    /// ```
+    fn entropy(&self) -> f64 {
+        1_f64 - math::ln(self.rate)
     }
 }
@@ -0,0 +1,139 @@
+//! This module contains functions writing generated samples directly to files.
+//!
+//! The samples are generated in chunks into a reused buffer and written with buffered IO,
+//! so files of many gigabytes can be created without holding the samples in memory.
+//!
+//! With the `arrow` feature, samples of several distributions can also be collected into an Arrow `RecordBatch`,
+//! which can be handed to polars, pandas (through pyarrow) or a Parquet writer without copying.
+
+use std::fs::File;
+use std::io::{BufWriter, Result, Write};
+use std::path::Path;
+#[cfg(feature = "arrow")]
+use std::sync::Arc;
+
+#[cfg(feature = "arrow")]
+use arrow_array::{ArrayRef, Float64Array, RecordBatch};
+#[cfg(feature = "arrow")]
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+use crate::rng::RngTrait;
+
+/// The number of samples generated at once before they are written.
+const CHUNK_SIZE: usize = 4_096_usize;
+
+/// Writes samples of a distribution to a CSV file.
+///
+/// The file has the header `value` and one sample per line.
+/// The values are written in the shortest form which is parsed back to the same `f64`.
+/// An existing file is overwritten.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file.
+/// * `distribution` - Any distribution implementing `RngTrait`.
+/// * `n` - A `usize` representing the number of samples.
+///
+/// # Returns
+///
+/// * `Ok(())` - If all samples were written.
+/// * `Err(std::io::Error)` - If the file could not be created or written.
+pub fn write_csv<P: AsRef<Path>, D: RngTrait + ?Sized>(
+    path: P,
+    distribution: &mut D,
+    n: usize,
+) -> Result<()> {
+    let mut out: BufWriter<File> = BufWriter::new(File::create(path)?);
+
+    writeln!(out, "value")?;
+    write_chunks(distribution, n, |chunk| {
+        for sample in chunk {
+            writeln!(out, "{}", sample)?;
+        }
+        Ok(())
+    })?;
+    out.flush()
+}
+
+/// Writes samples of a distribution to a binary file of raw `f64` values.
+///
+/// Every sample takes 8 bytes in little-endian byte order without any header,
+/// e.g. the file can be read with `numpy.fromfile(path, dtype="<f8")`.
+/// An existing file is overwritten.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file.
+/// * `distribution` - Any distribution implementing `RngTrait`.
+/// * `n` - A `usize` representing the number of samples.
+///
+/// # Returns
+///
+/// * `Ok(())` - If all samples were written.
+/// * `Err(std::io::Error)` - If the file could not be created or written.
+pub fn write_f64_binary<P: AsRef<Path>, D: RngTrait + ?Sized>(
+    path: P,
+    distribution: &mut D,
+    n: usize,
+) -> Result<()> {
+    let mut out: BufWriter<File> = BufWriter::new(File::create(path)?);
+
+    write_chunks(distribution, n, |chunk| {
+        for sample in chunk {
+            out.write_all(&sample.to_le_bytes())?;
+        }
+        Ok(())
+    })?;
+    out.flush()
+}
+
+/// Collects samples of named distributions into an Arrow `RecordBatch` with one column per distribution.
+///
+/// Every column is a non-nullable `Float64` column named after its distribution and holds `n` samples.
+/// This method requires the `arrow` feature.
+///
+/// # Arguments
+///
+/// * `columns` - A slice of pairs of a column name and a distribution implementing `RngTrait`.
+/// * `n` - A `usize` representing the number of samples per column, i.e. the number of rows.
+///
+/// # Returns
+///
+/// * `Ok(RecordBatch)` - Returns the record batch with the columns in the given order.
+/// * `Err(ArrowError)` - If there are no columns.
+#[cfg(feature = "arrow")]
+pub fn record_batch(
+    columns: &mut [(&str, &mut dyn RngTrait)],
+    n: usize,
+) -> std::result::Result<RecordBatch, ArrowError> {
+    let fields: Vec<Field> = columns
+        .iter()
+        .map(|(name, _)| Field::new(*name, DataType::Float64, false))
+        .collect();
+    let arrays: Vec<ArrayRef> = columns
+        .iter_mut()
+        .map(|(_, distribution)| {
+            Arc::new(Float64Array::from(distribution.generate_multiple(n))) as ArrayRef
+        })
+        .collect();
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+}
+
+/// Generates `n` samples in chunks of at most `CHUNK_SIZE` values and passes every chunk to `write`.
+fn write_chunks<D: RngTrait + ?Sized, W: FnMut(&[f64]) -> Result<()>>(
+    distribution: &mut D,
+    n: usize,
+    mut write: W,
+) -> Result<()> {
+    let mut buffer: Vec<f64> = vec![0_f64; CHUNK_SIZE.min(n)];
+
+    let mut remaining: usize = n;
+    while remaining > 0_usize {
+        let chunk: &mut [f64] = &mut buffer[..remaining.min(CHUNK_SIZE)];
+        distribution.fill(chunk);
+        write(chunk)?;
+        remaining -= chunk.len();
+    }
+    Ok(())
+}
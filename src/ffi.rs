@@ -0,0 +1,165 @@
+//! This module contains the C interface of the crate.
+//!
+//! A distribution is created by one of the `random_<distribution>_new` functions, which return an opaque handle.
+//! All other functions work on a handle of any distribution, e.g. `random_generate` or `random_fill`.
+//! Every handle must be released by `random_free` exactly once.
+//!
+//! The functions only use C types and a pointer to the opaque `RandomDistribution`,
+//! so the ABI stays stable when the distributions change.
+//! The declarations for C and C++ are in `include/random.h`.
+//!
+//! This module requires the `ffi` feature. The shared library is built with `cargo build --release --features ffi`.
+
+use crate::rng::RngTrait;
+use crate::{
+    Bernoulli, Beta, Binomial, ChiSquared, Exponential, Gamma, Geometric, Laplace, LogNormal,
+    Normal, Poisson, StudentsT, Uniform, Weibull,
+};
+
+/// An opaque handle of a distribution, only used behind a pointer in C.
+///
+/// # Fields
+///
+/// * `inner` - The wrapped distribution.
+pub struct RandomDistribution {
+    /// The wrapped distribution.
+    inner: Box<dyn RngTrait>,
+}
+
+/// Implements an `extern "C"` constructor for a distribution.
+///
+/// The constructor takes the parameters of `new` and returns a null pointer if they are invalid.
+macro_rules! ffi_constructor {
+    ($function:ident, $distribution:ident ( $($param:ident: $ty:ty),* )) => {
+        #[doc = concat!("Creates a handle of a new `", stringify!($distribution), "` seeded by the system.")]
+        ///
+        /// # Returns
+        ///
+        /// A pointer to the handle, which must be released by `random_free`,
+        /// or a null pointer if the parameters are invalid.
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $function($($param: $ty),*) -> *mut RandomDistribution {
+            match $distribution::new($($param),*) {
+                Ok(distribution) => Box::into_raw(Box::new(RandomDistribution {
+                    inner: Box::new(distribution),
+                })),
+                Err(_) => std::ptr::null_mut(),
+            }
+        }
+    };
+}
+
+ffi_constructor!(random_bernoulli_new, Bernoulli(probability: f64));
+ffi_constructor!(random_beta_new, Beta(alpha: f64, beta: f64));
+ffi_constructor!(random_binomial_new, Binomial(n: i32, p: f64));
+ffi_constructor!(random_chi_squared_new, ChiSquared(k: i32));
+ffi_constructor!(random_exponential_new, Exponential(rate: f64));
+ffi_constructor!(random_gamma_new, Gamma(shape: f64, scale: f64));
+ffi_constructor!(random_geometric_new, Geometric(probability: f64));
+ffi_constructor!(random_laplace_new, Laplace(location: f64, scale: f64));
+ffi_constructor!(random_lognormal_new, LogNormal(mean: f64, variance: f64));
+ffi_constructor!(random_normal_new, Normal(mean: f64, variance: f64));
+ffi_constructor!(random_poisson_new, Poisson(rate: f64));
+ffi_constructor!(random_students_t_new, StudentsT(k: f64));
+ffi_constructor!(random_uniform_new, Uniform(a: f64, b: f64));
+ffi_constructor!(random_weibull_new, Weibull(shape: f64, scale: f64));
+
+/// Generates a single random value.
+///
+/// # Returns
+///
+/// The random value as a `double`, or NaN if the handle is null.
+///
+/// # Safety
+///
+/// `handle` must be null or a handle created by this module, which has not been released.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn random_generate(handle: *mut RandomDistribution) -> f64 {
+    // SAFETY: The caller guarantees that a non-null handle is valid.
+    match unsafe { handle.as_mut() } {
+        Some(handle) => {
+            let mut value: [f64; 1] = [0_f64];
+            handle.inner.fill(&mut value);
+            value[0]
+        }
+        None => f64::NAN,
+    }
+}
+
+/// Fills a buffer with `len` random values.
+///
+/// Nothing is written if the handle or the buffer is null.
+///
+/// # Safety
+///
+/// `handle` must be null or a valid handle, and `buffer` must be null or point to `len` writable `double` values.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn random_fill(
+    handle: *mut RandomDistribution,
+    buffer: *mut f64,
+    len: usize,
+) {
+    if buffer.is_null() {
+        return;
+    }
+    // SAFETY: The caller guarantees that a non-null handle is valid and the buffer has `len` values.
+    if let Some(handle) = unsafe { handle.as_mut() } {
+        handle
+            .inner
+            .fill(unsafe { std::slice::from_raw_parts_mut(buffer, len) });
+    }
+}
+
+/// Returns the seed of the random number generator.
+///
+/// # Returns
+///
+/// The seed as a `uint64_t`, or 0 if the handle is null.
+///
+/// # Safety
+///
+/// `handle` must be null or a valid handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn random_seed(handle: *const RandomDistribution) -> u64 {
+    // SAFETY: The caller guarantees that a non-null handle is valid.
+    unsafe { handle.as_ref() }.map_or(0_u64, |handle| handle.inner.seed())
+}
+
+/// Sets the seed of the random number generator.
+///
+/// # Safety
+///
+/// `handle` must be null or a valid handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn random_set_seed(handle: *mut RandomDistribution, seed: u64) {
+    // SAFETY: The caller guarantees that a non-null handle is valid.
+    if let Some(handle) = unsafe { handle.as_mut() } {
+        handle.inner.set_seed(seed);
+    }
+}
+
+/// Restarts the random number generator from its seed.
+///
+/// # Safety
+///
+/// `handle` must be null or a valid handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn random_restart(handle: *mut RandomDistribution) {
+    // SAFETY: The caller guarantees that a non-null handle is valid.
+    if let Some(handle) = unsafe { handle.as_mut() } {
+        handle.inner.restart();
+    }
+}
+
+/// Releases a handle. Nothing happens if the handle is null.
+///
+/// # Safety
+///
+/// `handle` must be null or a valid handle, which is not used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn random_free(handle: *mut RandomDistribution) {
+    if !handle.is_null() {
+        // SAFETY: The handle was created by `Box::into_raw` and is released only once.
+        drop(unsafe { Box::from_raw(handle) });
+    }
+}
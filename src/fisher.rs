@@ -1,5 +1,8 @@
 //! This module contains the implementation of the `Fisher` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
@@ -7,26 +10,30 @@ use crate::rng_error::RngError;
 /// A struct for generating random variables from a Fisher distribution.
 ///
 /// This struct uses a uniformly distributed random number generator (`Rng`) to generate values
-/// from the standard Normal distribution and generates a Fisher distribution with a specified `mean` (μ) and `variance` (σ²) accordingly.
-/// The `gen` method generates a random variate according to the Normal distribution.
+/// from two independent Gamma distributions and generates a Fisher distribution with real-valued degrees of freedom `m` and `n` accordingly.
+/// The `gen` method generates a random variate according to the Fisher distribution.
 ///
 /// # Fields
 ///
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `m` - The first degrees of freedom the distribution.
-/// * `n` - The first degrees of freedom the distribution.
+/// * `n` - The second degrees of freedom the distribution.
+#[derive(Debug, Clone)]
 pub struct Fisher {
     /// The uniformly distributed random number generator.
     rng: Rng,
 
-    /// The first degrees of freedom the distribution.
-    m: i32,
+    /// The first degrees of freedom the distribution. Must be a positive number.
+    m: f64,
 
-    /// The second degrees of freedom the distribution.
-    n: i32,
+    /// The second degrees of freedom the distribution. Must be a positive number.
+    n: f64,
 }
 
 auto_rng_trait!(Fisher);
+auto_builder!(Fisher, FisherBuilder { m: f64, n: f64 });
+auto_params!(Fisher { m: f64, n: f64 });
+auto_display!(Fisher { "m" = m, "n" = n });
 
 impl Fisher {
     /// Creates a new `Fisher` instance with given degrees of freedom.
@@ -35,18 +42,20 @@ impl Fisher {
     ///
     /// # Arguments
     ///
-    /// * `m` - A `i32` representing the first degrees of freedom of the Fisher distribution.
-    /// It must be a positive integer.
-    /// * `n` - A `i32` representing the second degrees of freedom of the Fisher distribution.
-    /// It must be a positive integer.
+    /// * `m` - A `f64` representing the first degrees of freedom of the Fisher distribution.
+    /// It must be a positive number, but does not need to be an integer.
+    /// * `n` - A `f64` representing the second degrees of freedom of the Fisher distribution.
+    /// It must be a positive number, but does not need to be an integer.
     ///
     /// # Returns
     ///
     /// * `Ok(Fisher)` - Returns an instance of `Fisher` if the degrees of freedom are positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if any degree of freedom is less than or equal to 0.
-    pub fn new(m: i32, n: i32) -> Result<Fisher, RngError> {
-        RngError::check_positive(m as f64)?;
-        RngError::check_positive(n as f64)?;
+    /// * `Err(RngError)` - Returns a `PositiveError` if any degree of freedom is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(m: f64, n: f64) -> Result<Fisher, RngError> {
+        RngError::check_finite(m)?;
+        RngError::check_finite(n)?;
+        RngError::check_positive(m)?;
+        RngError::check_positive(n)?;
 
         Ok(Fisher {
             rng: Rng::new(),
@@ -59,24 +68,28 @@ impl Fisher {
     ///
     /// This method generates a random variate according to the Fisher distribution using the formula:
     /// ```text
-    /// X = (χ_n / n) / (χ_m / m)
+    /// X = (χ_m / m) / (χ_n / n) = (G_m / m) / (G_n / n)
     /// ```
-    /// where `χ_n` and `χ_m` are independently ChiSquared distributed.
+    /// where `χ_m` and `χ_n` are independently ChiSquared distributed with `m` and `n` degrees of freedom.
+    /// As `χ_k = 2 G_k` with `G_k` being Gamma distributed with shape `k/2` and scale 1, the factors 2 cancel.
+    /// The Gamma values are generated by `Rng::gen_standard_gamma`, so this takes `O(1)` time for all degrees of freedom.
     ///
     /// # Returns
     ///
     /// A `f64` value generated from the Fisher distribution.
     pub fn generate(&mut self) -> f64 {
-        let mut sum_m: f64 = 0_f64;
-        for _ in 0_i32..self.m {
-            sum_m += self.rng.gen_standard_normal().powi(2_i32);
-        }
+        let gamma_m: f64 = self.rng.gen_standard_gamma(0.5_f64 * self.m);
+        let gamma_n: f64 = self.rng.gen_standard_gamma(0.5_f64 * self.n);
 
-        let mut sum_n: f64 = 0_f64;
-        for _ in 0_i32..self.n {
-            sum_n += self.rng.gen_standard_normal().powi(2_i32);
-        }
+        (gamma_m / self.m) / (gamma_n / self.n)
+    }
+}
 
-        (sum_m / self.m as f64) / (sum_n / self.n as f64)
+impl Default for Fisher {
+    /// Creates a `Fisher` instance with 1 and 1 degrees of freedom.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Fisher::new(1_f64, 1_f64).unwrap()
     }
 }
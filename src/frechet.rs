@@ -1,7 +1,13 @@
 //! This module contains the implementation of the `Frechet` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
 use crate::auxiliary::simple_ln;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -17,6 +23,7 @@ use crate::rng_error::RngError;
 /// * `location` - The location (m) of the Frechet distribution.
 /// * `shape` - The shape (α) of the Frechet distribution. Must be a positive number.
 /// * `scale` - The scale (s) of the Frechet distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Frechet {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -32,6 +39,20 @@ pub struct Frechet {
 }
 
 auto_rng_trait!(Frechet);
+auto_builder!(
+    Frechet,
+    FrechetBuilder {
+        location: f64,
+        shape: f64,
+        scale: f64
+    }
+);
+auto_params!(Frechet {
+    location: f64,
+    shape: f64,
+    scale: f64
+});
+auto_display!(Frechet { "m" = location, "α" = shape, "s" = scale });
 
 impl Frechet {
     /// Creates a new `Frechet` instance with a given rate.
@@ -49,8 +70,11 @@ impl Frechet {
     /// # Returns
     ///
     /// * `Ok(Frechet)` - Returns an instance of `Frechet` if the `shape` and scale are positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the `shape` or `scale` are less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `shape` or `scale` are less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(location: f64, shape: f64, scale: f64) -> Result<Frechet, RngError> {
+        RngError::check_finite(location)?;
+        RngError::check_finite(shape)?;
+        RngError::check_finite(scale)?;
         RngError::check_positive(shape)?;
         RngError::check_positive(location)?;
 
@@ -78,6 +102,76 @@ impl Frechet {
     ///
     /// This uses the `simple_ln` function for speed up.
     pub fn generate(&mut self) -> f64 {
-        self.location + self.scale * (-simple_ln(self.rng.generate())).powf(-1_f64 / self.shape)
+        self.location
+            + self.scale * math::powf(-simple_ln(self.rng.generate()), -1_f64 / self.shape)
+    }
+}
+
+impl Quantile for Frechet {
+    /// Evaluates the quantile function of the Frechet distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = location + scale (-ln(p))^(-1 / shape)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.location + self.scale * math::powf(-math::ln(p), -1_f64 / self.shape)
+    }
+}
+
+impl Density for Frechet {
+    /// Evaluates the density of the Frechet distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = α/s z^(-1 - α) exp(-z^(-α))    with z = (x - m) / s > 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return 0_f64;
+        }
+
+        let z: f64 = (x - self.location) / self.scale;
+        self.shape / self.scale
+            * math::powf(z, -1_f64 - self.shape)
+            * math::exp(-math::powf(z, -self.shape))
+    }
+
+    /// Evaluates the distribution function of the Frechet distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = exp(-z^(-α))    with z = (x - m) / s > 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            0_f64
+        } else {
+            math::exp(-math::powf((x - self.location) / self.scale, -self.shape))
+        }
     }
 }
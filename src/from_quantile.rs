@@ -0,0 +1,105 @@
+//! This module contains the implementation of the `FromQuantile` struct and its methods.
+
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::auto_rng_trait;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+
+/// A struct for generating random variables from a distribution given by its quantile function.
+///
+/// The values are generated by inverse transform sampling
+/// ```text
+/// X = Q(U)
+/// ```
+/// where `Q` is the quantile function and `U` is uniformly distributed on [0, 1].
+/// This turns any closure into a full distribution implementing `RngTrait` and `Quantile`,
+/// so it can be used everywhere a built-in distribution can, e.g. as a marginal of a `GaussianCopula`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `quantile` - The quantile function. Must be non-decreasing on [0, 1].
+#[derive(Clone)]
+pub struct FromQuantile {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The quantile function.
+    quantile: Rc<dyn Fn(f64) -> f64>,
+}
+
+auto_rng_trait!(FromQuantile);
+
+impl Debug for FromQuantile {
+    /// Formats the parameters of the `FromQuantile`, leaving out the quantile function.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("FromQuantile")
+            .field("rng", &self.rng)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FromQuantile {
+    /// Creates a new `FromQuantile` instance with a given quantile function.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantile` - A closure representing the quantile function. It must be non-decreasing on [0, 1].
+    ///
+    /// # Returns
+    ///
+    /// An instance of `FromQuantile`.
+    pub fn new<F: Fn(f64) -> f64 + 'static>(quantile: F) -> FromQuantile {
+        FromQuantile::with_rng(quantile, Rng::new())
+    }
+
+    /// Creates a new `FromQuantile` instance with a given quantile function and random number generator.
+    ///
+    /// This makes it possible to use a generator handed out by a `StreamManager`
+    /// or one with a specific seed and state.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantile` - A closure representing the quantile function. It must be non-decreasing on [0, 1].
+    /// * `rng` - The `Rng` used to generate uniformly distributed random numbers.
+    ///
+    /// # Returns
+    ///
+    /// An instance of `FromQuantile`.
+    pub fn with_rng<F: Fn(f64) -> f64 + 'static>(quantile: F, rng: Rng) -> FromQuantile {
+        FromQuantile {
+            rng,
+            quantile: Rc::new(quantile),
+        }
+    }
+
+    /// Generates a random value by applying the quantile function to a uniformly distributed number.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the distribution.
+    pub fn generate(&mut self) -> f64 {
+        let uni: f64 = self.rng.generate();
+        (self.quantile)(uni)
+    }
+}
+
+impl Quantile for FromQuantile {
+    /// Evaluates the given quantile function.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        (self.quantile)(p)
+    }
+}
@@ -1,8 +1,14 @@
 //! This module contains the implementation of the `Gamma` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::mean;
+use crate::math;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
+use crate::special::{digamma, trigamma};
 
 /// A struct for generating random variables from a Gamma distribution.
 ///
@@ -15,24 +21,39 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `shape` - The shape (α) of the Gamma distribution. Must be a positive number.
 /// * `scale` - The scale (θ) of the Gamma distribution. Must be a positive number.
-///
-/// # Notes
-///
-/// This implementation is using that the Gamma(1, 1) distribution is the same as an Exponential(1) distribution.
-/// The necessity for this is, that the distribution function of the Gamma distribution does not have a closed form.
-/// This approach also is the reason the shape is confined to an integer.
+#[derive(Debug, Clone)]
 pub struct Gamma {
     /// The uniformly distributed random number generator.
     rng: Rng,
 
     /// The shape (α) of the distribution.
-    shape: i32,
+    shape: f64,
 
     /// The scale (θ) of the distribution.
     scale: f64,
 }
 
 auto_rng_trait!(Gamma);
+auto_builder!(
+    Gamma,
+    GammaBuilder {
+        shape: f64,
+        scale: f64
+    }
+);
+auto_params!(Gamma {
+    shape: f64,
+    scale: f64
+});
+auto_display!(Gamma { "α" = shape, "θ" = scale });
+
+impl Gamma {
+    /// The maximal number of Newton iterations used by `fit`.
+    const FIT_ITERATIONS: usize = 100_usize;
+
+    /// The relative tolerance at which the Newton iterations of `fit` stop.
+    const FIT_TOLERANCE: f64 = 1e-12_f64;
+}
 
 impl Gamma {
     /// Creates a new `Gamma` instance with a given shape and scale.
@@ -49,9 +70,11 @@ impl Gamma {
     /// # Returns
     ///
     /// * `Ok(Gamma)` - Returns an instance of `Gamma` if the shape and scale are valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the shape or scale are less than or equal to 0.
-    pub fn new(shape: i32, scale: f64) -> Result<Self, RngError> {
-        RngError::check_positive(shape as f64)?;
+    /// * `Err(RngError)` - Returns a `PositiveError` if the shape or scale are less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(shape: f64, scale: f64) -> Result<Self, RngError> {
+        RngError::check_finite(shape)?;
+        RngError::check_finite(scale)?;
+        RngError::check_positive(shape)?;
         RngError::check_positive(scale)?;
 
         Ok(Gamma {
@@ -63,33 +86,71 @@ impl Gamma {
 
     /// Generates a random value from the Gamma distribution.
     ///
-    /// This uses the fact that Gamma(1, 1) ~ Exp(1) and
+    /// This uses the fact that
     /// ```text
-    /// Gamma(n, 1) = Exp(1) + ... + Exp(1)
+    /// Gamma(α, θ) = θ Gamma(α, 1)
     /// ```
+    /// The standard Gamma value is generated by `Rng::gen_standard_gamma`, which takes `O(1)` time for every shape.
     ///
     /// # Returns
     ///
     /// A `f64` value generated from the Gamma distribution.
+    pub fn generate(&mut self) -> f64 {
+        self.rng.gen_standard_gamma(self.shape) * self.scale
+    }
+
+    /// Estimates the parameters of a `Gamma` distribution from samples using maximum likelihood.
     ///
-    /// # Notes
-    ///
-    /// Because the evaluation of a natural logarithm is comparably slow, we use
+    /// The maximum likelihood estimator of the shape `k` solves
     /// ```text
-    /// ln(a) + ln(b) = ln(a * b)
+    /// ln k - ψ(k) = ln(1/n Σ x_i) - 1/n Σ ln x_i
     /// ```
-    /// This could be a problem, if the shape is very large, because the product of the uniform values would be very small.
-    /// If it shrinks to zero because of rounding this would result in
-    /// ```text
-    /// ln(0) = inf
-    /// ```
-    pub fn generate(&mut self) -> f64 {
-        let mut prod: f64 = 1_f64;
+    /// where `ψ` is the digamma function.
+    /// This equation is solved with Newton's method, starting from the approximation by Minka.
+    /// The scale is then estimated by `θ = mean / k`.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of positive `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Gamma)` - Returns the fitted instance of `Gamma`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if a sample is not positive or all samples are equal.
+    pub fn fit(samples: &[f64]) -> Result<Self, RngError> {
+        RngError::check_empty(samples)?;
+        for &sample in samples {
+            RngError::check_positive(sample)?;
+        }
+
+        let sample_mean: f64 = mean(samples);
+        let log_mean: f64 =
+            samples.iter().map(|&x| math::ln(x)).sum::<f64>() / samples.len() as f64;
+        let s: f64 = math::ln(sample_mean) - log_mean;
+        RngError::check_positive(s)?;
 
-        for _ in 0_usize..(self.shape as usize) {
-            prod *= self.rng.generate();
+        let mut shape: f64 =
+            (3_f64 - s + ((s - 3_f64).powi(2_i32) + 24_f64 * s).sqrt()) / (12_f64 * s);
+        for _ in 0_usize..Self::FIT_ITERATIONS {
+            let step: f64 =
+                (math::ln(shape) - digamma(shape) - s) / (1_f64 / shape - trigamma(shape));
+            shape = (shape - step).max(0.5_f64 * shape);
+            if step.abs() < Self::FIT_TOLERANCE * shape {
+                break;
+            }
         }
 
-        prod.ln() * (-self.scale)
+        Gamma::new(shape, sample_mean / shape)
+    }
+}
+
+impl Default for Gamma {
+    /// Creates a standard `Gamma` instance with shape 1 and scale 1, which is Exponential distributed with rate 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Gamma::new(1_f64, 1_f64).unwrap()
     }
 }
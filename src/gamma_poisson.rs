@@ -0,0 +1,102 @@
+//! This module contains the implementation of the `GammaPoisson` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a Gamma-Poisson distribution.
+///
+/// The Gamma-Poisson distribution is a Poisson distribution whose rate is Gamma distributed.
+/// It models counts that vary more than a Poisson distribution allows (overdispersion)
+/// and is the Negative Binomial distribution with `shape` successes and probability of success `rate / (rate + 1)`,
+/// but the shape does not need to be an integer.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `shape` - The shape (α) of the Gamma distribution of the rate. Must be a positive number.
+/// * `rate` - The rate (β) of the Gamma distribution of the rate. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct GammaPoisson {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The shape (α) of the Gamma distribution.
+    shape: f64,
+
+    /// The rate (β) of the Gamma distribution.
+    rate: f64,
+}
+
+auto_rng_trait!(GammaPoisson);
+auto_builder!(
+    GammaPoisson,
+    GammaPoissonBuilder {
+        shape: f64,
+        rate: f64
+    }
+);
+auto_params!(GammaPoisson {
+    shape: f64,
+    rate: f64
+});
+auto_display!(GammaPoisson { "α" = shape, "β" = rate });
+
+impl GammaPoisson {
+    /// Creates a new `GammaPoisson` instance with a given shape and rate.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - A `f64` representing the shape (α) of the Gamma distribution. It must be a positive number.
+    /// * `rate` - A `f64` representing the rate (β) of the Gamma distribution. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GammaPoisson)` - Returns an instance of `GammaPoisson` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the shape or the rate is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(shape: f64, rate: f64) -> Result<GammaPoisson, RngError> {
+        RngError::check_finite(shape)?;
+        RngError::check_finite(rate)?;
+        RngError::check_positive(shape)?;
+        RngError::check_positive(rate)?;
+
+        Ok(GammaPoisson {
+            rng: Rng::new(),
+            shape,
+            rate,
+        })
+    }
+
+    /// Generates a random value from the Gamma-Poisson distribution.
+    ///
+    /// This uses the definition
+    /// ```text
+    /// Λ ~ Gamma(α, β) = G / β
+    /// X ~ Poisson(Λ)
+    /// ```
+    /// where `G` is Gamma(α, 1) distributed.
+    /// All draws are taken from the same random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A non-negative `i32` value generated from the Gamma-Poisson distribution.
+    pub fn generate(&mut self) -> i32 {
+        let lambda: f64 = self.rng.gen_standard_gamma(self.shape) / self.rate;
+
+        self.rng.gen_poisson(lambda) as i32
+    }
+}
+
+impl Default for GammaPoisson {
+    /// Creates a `GammaPoisson` instance with shape 1 and rate 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        GammaPoisson::new(1_f64, 1_f64).unwrap()
+    }
+}
@@ -0,0 +1,206 @@
+//! This module contains the implementation of the `GeneralizedExtremeValue` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a generalized extreme value (GEV) distribution.
+///
+/// The GEV distribution is the limit distribution of the maxima of independent random variables.
+/// Its shape (ξ) unifies the three classical extreme value distributions:
+/// * `ξ = 0` is the Gumbel distribution with the same location and scale.
+/// * `ξ > 0` is a Frechet distribution with shape `1/ξ`, scale `σ/ξ` and location `μ - σ/ξ`.
+/// * `ξ < 0` is a reversed Weibull distribution, i.e. bounded from above by `μ - σ/ξ`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `location` - The location (μ) of the GEV distribution.
+/// * `scale` - The scale (σ) of the GEV distribution. Must be a positive number.
+/// * `shape` - The shape (ξ) of the GEV distribution.
+#[derive(Debug, Clone)]
+pub struct GeneralizedExtremeValue {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The location (μ) of the distribution.
+    location: f64,
+
+    /// The scale (σ) of the distribution.
+    scale: f64,
+
+    /// The shape (ξ) of the distribution.
+    shape: f64,
+}
+
+auto_rng_trait!(GeneralizedExtremeValue);
+auto_builder!(
+    GeneralizedExtremeValue,
+    GeneralizedExtremeValueBuilder {
+        location: f64,
+        scale: f64,
+        shape: f64
+    }
+);
+auto_params!(GeneralizedExtremeValue {
+    location: f64,
+    scale: f64,
+    shape: f64
+});
+auto_display!(GeneralizedExtremeValue { "μ" = location, "σ" = scale, "ξ" = shape });
+
+impl GeneralizedExtremeValue {
+    /// Creates a new `GeneralizedExtremeValue` instance with a given location, scale and shape.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - A `f64` representing the location (μ) of the GEV distribution.
+    /// * `scale` - A `f64` representing the scale (σ) of the GEV distribution. It must be a positive number.
+    /// * `shape` - A `f64` representing the shape (ξ) of the GEV distribution.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GeneralizedExtremeValue)` - Returns an instance of `GeneralizedExtremeValue` if the scale is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the scale is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedExtremeValue, RngError> {
+        RngError::check_finite(location)?;
+        RngError::check_finite(scale)?;
+        RngError::check_finite(shape)?;
+        RngError::check_positive(scale)?;
+
+        Ok(GeneralizedExtremeValue {
+            rng: Rng::new(),
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Generates a random value from the GEV distribution.
+    ///
+    /// This uses inverse transform sampling, see `quantile`.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the GEV distribution.
+    pub fn generate(&mut self) -> f64 {
+        let uni: f64 = self.rng.generate();
+        self.quantile(uni)
+    }
+
+    /// Calculates `t(x)`, where the distribution function is `exp(-t(x))`.
+    ///
+    /// ```text
+    /// t(x) = (1 + ξ z)^(-1/ξ)    for ξ ≠ 0
+    /// t(x) = exp(-z)             for ξ = 0
+    /// ```
+    /// with `z = (x - μ) / σ`.
+    /// Outside of the support this is infinite below and 0 above.
+    fn t(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.location) / self.scale;
+
+        if self.shape == 0_f64 {
+            return math::exp(-z);
+        }
+
+        let base: f64 = 1_f64 + self.shape * z;
+        if base > 0_f64 {
+            math::exp(-math::ln_1p(self.shape * z) / self.shape)
+        } else if self.shape > 0_f64 {
+            f64::INFINITY
+        } else {
+            0_f64
+        }
+    }
+}
+
+impl Default for GeneralizedExtremeValue {
+    /// Creates a `GeneralizedExtremeValue` instance with location 0, scale 1 and shape 0, which is standard Gumbel distributed.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        GeneralizedExtremeValue::new(0_f64, 1_f64, 0_f64).unwrap()
+    }
+}
+
+impl Quantile for GeneralizedExtremeValue {
+    /// Evaluates the quantile function of the GEV distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = μ + σ ((-ln p)^(-ξ) - 1) / ξ    for ξ ≠ 0
+    /// Q(p) = μ - σ ln(-ln p)                 for ξ = 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        let log: f64 = math::ln(-math::ln(p));
+
+        if self.shape == 0_f64 {
+            self.location - self.scale * log
+        } else {
+            // exp_m1 keeps the precision for a shape close to 0
+            self.location + self.scale * math::exp_m1(-self.shape * log) / self.shape
+        }
+    }
+}
+
+impl Density for GeneralizedExtremeValue {
+    /// Evaluates the density of the GEV distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 1/σ t(x)^(ξ + 1) exp(-t(x))
+    /// ```
+    /// with `t(x) = (1 + ξ (x - μ) / σ)^(-1/ξ)` and `t(x) = exp(-(x - μ) / σ)` for `ξ = 0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        let t: f64 = self.t(x);
+
+        if t == 0_f64 || t.is_infinite() {
+            0_f64
+        } else {
+            math::powf(t, self.shape + 1_f64) * math::exp(-t) / self.scale
+        }
+    }
+
+    /// Evaluates the distribution function of the GEV distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = exp(-t(x))
+    /// ```
+    /// with `t(x) = (1 + ξ (x - μ) / σ)^(-1/ξ)` and `t(x) = exp(-(x - μ) / σ)` for `ξ = 0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        math::exp(-self.t(x))
+    }
+}
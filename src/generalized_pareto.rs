@@ -0,0 +1,210 @@
+//! This module contains the implementation of the `GeneralizedPareto` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a generalized Pareto distribution (GPD).
+///
+/// The GPD is the limit distribution of the excesses over a high threshold (peaks over threshold).
+/// Its shape (ξ) covers the following special cases:
+/// * `ξ = 0` is the Exponential distribution with rate `1/σ`, shifted by `μ`.
+/// * `ξ > 0` is a Pareto distribution with shape `1/ξ` and scale `σ/ξ`, shifted by `μ - σ/ξ`.
+/// * `ξ < 0` is bounded, with the support [μ, μ - σ/ξ]. For `ξ = -1` this is the Uniform distribution.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `location` - The location (μ) of the GPD, i.e. the threshold.
+/// * `scale` - The scale (σ) of the GPD. Must be a positive number.
+/// * `shape` - The shape (ξ) of the GPD.
+#[derive(Debug, Clone)]
+pub struct GeneralizedPareto {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The location (μ) of the distribution.
+    location: f64,
+
+    /// The scale (σ) of the distribution.
+    scale: f64,
+
+    /// The shape (ξ) of the distribution.
+    shape: f64,
+}
+
+auto_rng_trait!(GeneralizedPareto);
+auto_builder!(
+    GeneralizedPareto,
+    GeneralizedParetoBuilder {
+        location: f64,
+        scale: f64,
+        shape: f64
+    }
+);
+auto_params!(GeneralizedPareto {
+    location: f64,
+    scale: f64,
+    shape: f64
+});
+auto_display!(GeneralizedPareto { "μ" = location, "σ" = scale, "ξ" = shape });
+
+impl GeneralizedPareto {
+    /// Creates a new `GeneralizedPareto` instance with a given location, scale and shape.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - A `f64` representing the location (μ) of the GPD.
+    /// * `scale` - A `f64` representing the scale (σ) of the GPD. It must be a positive number.
+    /// * `shape` - A `f64` representing the shape (ξ) of the GPD.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GeneralizedPareto)` - Returns an instance of `GeneralizedPareto` if the scale is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the scale is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedPareto, RngError> {
+        RngError::check_finite(location)?;
+        RngError::check_finite(scale)?;
+        RngError::check_finite(shape)?;
+        RngError::check_positive(scale)?;
+
+        Ok(GeneralizedPareto {
+            rng: Rng::new(),
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Generates a random value from the GPD.
+    ///
+    /// This uses inverse transform sampling, see `quantile`.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the GPD.
+    pub fn generate(&mut self) -> f64 {
+        let uni: f64 = self.rng.generate();
+        self.quantile(uni)
+    }
+
+    /// Calculates the survival function `1 - F(x)`.
+    ///
+    /// ```text
+    /// S(x) = (1 + ξ z)^(-1/ξ)    for ξ ≠ 0
+    /// S(x) = exp(-z)             for ξ = 0
+    /// ```
+    /// with `z = (x - μ) / σ`, clamped to [0, 1] outside of the support.
+    fn survival(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.location) / self.scale;
+
+        if z <= 0_f64 {
+            1_f64
+        } else if self.shape == 0_f64 {
+            math::exp(-z)
+        } else {
+            let base: f64 = 1_f64 + self.shape * z;
+            if base > 0_f64 {
+                math::exp(-math::ln_1p(self.shape * z) / self.shape)
+            } else {
+                0_f64
+            }
+        }
+    }
+}
+
+impl Default for GeneralizedPareto {
+    /// Creates a `GeneralizedPareto` instance with location 0, scale 1 and shape 0, which is Exponential distributed with rate 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        GeneralizedPareto::new(0_f64, 1_f64, 0_f64).unwrap()
+    }
+}
+
+impl Quantile for GeneralizedPareto {
+    /// Evaluates the quantile function of the GPD.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = μ + σ ((1 - p)^(-ξ) - 1) / ξ    for ξ ≠ 0
+    /// Q(p) = μ - σ ln(1 - p)                 for ξ = 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        let log: f64 = math::ln_1p(-p);
+
+        if self.shape == 0_f64 {
+            self.location - self.scale * log
+        } else {
+            // exp_m1 keeps the precision for a shape close to 0
+            self.location + self.scale * math::exp_m1(-self.shape * log) / self.shape
+        }
+    }
+}
+
+impl Density for GeneralizedPareto {
+    /// Evaluates the density of the GPD.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 1/σ (1 + ξ z)^(-1/ξ - 1)    for ξ ≠ 0
+    /// f(x) = 1/σ exp(-z)                 for ξ = 0
+    /// ```
+    /// with `z = (x - μ) / σ` on the support.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.location) / self.scale;
+        let base: f64 = 1_f64 + self.shape * z;
+
+        if z < 0_f64 || base <= 0_f64 {
+            0_f64
+        } else if self.shape == 0_f64 {
+            math::exp(-z) / self.scale
+        } else {
+            math::exp(-(1_f64 / self.shape + 1_f64) * math::ln_1p(self.shape * z)) / self.scale
+        }
+    }
+
+    /// Evaluates the distribution function of the GPD.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 1 - (1 + ξ z)^(-1/ξ)    for ξ ≠ 0
+    /// F(x) = 1 - exp(-z)             for ξ = 0
+    /// ```
+    /// with `z = (x - μ) / σ` on the support.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        1_f64 - self.survival(x)
+    }
+}
@@ -1,29 +1,64 @@
 //! This module contains the implementation of the `Geometric` struct and its methods.
 
+use std::ops::RangeInclusive;
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
-use crate::auxiliary::simple_ln;
+use crate::auxiliary::mean;
+use crate::discrete_distribution::DiscreteDistribution;
+use crate::math;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
+use crate::tie_breaking::TieBreaking;
+
+/// The conventions for the values of a `Geometric` distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometricConvention {
+    /// The number of trials up to and including the first success, with the support `1, 2, 3, ...`.
+    Trials,
+
+    /// The number of failures before the first success, with the support `0, 1, 2, ...`.
+    Failures,
+}
 
 /// A struct for generating random variables from an Geometric distribution.
 ///
 /// This struct uses a uniformly distributed random number generator (`Rng`) to generate values
 /// from the Geometric distribution with a specified `probability` (p).
 /// The `gen` method generates a random variate according to the Geometric distribution.
+/// By default the values are the number of trials up to and including the first success, see `GeometricConvention`.
 ///
 /// # Fields
 ///
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
-/// * `probability` - The probability (p) of the Geometric distribution. Must be a probability.
+/// * `probability` - The probability (p) of the Geometric distribution. Must be a probability bigger than 0.
+/// * `ln_complement` - The value of `ln(1 - p)`.
+/// * `convention` - Whether the trials or the failures are counted.
+/// * `tie_breaking` - The rule for a uniform number on a step of the cumulative distribution function.
+#[derive(Debug, Clone)]
 pub struct Geometric {
     /// The uniformly distributed random number generator.
     rng: Rng,
 
     /// The probability (p) of the Geometric distribution.
     probability: f64,
+
+    /// The value of `ln(1 - p)`, pre-computed accurately for small `p` by `ln_1p`.
+    ln_complement: f64,
+
+    /// Whether the trials or the failures are counted.
+    convention: GeometricConvention,
+
+    /// The rule for a uniform number on a step of the cumulative distribution function.
+    tie_breaking: TieBreaking,
 }
 
 auto_rng_trait!(Geometric);
+auto_builder!(Geometric, GeometricBuilder { probability: f64 });
+auto_params!(Geometric { probability: f64 } keep convention, tie_breaking);
+auto_display!(Geometric { "p" = probability });
 
 impl Geometric {
     /// Creates a new `Geometric` instance with a given rate.
@@ -33,37 +68,170 @@ impl Geometric {
     /// # Arguments
     ///
     /// * `probability` - A `f64` representing the probability (p) of the Geometric distribution.
-    /// It must be a probability.
+    /// It must be a probability bigger than 0. For 1 the first trial is always a success.
     ///
     /// # Returns
     ///
     /// * `Ok(Geometric)` - Returns an instance of `Geometric` if the `probability` is a probability.
-    /// * `Err(RngError)` - Returns an `IntervalError` if the `probability` is less than 0 or greater than one.
+    /// * `Err(RngError)` - Returns a `ProbabilityError` if the `probability` is less than 0 or greater than one.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `probability` is 0, where a success never happens.
     pub fn new(probability: f64) -> Result<Geometric, RngError> {
-        RngError::check_interval(probability, 0_f64, 1_f64)?;
+        RngError::check_probability(probability)?;
+        RngError::check_positive(probability)?;
 
         Ok(Geometric {
             rng: Rng::new(),
             probability,
+            ln_complement: math::ln_1p(-probability),
+            convention: GeometricConvention::Trials,
+            tie_breaking: TieBreaking::Upper,
         })
     }
 
+    /// Sets the convention of the generated values.
+    ///
+    /// By default `GeometricConvention::Trials` counts the trials up to and including the first success.
+    /// `GeometricConvention::Failures` counts the failures before it, i.e. every value is smaller by 1.
+    /// The convention also applies to the probability mass function and the support.
+    ///
+    /// # Arguments
+    ///
+    /// * `convention` - The `GeometricConvention` to use.
+    pub fn set_convention(&mut self, convention: GeometricConvention) {
+        self.convention = convention;
+    }
+
+    /// Returns the convention of the generated values.
+    ///
+    /// # Returns
+    ///
+    /// The `GeometricConvention` in use.
+    pub fn convention(&self) -> GeometricConvention {
+        self.convention
+    }
+
+    /// Sets the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// By default `TieBreaking::Upper` generates the next value, see `TieBreaking` for the bias of both rules.
+    /// With `TieBreaking::Lower` the number of failures is `ceil(ln(1 - U) / ln(1 - p)) - 1` instead, but at least 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `tie_breaking` - The `TieBreaking` rule to use.
+    pub fn set_tie_breaking(&mut self, tie_breaking: TieBreaking) {
+        self.tie_breaking = tie_breaking;
+    }
+
+    /// Returns the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// # Returns
+    ///
+    /// The `TieBreaking` rule in use.
+    pub fn tie_breaking(&self) -> TieBreaking {
+        self.tie_breaking
+    }
+
     /// Generates a random value from the Geometric distribution.
     ///
-    /// This method generates a random variate according to the Geometric distribution using the formula:
+    /// This method generates the number of failures according to the Geometric distribution using the formula:
+    /// ```text
+    /// floor(ln(U) / ln(1 - p))
+    /// ```
+    /// where `U` is a uniformly distributed random variable on (0, 1].
+    /// The number of trials is larger by 1.
+    ///
+    /// Both logarithms are exact, in particular `ln(1 - p)` is computed by `ln_1p`,
+    /// so small probabilities, e.g. `p = 1e-12`, do not lose precision.
+    /// For `p = 1` there are never any failures.
+    ///
+    /// # Returns
+    ///
+    /// A `i32` value generated from the Geometric distribution. Values beyond `i32::MAX` are capped.
+    pub fn generate(&mut self) -> i32 {
+        let failures: i32 = if self.probability == 1_f64 {
+            0_i32
+        } else {
+            let position: f64 = math::ln(1_f64 - self.rng.generate()) / self.ln_complement;
+            self.tie_breaking.cell(position) as i32
+        };
+        failures.saturating_add(self.offset())
+    }
+
+    /// Returns the smallest value of the distribution, which is 1 when counting the trials and 0 otherwise.
+    fn offset(&self) -> i32 {
+        match self.convention {
+            GeometricConvention::Trials => 1_i32,
+            GeometricConvention::Failures => 0_i32,
+        }
+    }
+
+    /// Estimates the probability of a `Geometric` distribution from samples using maximum likelihood.
+    ///
+    /// The samples are interpreted as the number of trials up to and including the first success.
+    /// The estimator is
     /// ```text
-    /// ceil(ln(U) / ln(1 - p))
+    /// p = n / Σ x_i
     /// ```
-    /// where `U` is a uniformly distributed random variable between [0, 1].
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values, which should be integers greater than or equal to 1.
     ///
     /// # Returns
     ///
-    /// A `f64` value generated from the Geometric distribution.
+    /// * `Ok(Geometric)` - Returns the fitted instance of `Geometric`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `ProbabilityError` if the estimate is not a probability.
+    pub fn fit(samples: &[f64]) -> Result<Geometric, RngError> {
+        RngError::check_empty(samples)?;
+
+        Geometric::new(1_f64 / mean(samples))
+    }
+}
+
+impl Default for Geometric {
+    /// Creates a `Geometric` instance with the probability 0.5.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Geometric::new(0.5_f64).unwrap()
+    }
+}
+
+impl DiscreteDistribution for Geometric {
+    /// Evaluates the probability mass function of the Geometric distribution.
     ///
-    /// # Notes
+    /// When counting the trials, it is
+    /// ```text
+    /// P(X = k) = p (1 - p)ᵏ⁻¹
+    /// ```
+    /// and when counting the failures `P(X = k) = p (1 - p)ᵏ`.
+    fn pmf(&self, k: i32) -> f64 {
+        if k < self.offset() {
+            return 0_f64;
+        }
+        self.probability * (1_f64 - self.probability).powi(k - self.offset())
+    }
+
+    /// Evaluates the logarithm of the probability mass function of the Geometric distribution.
     ///
-    /// This uses the `simple_ln` function for speed up.
-    pub fn generate(&mut self) -> i32 {
-        (simple_ln(self.rng.generate()) / simple_ln(1_f64 - self.probability)).ceil() as i32
+    /// ```text
+    /// ln P(X = k) = ln p + (k - 1) ln(1 - p)
+    /// ```
+    /// when counting the trials and `ln p + k ln(1 - p)` when counting the failures.
+    fn ln_pmf(&self, k: i32) -> f64 {
+        if k < self.offset() {
+            return f64::NEG_INFINITY;
+        }
+        if k == self.offset() {
+            return math::ln(self.probability);
+        }
+        math::ln(self.probability) + (k - self.offset()) as f64 * self.ln_complement
+    }
+
+    /// Returns the unbounded support `1, 2, 3, ...` when counting the trials and `0, 1, 2, ...` when counting the failures.
+    fn support(&self) -> RangeInclusive<i32> {
+        self.offset()..=i32::MAX
     }
 }
@@ -1,7 +1,13 @@
 //! This module contains the implementation of the `Gumbel` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
-use crate::auxiliary::simple_ln;
+use crate::auxiliary::{mean, simple_ln, variance};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -16,6 +22,7 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `location` - The location (μ) of the Gumbel distribution.
 /// * `scale` - The scale (s) of the Gumbel distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Gumbel {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -28,6 +35,23 @@ pub struct Gumbel {
 }
 
 auto_rng_trait!(Gumbel);
+auto_builder!(
+    Gumbel,
+    GumbelBuilder {
+        location: f64,
+        scale: f64
+    }
+);
+auto_params!(Gumbel {
+    location: f64,
+    scale: f64
+});
+auto_display!(Gumbel { "μ" = location, "s" = scale });
+
+impl Gumbel {
+    /// The Euler-Mascheroni constant γ, the mean of the standard Gumbel distribution.
+    const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9_f64;
+}
 
 impl Gumbel {
     /// Creates a new `Gumbel` instance with a given rate.
@@ -43,8 +67,10 @@ impl Gumbel {
     /// # Returns
     ///
     /// * `Ok(Gumbel)` - Returns an instance of `Gumbel` if the `scale` is positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(location: f64, scale: f64) -> Result<Gumbel, RngError> {
+        RngError::check_finite(location)?;
+        RngError::check_finite(scale)?;
         RngError::check_positive(scale)?;
 
         Ok(Gumbel {
@@ -70,6 +96,120 @@ impl Gumbel {
     ///
     /// This uses the `simple_ln` function for speed up.
     pub fn generate(&mut self) -> f64 {
-        self.location - self.scale * f64::ln(-simple_ln(self.rng.generate()))
+        self.location - self.scale * math::ln(-simple_ln(self.rng.generate()))
+    }
+
+    /// Estimates the parameters of a `Gumbel` distribution from samples using the method of moments.
+    ///
+    /// With the sample mean `m` and the sample variance `v` the estimators are
+    /// ```text
+    /// s = sqrt(6 v) / π
+    /// μ = m - γ s
+    /// ```
+    /// where `γ` is the Euler-Mascheroni constant.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Gumbel)` - Returns the fitted instance of `Gumbel`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if all samples are equal.
+    pub fn fit_moments(samples: &[f64]) -> Result<Gumbel, RngError> {
+        RngError::check_empty(samples)?;
+
+        let sample_mean: f64 = mean(samples);
+        let scale: f64 = (6_f64 * variance(samples, sample_mean)).sqrt() / std::f64::consts::PI;
+        Gumbel::new(sample_mean - Self::EULER_MASCHERONI * scale, scale)
+    }
+}
+
+impl Default for Gumbel {
+    /// Creates a standard `Gumbel` instance with location 0 and scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Gumbel::new(0_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for Gumbel {
+    /// Evaluates the quantile function of the Gumbel distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = location - scale ln(-ln(p))
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.location - self.scale * math::ln(-math::ln(p))
+    }
+}
+
+impl Density for Gumbel {
+    /// Evaluates the density of the Gumbel distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 1/β exp(-(z + exp(-z)))    with z = (x - μ) / β
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.location) / self.scale;
+        math::exp(-(z + math::exp(-z))) / self.scale
+    }
+
+    /// Evaluates the logarithm of the density of the Gumbel distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = -(z + exp(-z)) - ln β
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.location) / self.scale;
+        -(z + math::exp(-z)) - math::ln(self.scale)
+    }
+
+    /// Evaluates the distribution function of the Gumbel distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = exp(-exp(-z))    with z = (x - μ) / β
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        math::exp(-math::exp(-(x - self.location) / self.scale))
     }
 }
@@ -1,8 +1,13 @@
 //! This module contains the implementation of the `Gumbel2` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
 use crate::auxiliary::simple_ln;
+use crate::math;
 use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
 
 /// A struct for generating random variables from a Gumbel type 2 distribution.
 ///
@@ -13,8 +18,9 @@ use crate::rng::{Rng, RngTrait};
 /// # Fields
 ///
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
-/// * `shape` - The shape (a) of the Gumbel type 2 distribution.
-/// * `scale` - The scale (b) of the Gumbel type 2 distribution.
+/// * `shape` - The shape (a) of the Gumbel type 2 distribution. Must be a positive number.
+/// * `scale` - The scale (b) of the Gumbel type 2 distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Gumbel2 {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -27,26 +33,44 @@ pub struct Gumbel2 {
 }
 
 auto_rng_trait!(Gumbel2);
+auto_builder!(
+    Gumbel2,
+    Gumbel2Builder {
+        shape: f64,
+        scale: f64
+    }
+);
+auto_params!(Gumbel2 {
+    shape: f64,
+    scale: f64
+});
+auto_display!(Gumbel2 { "a" = shape, "b" = scale });
 
 impl Gumbel2 {
-    /// Creates a new `Gumbel2` instance with a given rate.
+    /// Creates a new `Gumbel2` instance with a given shape and scale.
     ///
     /// This method initializes the underlying random number generator using a system-generated seed.
     ///
     /// # Arguments
     ///
-    /// * `shape` - A `f64` representing the shape (a) of the Gumbel type 2 distribution.
-    /// * `scale` - A `f64` representing the scale (b) of the Gumbel type 2 distribution.
+    /// * `shape` - A `f64` representing the shape (a) of the Gumbel type 2 distribution. It must be a positive number.
+    /// * `scale` - A `f64` representing the scale (b) of the Gumbel type 2 distribution. It must be a positive number.
     ///
     /// # Returns
     ///
-    /// Returns an instance of `Gumbel2`.
-    pub fn new(shape: f64, scale: f64) -> Gumbel2 {
-        Gumbel2 {
+    /// * `Ok(Gumbel2)` - Returns an instance of `Gumbel2` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `shape` or the `scale` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(shape: f64, scale: f64) -> Result<Gumbel2, RngError> {
+        RngError::check_finite(shape)?;
+        RngError::check_finite(scale)?;
+        RngError::check_positive(shape)?;
+        RngError::check_positive(scale)?;
+
+        Ok(Gumbel2 {
             rng: Rng::new(),
             shape,
             scale,
-        }
+        })
     }
 
     /// Generates a random value from the Gumbel type 2 distribution.
@@ -67,6 +91,15 @@ impl Gumbel2 {
     pub fn generate(&mut self) -> f64 {
         let uni: f64 = self.rng.generate();
 
-        (-simple_ln(uni / self.scale)).powf(-1_f64 / self.shape)
+        math::powf(-simple_ln(uni / self.scale), -1_f64 / self.shape)
+    }
+}
+
+impl Default for Gumbel2 {
+    /// Creates a standard `Gumbel2` instance with shape 1 and scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Gumbel2::new(1_f64, 1_f64).unwrap()
     }
 }
@@ -0,0 +1,176 @@
+//! This module contains the implementation of the `HalfNormal` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::auxiliary::{
+    ln_standard_normal_pdf, standard_normal_cdf, standard_normal_pdf, standard_normal_quantile,
+};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a half-normal distribution.
+///
+/// The half-normal distribution is the distribution of `|Y|`, where `Y` is Normal distributed with mean 0 and standard deviation `σ`.
+/// It models the size of errors whose sign is not known (folded errors).
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `sigma` - The standard deviation (σ) of the underlying Normal distribution. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct HalfNormal {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The standard deviation (σ) of the underlying Normal distribution.
+    sigma: f64,
+}
+
+auto_rng_trait!(HalfNormal);
+auto_builder!(HalfNormal, HalfNormalBuilder { sigma: f64 });
+auto_params!(HalfNormal { sigma: f64 });
+auto_display!(HalfNormal { "σ" = sigma });
+
+impl HalfNormal {
+    /// Creates a new `HalfNormal` instance with a given standard deviation.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `sigma` - A `f64` representing the standard deviation (σ) of the underlying Normal distribution. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HalfNormal)` - Returns an instance of `HalfNormal` if `sigma` is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `sigma` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(sigma: f64) -> Result<HalfNormal, RngError> {
+        RngError::check_finite(sigma)?;
+        RngError::check_positive(sigma)?;
+
+        Ok(HalfNormal {
+            rng: Rng::new(),
+            sigma,
+        })
+    }
+
+    /// Generates a random value from the half-normal distribution.
+    ///
+    /// This uses the definition
+    /// ```text
+    /// X = σ |Z|
+    /// ```
+    /// where `Z` is standard normal distributed.
+    ///
+    /// # Returns
+    ///
+    /// A non-negative `f64` value generated from the half-normal distribution.
+    pub fn generate(&mut self) -> f64 {
+        self.sigma * self.rng.gen_standard_normal().abs()
+    }
+}
+
+impl Default for HalfNormal {
+    /// Creates a standard `HalfNormal` instance with σ = 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        HalfNormal::new(1_f64).unwrap()
+    }
+}
+
+impl Quantile for HalfNormal {
+    /// Evaluates the quantile function of the half-normal distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = σ Φ⁻¹((1 + p) / 2)
+    /// ```
+    /// where `Φ⁻¹` is the quantile function of the standard Normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.sigma * standard_normal_quantile(0.5_f64 * (1_f64 + p))
+    }
+}
+
+impl Density for HalfNormal {
+    /// Evaluates the density of the half-normal distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 2/σ φ(x / σ)    for x >= 0
+    /// ```
+    /// where `φ` is the density of the standard Normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            0_f64
+        } else {
+            2_f64 * standard_normal_pdf(x / self.sigma) / self.sigma
+        }
+    }
+
+    /// Evaluates the logarithm of the density of the HalfNormal distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln 2 + ln φ(x / σ) - ln σ
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            f64::NEG_INFINITY
+        } else {
+            std::f64::consts::LN_2 + ln_standard_normal_pdf(x / self.sigma) - math::ln(self.sigma)
+        }
+    }
+
+    /// Evaluates the distribution function of the half-normal distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 2 Φ(x / σ) - 1    for x >= 0
+    /// ```
+    /// where `Φ` is the distribution function of the standard Normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            0_f64
+        } else {
+            2_f64 * standard_normal_cdf(x / self.sigma) - 1_f64
+        }
+    }
+}
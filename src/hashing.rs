@@ -0,0 +1,271 @@
+//! This module contains the implementation of the `UniversalHash`, `TabulationHash` and `MinHash` structs and their methods.
+
+use crate::rng::Rng;
+
+/// A randomly chosen hash function from the multiply-shift family of Carter and Wegman style universal hashing.
+///
+/// The hash of a key `x` is calculated as
+/// ```text
+/// h(x) = (a x + b) mod 2⁶⁴
+/// ```
+/// where `a` is a random odd number and `b` is a random number.
+/// The hash is reduced to `l` bits by taking the `l` most significant bits, which are the well mixed ones.
+/// For keys with at most 32 bits this family is strongly universal (pairwise independent).
+///
+/// # Fields
+///
+/// * `a` - The odd multiplier.
+/// * `b` - The increment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UniversalHash {
+    /// The odd multiplier.
+    a: u64,
+
+    /// The increment.
+    b: u64,
+}
+
+impl UniversalHash {
+    /// Creates a new `UniversalHash` instance with parameters drawn from a given random number generator.
+    ///
+    /// Seeding the random number generator makes the hash function reproducible.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator used.
+    ///
+    /// # Returns
+    ///
+    /// A random `UniversalHash` instance.
+    pub fn new(rng: &mut Rng) -> UniversalHash {
+        UniversalHash {
+            a: rng.next_u64() | 1_u64,
+            b: rng.next_u64(),
+        }
+    }
+
+    /// Hashes a given key.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `u64` representing the key.
+    ///
+    /// # Returns
+    ///
+    /// The hash as a `u64`.
+    pub fn hash(&self, x: u64) -> u64 {
+        self.a.wrapping_mul(x).wrapping_add(self.b)
+    }
+
+    /// Hashes a given key to a given number of bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `u64` representing the key.
+    /// * `bits` - A `u32` representing the number of bits of the hash. It must be between 1 and 64.
+    ///
+    /// # Returns
+    ///
+    /// The hash as a `u64` between 0 and `2^bits - 1`.
+    pub fn hash_bits(&self, x: u64, bits: u32) -> u64 {
+        self.hash(x) >> (64_u32 - bits)
+    }
+}
+
+/// A randomly chosen hash function from the simple tabulation hashing family.
+///
+/// The key is split into its 8 bytes `x₀, ..., x₇` and the hash is calculated as
+/// ```text
+/// h(x) = T₀[x₀] ⊕ ... ⊕ T₇[x₇]
+/// ```
+/// where the `Tᵢ` are tables of random numbers.
+/// This family is 3-independent and behaves like a truly random hash function in many applications, e.g. linear probing.
+///
+/// # Fields
+///
+/// * `tables` - The 8 tables of 256 random numbers each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabulationHash {
+    /// The tables of random numbers.
+    tables: Box<[[u64; 256]; 8]>,
+}
+
+impl TabulationHash {
+    /// Creates a new `TabulationHash` instance with tables drawn from a given random number generator.
+    ///
+    /// Seeding the random number generator makes the hash function reproducible.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator used.
+    ///
+    /// # Returns
+    ///
+    /// A random `TabulationHash` instance.
+    pub fn new(rng: &mut Rng) -> TabulationHash {
+        let mut tables: Box<[[u64; 256]; 8]> = Box::new([[0_u64; 256]; 8]);
+
+        for table in tables.iter_mut() {
+            for entry in table.iter_mut() {
+                *entry = rng.next_u64();
+            }
+        }
+        TabulationHash { tables }
+    }
+
+    /// Hashes a given key.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `u64` representing the key.
+    ///
+    /// # Returns
+    ///
+    /// The hash as a `u64`.
+    pub fn hash(&self, x: u64) -> u64 {
+        x.to_le_bytes()
+            .iter()
+            .zip(self.tables.iter())
+            .fold(0_u64, |hash, (&byte, table)| hash ^ table[byte as usize])
+    }
+}
+
+/// A struct for computing MinHash signatures of sets.
+///
+/// The signature consists of the minimal hash of the elements for `k` independent hash functions.
+/// The hash functions are multiply-shift hash functions followed by the SplitMix64 finalizer.
+/// Both are permutations of the `u64` values, and the finalizer removes the regular structure of multiply-shift,
+/// which would otherwise bias the minima of consecutive keys.
+/// The probability that two sets have the same minimum for one hash function is approximately their Jaccard similarity.
+///
+/// The hash functions are derived deterministically from a single master seed using `Rng::derive_seed`,
+/// so the same seed always produces comparable signatures.
+///
+/// # Fields
+///
+/// * `seed` - The master seed.
+/// * `hashes` - The hash functions, one per permutation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinHash {
+    /// The master seed.
+    seed: u64,
+
+    /// The hash functions.
+    hashes: Vec<UniversalHash>,
+}
+
+impl MinHash {
+    /// Creates a new `MinHash` instance with a given number of permutations and master seed.
+    ///
+    /// The i-th hash function is drawn from a `Rng` seeded with `Rng::derive_seed(seed, i)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_permutations` - A `usize` representing the length `k` of the signatures.
+    /// * `seed` - A `u64` representing the master seed.
+    ///
+    /// # Returns
+    ///
+    /// A `MinHash` instance.
+    pub fn new(n_permutations: usize, seed: u64) -> MinHash {
+        let hashes: Vec<UniversalHash> = Self::derive_seeds(seed, n_permutations)
+            .into_iter()
+            .map(|derived| UniversalHash::new(&mut Rng::new_seed(derived)))
+            .collect();
+
+        MinHash { seed, hashes }
+    }
+
+    /// Derives a given number of independent seeds from a master seed.
+    ///
+    /// This can be used to seed the `k` hash functions of a Bloom filter or similar sketches.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` representing the master seed.
+    /// * `k` - A `usize` representing the number of seeds.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u64>` of length `k`, where the i-th entry is `Rng::derive_seed(seed, i)`.
+    pub fn derive_seeds(seed: u64, k: usize) -> Vec<u64> {
+        (0_u64..k as u64)
+            .map(|index| Rng::derive_seed(seed, index))
+            .collect()
+    }
+
+    /// Returns the master seed.
+    ///
+    /// # Returns
+    ///
+    /// The master seed as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of permutations, i.e. the length of the signatures.
+    ///
+    /// # Returns
+    ///
+    /// The number of permutations as a `usize`.
+    pub fn n_permutations(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Computes the signature of a set.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The elements of the set, already hashed to `u64`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u64>` of length `n_permutations` containing the minimal hash for every permutation.
+    /// For an empty set all entries are `u64::MAX`.
+    pub fn signature<I: IntoIterator<Item = u64>>(&self, items: I) -> Vec<u64> {
+        let mut signature: Vec<u64> = vec![u64::MAX; self.hashes.len()];
+
+        for item in items {
+            for (minimum, hash) in signature.iter_mut().zip(self.hashes.iter()) {
+                *minimum = (*minimum).min(Rng::mix64(hash.hash(item)));
+            }
+        }
+        signature
+    }
+
+    /// Estimates the Jaccard similarity of two sets from their signatures.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The signature of the first set.
+    /// * `b` - The signature of the second set, computed by the same `MinHash`.
+    ///
+    /// # Returns
+    ///
+    /// The fraction of equal entries as a `f64` between 0 and 1.
+    pub fn similarity(a: &[u64], b: &[u64]) -> f64 {
+        let equal: usize = a.iter().zip(b.iter()).filter(|(x, y)| x == y).count();
+        equal as f64 / a.len().max(1_usize) as f64
+    }
+}
+
+/// Hashes a byte string with the 64-bit FNV-1a hash function.
+///
+/// FNV-1a is a simple and fast hash function whose value does not depend on the platform or the compiler version.
+/// Therefore, it is suited for deriving reproducible values from keys.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to hash.
+///
+/// # Returns
+///
+/// The hash as a `u64`.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xCBF29CE484222325_u64;
+    const PRIME: u64 = 0x100000001B3_u64;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
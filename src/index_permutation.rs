@@ -0,0 +1,205 @@
+//! This module contains the implementation of the `IndexPermutation` struct and its methods.
+
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for a pseudorandom permutation of the indices `0, ..., n - 1` which is never materialized.
+///
+/// The permutation is a balanced Feistel network on the smallest even number of bits `2 h` with `4ʰ >= n`.
+/// An index is split into a left and a right half of `h` bits and every round maps
+/// ```text
+/// (L, R) -> (R, L ⊕ F(R, kᵢ))
+/// ```
+/// where `F` is a hash of the right half and the round key `kᵢ`, derived from the seed.
+/// Every round is invertible, so the network is a permutation of `[0, 4ʰ)`.
+/// Images outside of `[0, n)` are mapped again until they fall into the range (cycle walking),
+/// which restricts the permutation to `[0, n)`. Since `4ʰ < 4 n`, this needs less than 4 rounds on average.
+///
+/// Therefore, the image of an index is calculated in constant time and memory,
+/// which allows traversing huge datasets in a random order without storing a permutation of their indices.
+///
+/// # Fields
+///
+/// * `n` - The number of indices.
+/// * `seed` - The seed the round keys are derived from.
+/// * `half_bits` - The number of bits `h` of each half.
+/// * `keys` - The round keys.
+///
+/// # Notes
+///
+/// The permutation is not uniformly distributed over all permutations, but it is well mixed for all practical purposes.
+/// It is not cryptographically secure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexPermutation {
+    /// The number of indices.
+    n: u64,
+
+    /// The seed the round keys are derived from.
+    seed: u64,
+
+    /// The number of bits of each half.
+    half_bits: u32,
+
+    /// The round keys.
+    keys: [u64; IndexPermutation::ROUNDS],
+}
+
+impl IndexPermutation {
+    /// The number of rounds of the Feistel network.
+    const ROUNDS: usize = 6_usize;
+}
+
+impl IndexPermutation {
+    /// Creates a new `IndexPermutation` instance of `0, ..., n - 1` with a given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `u64` representing the number of indices. It must be a positive integer.
+    /// * `seed` - A `u64` the permutation is derived from. The same seed always gives the same permutation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IndexPermutation)` - Returns an instance of `IndexPermutation` if `n` is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `n` is 0.
+    pub fn new(n: u64, seed: u64) -> Result<IndexPermutation, RngError> {
+        RngError::check_positive(n as f64)?;
+
+        // Smallest h with 4^h >= n, but at least 1
+        let bits: u32 = u64::BITS - (n - 1_u64).leading_zeros();
+        let half_bits: u32 = bits.div_ceil(2_u32).max(1_u32);
+
+        let mut keys: [u64; IndexPermutation::ROUNDS] = [0_u64; IndexPermutation::ROUNDS];
+        for (round, key) in keys.iter_mut().enumerate() {
+            *key = Rng::derive_seed(seed, round as u64);
+        }
+
+        Ok(IndexPermutation {
+            n,
+            seed,
+            half_bits,
+            keys,
+        })
+    }
+
+    /// Returns the number of indices.
+    ///
+    /// # Returns
+    ///
+    /// The number of indices `n` as a `u64`.
+    pub fn len(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns whether the permutation is empty, which is never the case.
+    ///
+    /// # Returns
+    ///
+    /// `false`, because `n` is positive.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0_u64
+    }
+
+    /// Returns the seed the permutation is derived from.
+    ///
+    /// # Returns
+    ///
+    /// The seed as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Calculates the image of an index under the permutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - A `u64` smaller than `n`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The image of the index, which is smaller than `n`.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the index is not smaller than `n`.
+    pub fn get(&self, index: u64) -> Result<u64, RngError> {
+        self.check_index(index)?;
+
+        let mut value: u64 = self.encrypt(index);
+        while value >= self.n {
+            value = self.encrypt(value);
+        }
+        Ok(value)
+    }
+
+    /// Calculates the index which is mapped to a given image, i.e. applies the inverse permutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - A `u64` smaller than `n`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The index `i` with `get(i) = image`.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the image is not smaller than `n`.
+    pub fn inverse(&self, image: u64) -> Result<u64, RngError> {
+        self.check_index(image)?;
+
+        let mut value: u64 = self.decrypt(image);
+        while value >= self.n {
+            value = self.decrypt(value);
+        }
+        Ok(value)
+    }
+
+    /// Returns an iterator over the images of `0, ..., n - 1`, i.e. all indices in a random order.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding every index smaller than `n` exactly once.
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        (0_u64..self.n).map(|index| {
+            let mut value: u64 = self.encrypt(index);
+            while value >= self.n {
+                value = self.encrypt(value);
+            }
+            value
+        })
+    }
+
+    /// Returns an `IntervalError` if the index is not smaller than `n`.
+    fn check_index(&self, index: u64) -> Result<(), RngError> {
+        if index >= self.n {
+            return Err(RngError::interval(
+                index as f64,
+                0_f64,
+                (self.n - 1_u64) as f64,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Applies the Feistel network to a value of `2 h` bits.
+    fn encrypt(&self, value: u64) -> u64 {
+        let mask: u64 = (1_u64 << self.half_bits) - 1_u64;
+        let mut left: u64 = value >> self.half_bits;
+        let mut right: u64 = value & mask;
+
+        for &key in self.keys.iter() {
+            let next: u64 = left ^ (Rng::mix64(right ^ key) & mask);
+            left = right;
+            right = next;
+        }
+        (left << self.half_bits) | right
+    }
+
+    /// Applies the inverse of the Feistel network to a value of `2 h` bits.
+    fn decrypt(&self, value: u64) -> u64 {
+        let mask: u64 = (1_u64 << self.half_bits) - 1_u64;
+        let mut left: u64 = value >> self.half_bits;
+        let mut right: u64 = value & mask;
+
+        for &key in self.keys.iter().rev() {
+            let previous: u64 = right ^ (Rng::mix64(left ^ key) & mask);
+            right = left;
+            left = previous;
+        }
+        (left << self.half_bits) | right
+    }
+}
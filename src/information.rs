@@ -0,0 +1,58 @@
+//! This module contains information-theoretic comparisons of distributions.
+//!
+//! The entropy of a single distribution is provided by `Density::entropy` and `DiscreteDistribution::entropy`.
+//! The Kullback-Leibler divergence `D(P || Q)` measures how much information is lost when `Q` is used to approximate `P`.
+//! It is 0 if and only if the distributions are equal, but it is not symmetric.
+
+use crate::density::{Density, quadrature};
+use crate::discrete_distribution::DiscreteDistribution;
+
+/// Calculates the Kullback-Leibler divergence of two discrete distributions in nats.
+///
+/// ```text
+/// D(P || Q) = Σ P(X = k) ln(P(X = k) / Q(X = k))
+/// ```
+/// The sum runs over the values of `p.pmf_table()`.
+///
+/// # Arguments
+///
+/// * `p` - The distribution `P` the expectation is taken with.
+/// * `q` - The approximating distribution `Q`.
+///
+/// # Returns
+///
+/// A non-negative `f64` representing the divergence.
+/// It is infinity if `Q` assigns the probability 0 to a value which is possible under `P`.
+pub fn kl_divergence<P: DiscreteDistribution, Q: DiscreteDistribution>(p: &P, q: &Q) -> f64 {
+    p.pmf_table()
+        .iter()
+        .filter(|(_, probability)| *probability > 0_f64)
+        .map(|&(k, probability)| probability * (p.ln_pmf(k) - q.ln_pmf(k)))
+        .sum::<f64>()
+        .max(0_f64)
+}
+
+/// Calculates the Kullback-Leibler divergence of two continuous distributions in nats.
+///
+/// Substituting `x = Q_P(u)` with the quantile function of `P` turns the divergence into an integral over the probabilities
+/// ```text
+/// D(P || Q) = ∫ f_P(x) ln(f_P(x) / f_Q(x)) dx = ∫₀¹ ln(f_P(Q_P(u)) / f_Q(Q_P(u))) du
+/// ```
+/// which is approximated by the midpoint rule.
+///
+/// # Arguments
+///
+/// * `p` - The distribution `P` the expectation is taken with.
+/// * `q` - The approximating distribution `Q`.
+///
+/// # Returns
+///
+/// A non-negative `f64` representing the divergence.
+/// It is infinity if the support of `P` is not contained in the support of `Q`.
+pub fn kl_divergence_density<P: Density, Q: Density>(p: &P, q: &Q) -> f64 {
+    quadrature(|u| {
+        let x: f64 = p.quantile(u);
+        p.ln_pdf(x) - q.ln_pdf(x)
+    })
+    .max(0_f64)
+}
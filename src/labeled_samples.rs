@@ -0,0 +1,215 @@
+//! This module contains the implementation of the `LabeledSamples` struct and its methods.
+
+use std::collections::BTreeMap;
+
+use crate::auxiliary::{mean, variance};
+use crate::rng::RngTrait;
+
+/// A batch of samples together with the information needed to describe and reproduce it.
+///
+/// # Fields
+///
+/// * `distribution` - The name of the distribution.
+/// * `parameters` - The names and values of the parameters of the distribution.
+/// * `seed` - The seed of the generator.
+/// * `start_index` - The index of the first sample of the batch in the stream of the generator.
+/// * `values` - The samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleBatch {
+    /// The name of the distribution.
+    pub distribution: String,
+
+    /// The names and values of the parameters.
+    pub parameters: Vec<(String, f64)>,
+
+    /// The seed of the generator.
+    pub seed: u64,
+
+    /// The index of the first sample in the stream of the generator.
+    pub start_index: usize,
+
+    /// The samples.
+    pub values: Vec<f64>,
+}
+
+/// Summary statistics of all samples of a distribution.
+///
+/// # Fields
+///
+/// * `distribution` - The name of the distribution.
+/// * `count` - The number of samples.
+/// * `mean` - The sample mean.
+/// * `variance` - The (biased) sample variance.
+/// * `min` - The smallest sample.
+/// * `max` - The largest sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleSummary {
+    /// The name of the distribution.
+    pub distribution: String,
+
+    /// The number of samples.
+    pub count: usize,
+
+    /// The sample mean.
+    pub mean: f64,
+
+    /// The (biased) sample variance.
+    pub variance: f64,
+
+    /// The smallest sample.
+    pub min: f64,
+
+    /// The largest sample.
+    pub max: f64,
+}
+
+/// A container of labeled sample batches, making the output of simulations with multiple distributions self-describing.
+///
+/// Every batch records the distribution name, its parameters, the seed and the index of its first draw,
+/// so every sample can be traced back to the generator and position it came from.
+///
+/// # Fields
+///
+/// * `batches` - The recorded batches in the order they were added.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LabeledSamples {
+    /// The recorded batches.
+    batches: Vec<SampleBatch>,
+}
+
+impl LabeledSamples {
+    /// Creates a new empty `LabeledSamples` instance.
+    ///
+    /// # Returns
+    ///
+    /// An empty `LabeledSamples` instance.
+    pub fn new() -> LabeledSamples {
+        LabeledSamples {
+            batches: Vec::new(),
+        }
+    }
+
+    /// Generates a batch of samples from a distribution and records it.
+    ///
+    /// The draw index continues after the previous batches with the same distribution name and seed,
+    /// so consecutive calls with the same generator result in consecutive indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the distribution.
+    /// * `parameters` - The names and values of the parameters of the distribution.
+    /// * `distribution` - Any distribution implementing `RngTrait`.
+    /// * `number` - A `usize` representing the number of samples.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the recorded batch.
+    pub fn record<D: RngTrait>(
+        &mut self,
+        name: &str,
+        parameters: &[(&str, f64)],
+        distribution: &mut D,
+        number: usize,
+    ) -> &SampleBatch {
+        let seed: u64 = distribution.seed();
+        let start_index: usize = self
+            .batches
+            .iter()
+            .filter(|batch| batch.distribution == name && batch.seed == seed)
+            .map(|batch| batch.values.len())
+            .sum();
+
+        self.push(SampleBatch {
+            distribution: name.to_string(),
+            parameters: parameters
+                .iter()
+                .map(|&(key, value)| (key.to_string(), value))
+                .collect(),
+            seed,
+            start_index,
+            values: distribution.generate_multiple(number),
+        })
+    }
+
+    /// Adds an already labeled batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - The batch to add.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the added batch.
+    pub fn push(&mut self, batch: SampleBatch) -> &SampleBatch {
+        self.batches.push(batch);
+        &self.batches[self.batches.len() - 1_usize]
+    }
+
+    /// Returns all recorded batches.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the batches in the order they were added.
+    pub fn batches(&self) -> &[SampleBatch] {
+        &self.batches
+    }
+
+    /// Returns the total number of samples.
+    ///
+    /// # Returns
+    ///
+    /// The number of samples as a `usize`.
+    pub fn len(&self) -> usize {
+        self.batches.iter().map(|batch| batch.values.len()).sum()
+    }
+
+    /// Checks if there are no samples.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no batch contains a sample.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0_usize
+    }
+
+    /// Groups the samples by the name of their distribution.
+    ///
+    /// # Returns
+    ///
+    /// A `BTreeMap` from the distribution names to all of their samples, in the order they were recorded.
+    pub fn group_by_distribution(&self) -> BTreeMap<String, Vec<f64>> {
+        let mut groups: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+
+        for batch in self.batches.iter() {
+            groups
+                .entry(batch.distribution.clone())
+                .or_default()
+                .extend_from_slice(&batch.values);
+        }
+        groups
+    }
+
+    /// Calculates summary statistics for every distribution.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<SampleSummary>` sorted by the distribution names. Distributions without samples are omitted.
+    pub fn summary(&self) -> Vec<SampleSummary> {
+        self.group_by_distribution()
+            .into_iter()
+            .filter(|(_, values)| !values.is_empty())
+            .map(|(distribution, values)| {
+                let sample_mean: f64 = mean(&values);
+
+                SampleSummary {
+                    distribution,
+                    count: values.len(),
+                    mean: sample_mean,
+                    variance: variance(&values, sample_mean),
+                    min: values.iter().copied().fold(f64::INFINITY, f64::min),
+                    max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                }
+            })
+            .collect()
+    }
+}
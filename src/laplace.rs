@@ -1,7 +1,13 @@
 //! This module contains the implementation of the `Laplace` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
-use crate::auxiliary::simple_ln;
+use crate::auxiliary::{mean, simple_ln};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -16,6 +22,7 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `location` - The location (μ) of the Laplace distribution.
 /// * `scale` - The scale (s) of the Laplace distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Laplace {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -28,6 +35,18 @@ pub struct Laplace {
 }
 
 auto_rng_trait!(Laplace);
+auto_builder!(
+    Laplace,
+    LaplaceBuilder {
+        location: f64,
+        scale: f64
+    }
+);
+auto_params!(Laplace {
+    location: f64,
+    scale: f64
+});
+auto_display!(Laplace { "μ" = location, "s" = scale });
 
 impl Laplace {
     /// Creates a new `Laplace` instance with a given rate.
@@ -43,8 +62,10 @@ impl Laplace {
     /// # Returns
     ///
     /// * `Ok(Laplace)` - Returns an instance of `Laplace` if the `scale` is positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(location: f64, scale: f64) -> Result<Laplace, RngError> {
+        RngError::check_finite(location)?;
+        RngError::check_finite(scale)?;
         RngError::check_positive(scale)?;
 
         Ok(Laplace {
@@ -74,4 +95,134 @@ impl Laplace {
 
         self.location - self.scale * f64::signum(uni) * simple_ln(1_f64 - 2_f64 * f64::abs(uni))
     }
+
+    /// Estimates the parameters of a `Laplace` distribution from samples using maximum likelihood.
+    ///
+    /// The estimators are
+    /// ```text
+    /// μ = median x_i
+    /// s = 1/n Σ |x_i - μ|
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Laplace)` - Returns the fitted instance of `Laplace`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if all samples are equal.
+    pub fn fit(samples: &[f64]) -> Result<Laplace, RngError> {
+        RngError::check_empty(samples)?;
+
+        let mut sorted: Vec<f64> = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let middle: usize = sorted.len() / 2_usize;
+        let median: f64 = if sorted.len().is_multiple_of(2_usize) {
+            0.5_f64 * (sorted[middle - 1_usize] + sorted[middle])
+        } else {
+            sorted[middle]
+        };
+
+        let deviations: Vec<f64> = samples.iter().map(|x| (x - median).abs()).collect();
+        Laplace::new(median, mean(&deviations))
+    }
+}
+
+impl Default for Laplace {
+    /// Creates a standard `Laplace` instance with location 0 and scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Laplace::new(0_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for Laplace {
+    /// Evaluates the quantile function of the Laplace distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = location + scale ln(2 p)          for p < 1/2
+    /// Q(p) = location - scale ln(2 - 2 p)      for p >= 1/2
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        if p < 0.5_f64 {
+            self.location + self.scale * math::ln(2_f64 * p)
+        } else {
+            self.location - self.scale * math::ln(2_f64 - 2_f64 * p)
+        }
+    }
+}
+
+impl Density for Laplace {
+    /// Evaluates the density of the Laplace distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 1/(2 b) exp(-|x - μ| / b)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        math::exp(-(x - self.location).abs() / self.scale) / (2_f64 * self.scale)
+    }
+
+    /// Evaluates the logarithm of the density of the Laplace distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = -|x - μ| / b - ln(2 b)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        -(x - self.location).abs() / self.scale - math::ln(2_f64 * self.scale)
+    }
+
+    /// Evaluates the distribution function of the Laplace distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 1/2 exp((x - μ) / b)        for x < μ
+    /// F(x) = 1 - 1/2 exp(-(x - μ) / b)   for x >= μ
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        let z: f64 = (x - self.location) / self.scale;
+        if z < 0_f64 {
+            0.5_f64 * math::exp(z)
+        } else {
+            1_f64 - 0.5_f64 * math::exp(-z)
+        }
+    }
 }
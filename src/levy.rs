@@ -0,0 +1,199 @@
+//! This module contains the implementation of the `Levy` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::auxiliary::{standard_normal_cdf, standard_normal_quantile};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a Levy distribution.
+///
+/// The Levy distribution is the stable distribution with `α = 1/2` and `β = 1`,
+/// e.g. the distribution of the first time a Brownian motion hits a level.
+/// It is heavy tailed, so neither its mean nor its variance exist.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `location` - The location (μ) of the Levy distribution.
+/// * `scale` - The scale (c) of the Levy distribution. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct Levy {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The location (μ) of the distribution.
+    location: f64,
+
+    /// The scale (c) of the distribution.
+    scale: f64,
+}
+
+auto_rng_trait!(Levy);
+auto_builder!(
+    Levy,
+    LevyBuilder {
+        location: f64,
+        scale: f64
+    }
+);
+auto_params!(Levy {
+    location: f64,
+    scale: f64
+});
+auto_display!(Levy { "μ" = location, "c" = scale });
+
+impl Levy {
+    /// Creates a new `Levy` instance with a given location and scale.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - A `f64` representing the location (μ) of the Levy distribution.
+    /// * `scale` - A `f64` representing the scale (c) of the Levy distribution. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Levy)` - Returns an instance of `Levy` if the scale is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the scale is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(location: f64, scale: f64) -> Result<Levy, RngError> {
+        RngError::check_finite(location)?;
+        RngError::check_finite(scale)?;
+        RngError::check_positive(scale)?;
+
+        Ok(Levy {
+            rng: Rng::new(),
+            location,
+            scale,
+        })
+    }
+
+    /// Generates a random value from the Levy distribution.
+    ///
+    /// This uses the formula
+    /// ```text
+    /// X = μ + c / Z²
+    /// ```
+    /// where `Z` is standard normal distributed.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the Levy distribution.
+    pub fn generate(&mut self) -> f64 {
+        let normal: f64 = self.rng.gen_standard_normal();
+
+        self.location + self.scale / (normal * normal)
+    }
+}
+
+impl Default for Levy {
+    /// Creates a standard `Levy` instance with location 0 and scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Levy::new(0_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for Levy {
+    /// Evaluates the quantile function of the Levy distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = μ + c / Φ⁻¹(1 - p/2)²
+    /// ```
+    /// where `Φ⁻¹` is the quantile function of the standard Normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        let normal: f64 = standard_normal_quantile(1_f64 - 0.5_f64 * p);
+
+        self.location + self.scale / (normal * normal)
+    }
+}
+
+impl Density for Levy {
+    /// Evaluates the density of the Levy distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = sqrt(c / (2π)) exp(-c / (2 (x - μ))) / (x - μ)^(3/2)    for x > μ
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return 0_f64;
+        }
+
+        let z: f64 = x - self.location;
+        (self.scale / (2_f64 * std::f64::consts::PI)).sqrt() * math::exp(-0.5_f64 * self.scale / z)
+            / math::powf(z, 1.5_f64)
+    }
+
+    /// Evaluates the logarithm of the density of the Levy distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln(c / (2π)) / 2 - c / (2 (x - μ)) - 3/2 ln(x - μ)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return f64::NEG_INFINITY;
+        }
+
+        let z: f64 = x - self.location;
+        0.5_f64 * math::ln(self.scale / (2_f64 * std::f64::consts::PI))
+            - 0.5_f64 * self.scale / z
+            - 1.5_f64 * math::ln(z)
+    }
+
+    /// Evaluates the distribution function of the Levy distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 2 (1 - Φ(sqrt(c / (x - μ))))    for x > μ
+    /// ```
+    /// where `Φ` is the distribution function of the standard Normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return 0_f64;
+        }
+
+        2_f64 * standard_normal_cdf(-(self.scale / (x - self.location)).sqrt())
+    }
+}
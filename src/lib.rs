@@ -13,57 +13,191 @@
 
 #![allow(dead_code)]
 
+mod antithetic;
+mod arma;
+pub mod ascii;
+mod audit;
 mod auxiliary;
+#[cfg(feature = "bench")]
+pub mod bench;
 mod bernoulli;
 mod beta;
+mod beta_binomial;
 mod binomial;
+pub mod bootstrap;
+mod brownian_motion;
+pub mod calibrate;
+mod categorical;
 mod chi_squared;
+mod consistent_sample;
+mod copula;
+mod correlated_counts;
+mod custom_pdf;
+mod degenerate;
+mod density;
+pub mod diagnostics;
+mod discrete_distribution;
+mod discrete_uniform;
+pub mod ensembles;
+mod exact_discrete;
 mod exponential;
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod fisher;
 mod frechet;
+mod from_quantile;
 mod gamma;
+mod gamma_poisson;
+mod generalized_extreme_value;
+mod generalized_pareto;
 mod geometric;
 mod gumbel;
 mod gumbel2;
+mod half_normal;
+mod hashing;
+mod importance_sampler;
+mod index_permutation;
+pub mod information;
+mod labeled_samples;
 mod laplace;
+mod levy;
 mod loggamma;
 mod logistic;
 mod lognormal;
+mod markov_chain;
+mod math;
+pub mod maxent;
+mod maxwell_boltzmann;
+pub mod mcmc;
+mod nakagami;
 mod normal;
+mod normal_tail;
+mod ornstein_uhlenbeck;
 mod pareto;
+mod pert;
 mod poisson;
+mod poisson_process;
+#[cfg(feature = "python")]
+mod python;
+mod quantile;
+mod quantile_table;
+pub mod quasi;
+#[cfg(feature = "rand-compat")]
+mod rand_compat;
 mod randint;
+mod random_correlation;
+mod random_dag;
+mod random_ksat;
+mod random_rotation;
+mod random_tree;
+mod random_walk;
 mod rayleigh;
+mod rice;
 mod rng;
 mod rng_error;
+mod schedule;
+pub mod sketch;
+mod sparse_random;
+mod spatial_poisson;
+pub mod special;
+mod stable;
+mod stratified;
+mod stream_manager;
 mod students_t;
+mod tabulated_quantile;
+mod tessellation;
+mod tie_breaking;
 mod triangle;
+mod two_point;
 mod uniform;
+mod uniform_enum;
+mod uniform_sphere;
 mod weibull;
 mod randel;
+mod xoshiro512;
 
-pub use crate::bernoulli::Bernoulli;
-pub use crate::beta::Beta;
-pub use crate::binomial::Binomial;
-pub use crate::chi_squared::ChiSquared;
-pub use crate::exponential::Exponential;
-pub use crate::fisher::Fisher;
-pub use crate::frechet::Frechet;
-pub use crate::gamma::Gamma;
-pub use crate::geometric::Geometric;
-pub use crate::gumbel::Gumbel;
-pub use crate::gumbel2::Gumbel2;
-pub use crate::laplace::Laplace;
-pub use crate::loggamma::LogGamma;
-pub use crate::logistic::Logistic;
-pub use crate::lognormal::LogNormal;
-pub use crate::normal::Normal;
-pub use crate::pareto::Pareto;
-pub use crate::poisson::Poisson;
-pub use crate::rayleigh::Rayleigh;
-pub use crate::rng::{Rng, RngTrait};
+pub use crate::antithetic::AntitheticRng;
+pub use crate::arma::{Ar1, Arma};
+pub use crate::audit::{AuditLog, AuditRecord};
+pub use crate::bernoulli::{Bernoulli, BernoulliBuilder};
+pub use crate::beta::{Beta, BetaBuilder};
+pub use crate::beta_binomial::{BetaBinomial, BetaBinomialBuilder};
+pub use crate::binomial::{Binomial, BinomialAlgorithm, BinomialBuilder};
+pub use crate::brownian_motion::{BrownianMotion, GeometricBrownianMotion};
+pub use crate::categorical::Categorical;
+pub use crate::chi_squared::{ChiSquared, ChiSquaredBuilder};
+pub use crate::consistent_sample::ConsistentSample;
+pub use crate::copula::GaussianCopula;
+pub use crate::correlated_counts::CorrelatedCounts;
+pub use crate::custom_pdf::CustomPdf;
+pub use crate::degenerate::{Degenerate, DegenerateBuilder};
+pub use crate::density::{Density, HistogramBin, HistogramOverlay, PlotData};
+pub use crate::discrete_distribution::DiscreteDistribution;
+pub use crate::discrete_uniform::{DiscreteUniform, DiscreteUniformBuilder};
+pub use crate::exact_discrete::ExactDiscrete;
+pub use crate::exponential::{Exponential, ExponentialBuilder};
+pub use crate::fisher::{Fisher, FisherBuilder};
+pub use crate::frechet::{Frechet, FrechetBuilder};
+pub use crate::from_quantile::FromQuantile;
+pub use crate::gamma::{Gamma, GammaBuilder};
+pub use crate::gamma_poisson::{GammaPoisson, GammaPoissonBuilder};
+pub use crate::generalized_extreme_value::{
+    GeneralizedExtremeValue, GeneralizedExtremeValueBuilder,
+};
+pub use crate::generalized_pareto::{GeneralizedPareto, GeneralizedParetoBuilder};
+pub use crate::geometric::{Geometric, GeometricBuilder, GeometricConvention};
+pub use crate::gumbel::{Gumbel, GumbelBuilder};
+pub use crate::gumbel2::{Gumbel2, Gumbel2Builder};
+pub use crate::half_normal::{HalfNormal, HalfNormalBuilder};
+pub use crate::hashing::{MinHash, TabulationHash, UniversalHash};
+pub use crate::importance_sampler::ImportanceSampler;
+pub use crate::index_permutation::IndexPermutation;
+pub use crate::labeled_samples::{LabeledSamples, SampleBatch, SampleSummary};
+pub use crate::laplace::{Laplace, LaplaceBuilder};
+pub use crate::levy::{Levy, LevyBuilder};
+pub use crate::loggamma::{LogGamma, LogGammaBuilder};
+pub use crate::logistic::{Logistic, LogisticBuilder};
+pub use crate::lognormal::{LogNormal, LogNormalBuilder};
+pub use crate::markov_chain::MarkovChain;
+pub use crate::maxwell_boltzmann::{MaxwellBoltzmann, MaxwellBoltzmannBuilder};
+pub use crate::nakagami::{Nakagami, NakagamiBuilder};
+pub use crate::normal::{Normal, NormalBuilder};
+pub use crate::normal_tail::{NormalTail, NormalTailBuilder};
+pub use crate::ornstein_uhlenbeck::OrnsteinUhlenbeck;
+pub use crate::pareto::{Pareto, ParetoBuilder};
+pub use crate::pert::{Pert, PertBuilder};
+pub use crate::poisson::{Poisson, PoissonAlgorithm, PoissonBuilder};
+pub use crate::poisson_process::{NonHomogeneousPoissonProcess, PoissonGaps, PoissonProcess};
+pub use crate::quantile::Quantile;
+pub use crate::quantile_table::QuantileTable;
+#[cfg(feature = "rand-compat")]
+pub use crate::rand_compat::RandSource;
+pub use crate::randint::RandInt;
+pub use crate::random_correlation::RandomCorrelation;
+pub use crate::random_dag::{RandomDag, TaskGraph};
+pub use crate::random_ksat::RandomKSat;
+pub use crate::random_rotation::RandomRotation;
+pub use crate::random_tree::RandomTree;
+pub use crate::random_walk::RandomWalk;
+pub use crate::rayleigh::{Rayleigh, RayleighBuilder};
+pub use crate::rice::{Rice, RiceBuilder};
+pub use crate::rng::{Rng, RngTrait, UniformSource};
 pub use crate::rng_error::RngError;
-pub use crate::students_t::StudentsT;
-pub use crate::triangle::Triangle;
-pub use crate::uniform::Uniform;
-pub use crate::weibull::Weibull;
+pub use crate::schedule::Schedule;
+pub use crate::sparse_random::SparseRandom;
+pub use crate::spatial_poisson::{MaternCluster, SpatialPoisson};
+pub use crate::stable::{Stable, StableBuilder};
+pub use crate::stratified::Stratified;
+pub use crate::stream_manager::StreamManager;
+pub use crate::students_t::{StudentsT, StudentsTAlgorithm, StudentsTBuilder};
+pub use crate::tabulated_quantile::TabulatedQuantile;
+pub use crate::tessellation::{HardCore, PolygonSampler};
+pub use crate::tie_breaking::TieBreaking;
+pub use crate::triangle::{Triangle, TriangleBuilder};
+pub use crate::two_point::{TwoPoint, TwoPointBuilder};
+pub use crate::uniform::{Uniform, UniformBuilder};
+pub use crate::uniform_enum::{UniformEnum, VariantCount};
+pub use crate::uniform_sphere::{UniformBall, UniformCircle, UniformSphere};
+pub use crate::weibull::{Weibull, WeibullBuilder};
+pub use crate::xoshiro512::Xoshiro512;
@@ -1,6 +1,10 @@
 //! This module contains the implementation of the `LogGamma` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::math;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -15,6 +19,7 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `shape` - The shape (α) of the LogGamma distribution. Must be a positive number.
 /// * `scale` - The scale (θ) of the LogGamma distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct LogGamma {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -27,6 +32,18 @@ pub struct LogGamma {
 }
 
 auto_rng_trait!(LogGamma);
+auto_builder!(
+    LogGamma,
+    LogGammaBuilder {
+        shape: i32,
+        scale: f64
+    }
+);
+auto_params!(LogGamma {
+    shape: i32,
+    scale: f64
+});
+auto_display!(LogGamma { "α" = shape, "θ" = scale });
 
 impl LogGamma {
     /// Creates a new `LogGamma` instance with a given shape and scale.
@@ -43,8 +60,9 @@ impl LogGamma {
     /// # Returns
     ///
     /// * `Ok(LogGamma)` - Returns an instance of `LogGamma` if the shape and scale are valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the shape or scale are less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the shape or scale are less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(shape: i32, scale: f64) -> Result<Self, RngError> {
+        RngError::check_finite(scale)?;
         RngError::check_positive(shape as f64)?;
         RngError::check_positive(scale)?;
 
@@ -61,17 +79,21 @@ impl LogGamma {
     /// ```text
     /// LogGamma(n, θ) = exp(Gamma(n, θ))
     /// ```
+    /// The Gamma value is generated by `Rng::gen_erlang`, which does not underflow for large shapes.
     ///
     /// # Returns
     ///
     /// A `f64` value generated from the LogGamma distribution.
     pub fn generate(&mut self) -> f64 {
-        let mut prod: f64 = 1_f64;
-
-        for _ in 0_usize..(self.shape as usize) {
-            prod *= self.rng.generate();
-        }
+        math::exp(self.rng.gen_erlang(self.shape as u64) * self.scale)
+    }
+}
 
-        (prod.ln() * (-self.scale)).exp()
+impl Default for LogGamma {
+    /// Creates a standard `LogGamma` instance with shape 1 and scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        LogGamma::new(1_i32, 1_f64).unwrap()
     }
 }
@@ -1,7 +1,13 @@
 //! This module contains the implementation of the `Logistic` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
 use crate::auxiliary::simple_ln;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -16,6 +22,7 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `location` - The location (μ) of the Logistic distribution.
 /// * `scale` - The scale (s) of the Logistic distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Logistic {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -28,6 +35,18 @@ pub struct Logistic {
 }
 
 auto_rng_trait!(Logistic);
+auto_builder!(
+    Logistic,
+    LogisticBuilder {
+        location: f64,
+        scale: f64
+    }
+);
+auto_params!(Logistic {
+    location: f64,
+    scale: f64
+});
+auto_display!(Logistic { "μ" = location, "s" = scale });
 
 impl Logistic {
     /// Creates a new `Logistic` instance with a given rate.
@@ -43,8 +62,10 @@ impl Logistic {
     /// # Returns
     ///
     /// * `Ok(Logistic)` - Returns an instance of `Logistic` if the `scale` is positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(location: f64, scale: f64) -> Result<Logistic, RngError> {
+        RngError::check_finite(location)?;
+        RngError::check_finite(scale)?;
         RngError::check_positive(scale)?;
 
         Ok(Logistic {
@@ -75,3 +96,91 @@ impl Logistic {
         self.location + self.scale * (simple_ln(uni) - simple_ln(1_f64 - uni))
     }
 }
+
+impl Default for Logistic {
+    /// Creates a standard `Logistic` instance with location 0 and scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Logistic::new(0_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for Logistic {
+    /// Evaluates the quantile function of the Logistic distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = location + scale ln(p / (1 - p))
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.location + self.scale * math::ln(p / (1_f64 - p))
+    }
+}
+
+impl Density for Logistic {
+    /// Evaluates the density of the Logistic distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = exp(-z) / (s (1 + exp(-z))²)    with z = (x - μ) / s
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        // The density is symmetric, so exp(-|z|) avoids an overflow
+        let e: f64 = math::exp(-((x - self.location) / self.scale).abs());
+        e / (self.scale * (1_f64 + e) * (1_f64 + e))
+    }
+
+    /// Evaluates the logarithm of the density of the Logistic distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = -|z| - 2 ln(1 + exp(-|z|)) - ln s
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z: f64 = ((x - self.location) / self.scale).abs();
+        -z - 2_f64 * math::ln_1p(math::exp(-z)) - math::ln(self.scale)
+    }
+
+    /// Evaluates the distribution function of the Logistic distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 1 / (1 + exp(-z))    with z = (x - μ) / s
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        1_f64 / (1_f64 + math::exp(-(x - self.location) / self.scale))
+    }
+}
@@ -1,6 +1,16 @@
 //! This module contains the implementation of the `LogNormal` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::{
+    ln_standard_normal_pdf, mean, standard_normal_cdf, standard_normal_pdf,
+    standard_normal_quantile, variance,
+};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -16,6 +26,7 @@ use crate::rng_error::RngError;
 /// * `mean` - The mean (μ) of the Normal distribution.
 /// * `variance` - The variance (σ²) of the Normal distribution. Must be a positive number.
 /// * `std` - The standard deviation (σ) of the Normal distribution, pre-computed to optimize performance by avoiding repeated square rooting.
+#[derive(Debug, Clone)]
 pub struct LogNormal {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -31,6 +42,18 @@ pub struct LogNormal {
 }
 
 auto_rng_trait!(LogNormal);
+auto_builder!(
+    LogNormal,
+    LogNormalBuilder {
+        mean: f64,
+        variance: f64
+    }
+);
+auto_params!(LogNormal {
+    mean: f64,
+    variance: f64
+});
+auto_display!(LogNormal { "μ" = mean, "σ²" = variance });
 
 impl LogNormal {
     /// Creates a new `LogNormal` instance with a given mean and variance.
@@ -46,8 +69,10 @@ impl LogNormal {
     /// # Returns
     ///
     /// * `Ok(LogNormal)` - Returns an instance of `LogNormal` if the variance is valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the variance is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the variance is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(mean: f64, variance: f64) -> Result<LogNormal, RngError> {
+        RngError::check_finite(mean)?;
+        RngError::check_finite(variance)?;
         RngError::check_positive(variance)?;
 
         Ok(LogNormal {
@@ -72,6 +97,209 @@ impl LogNormal {
     pub fn generate(&mut self) -> f64 {
         let normal: f64 = self.rng.gen_standard_normal();
 
-        (self.std * normal + self.mean).exp()
+        math::exp(self.std * normal + self.mean)
+    }
+
+    /// Estimates the parameters of a `LogNormal` distribution from samples using maximum likelihood.
+    ///
+    /// The estimators are the ones of the Normal distribution applied to the logarithms of the samples:
+    /// ```text
+    /// μ = 1/n Σ ln x_i
+    /// σ² = 1/n Σ (ln x_i - μ)²
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of positive `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LogNormal)` - Returns the fitted instance of `LogNormal`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if a sample is not positive or all samples are equal.
+    pub fn fit(samples: &[f64]) -> Result<LogNormal, RngError> {
+        RngError::check_empty(samples)?;
+        for &sample in samples {
+            RngError::check_positive(sample)?;
+        }
+
+        let logs: Vec<f64> = samples.iter().map(|&x| math::ln(x)).collect();
+        let log_mean: f64 = mean(&logs);
+        LogNormal::new(log_mean, variance(&logs, log_mean))
+    }
+
+    /// Estimates the parameters of a `LogNormal` distribution from samples using the method of moments.
+    ///
+    /// With the sample mean `m` and the sample variance `v` the estimators are
+    /// ```text
+    /// σ² = ln(1 + v / m²)
+    /// μ = ln m - σ² / 2
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of positive `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LogNormal)` - Returns the fitted instance of `LogNormal`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if the mean is not positive or all samples are equal.
+    pub fn fit_moments(samples: &[f64]) -> Result<LogNormal, RngError> {
+        RngError::check_empty(samples)?;
+
+        let sample_mean: f64 = mean(samples);
+        RngError::check_positive(sample_mean)?;
+
+        let log_variance: f64 =
+            math::ln(1_f64 + variance(samples, sample_mean) / (sample_mean * sample_mean));
+        LogNormal::new(math::ln(sample_mean) - 0.5_f64 * log_variance, log_variance)
+    }
+
+    /// Creates a new `LogNormal` instance from a three-point estimate.
+    ///
+    /// The median is matched exactly and the spread is chosen such that the ratio of the P90 and the P10 estimate is matched:
+    /// ```text
+    /// μ = ln P50
+    /// σ = (ln P90 - ln P10) / (2 Φ⁻¹(0.9))
+    /// ```
+    /// where `Φ⁻¹` is the quantile function of the standard Normal distribution.
+    /// If the estimate is skewed on the logarithmic scale, i.e. `P50² ≠ P10 P90`, the P10 and P90 values are only matched approximately.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `p10` - A `f64` representing the 10 % quantile. It must be a positive number.
+    /// * `p50` - A `f64` representing the median. It must be bigger than `p10`.
+    /// * `p90` - A `f64` representing the 90 % quantile. It must be bigger than `p50`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LogNormal)` - Returns an instance of `LogNormal` if the estimates are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `p10` is not positive or an `OrderError` if the estimates are not increasing.
+    pub fn from_p10_p50_p90(p10: f64, p50: f64, p90: f64) -> Result<LogNormal, RngError> {
+        RngError::check_positive(p10)?;
+        RngError::check_order(p10, p50)?;
+        RngError::check_order(p50, p90)?;
+
+        let std: f64 =
+            (math::ln(p90) - math::ln(p10)) / (2_f64 * standard_normal_quantile(0.9_f64));
+        LogNormal::new(math::ln(p50), std * std)
+    }
+}
+
+impl LogNormalBuilder {
+    /// Sets the standard deviation (σ) of the underlying Normal distribution instead of the variance.
+    ///
+    /// This replaces a variance set before.
+    ///
+    /// # Arguments
+    ///
+    /// * `std_dev` - A `f64` representing the standard deviation (σ). It must be a positive number.
+    pub fn std_dev(self, std_dev: f64) -> Self {
+        // Keeping the sign lets `build` reject a non-positive standard deviation
+        self.variance(std_dev * std_dev.abs())
+    }
+}
+
+impl Default for LogNormal {
+    /// Creates a `LogNormal` instance whose logarithm is standard normal distributed.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        LogNormal::new(0_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for LogNormal {
+    /// Evaluates the quantile function of the LogNormal distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = exp(μ + σ Φ⁻¹(p))
+    /// ```
+    /// where `Φ⁻¹` is the quantile function of the standard Normal distribution, approximated with the algorithm of Acklam.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        math::exp(self.mean + self.std * standard_normal_quantile(p))
+    }
+}
+
+impl Density for LogNormal {
+    /// Evaluates the density of the LogNormal distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = φ((ln x - μ) / σ) / (σ x)    for x > 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0_f64 {
+            0_f64
+        } else {
+            standard_normal_pdf((math::ln(x) - self.mean) / self.std) / (self.std * x)
+        }
+    }
+
+    /// Evaluates the logarithm of the density of the LogNormal distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln φ((ln x - μ) / σ) - ln σ - ln x
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0_f64 {
+            f64::NEG_INFINITY
+        } else {
+            let ln_x: f64 = math::ln(x);
+            ln_standard_normal_pdf((ln_x - self.mean) / self.std) - math::ln(self.std) - ln_x
+        }
+    }
+
+    /// Evaluates the distribution function of the LogNormal distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = Φ((ln x - μ) / σ)    for x > 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0_f64 {
+            0_f64
+        } else {
+            standard_normal_cdf((math::ln(x) - self.mean) / self.std)
+        }
     }
 }
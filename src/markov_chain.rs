@@ -0,0 +1,149 @@
+//! This module contains the implementation of the `MarkovChain` struct and its methods.
+
+use crate::auto_seed_methods;
+use crate::categorical::Categorical;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for simulating a discrete-time Markov chain on the states `0, ..., n - 1`.
+///
+/// The chain is given by its transition matrix, where the entry `(i, j)` is the probability
+/// to move from state `i` to state `j` in one step.
+/// Every row of the matrix is stored as a `Categorical` distribution.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `rows` - The rows of the transition matrix as `Categorical` distributions.
+/// * `state` - The current state of the chain.
+#[derive(Debug, Clone)]
+pub struct MarkovChain {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The rows of the transition matrix.
+    rows: Vec<Categorical>,
+
+    /// The current state of the chain.
+    state: usize,
+}
+
+auto_seed_methods!(MarkovChain);
+
+impl MarkovChain {
+    /// Creates a new `MarkovChain` instance with a given transition matrix and initial state.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `transition_matrix` - A square `Vec<Vec<f64>>` whose rows are probability vectors, i.e. non-negative and summing up to 1.
+    /// * `initial_state` - A `usize` representing the state the chain starts in. It must be smaller than the number of states.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MarkovChain)` - Returns an instance of `MarkovChain` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if the matrix is empty, an `IntervalError` if the matrix is not square, a row does not sum up to 1 or the initial state is invalid, or a `NonNegativeError` if an entry is negative.
+    pub fn new(
+        transition_matrix: Vec<Vec<f64>>,
+        initial_state: usize,
+    ) -> Result<MarkovChain, RngError> {
+        RngError::check_empty(&transition_matrix)?;
+
+        let n: f64 = transition_matrix.len() as f64;
+        RngError::check_interval(initial_state as f64, 0_f64, n - 1_f64)?;
+
+        let mut rows: Vec<Categorical> = Vec::with_capacity(transition_matrix.len());
+        for row in transition_matrix {
+            RngError::check_interval(row.len() as f64, n, n)?;
+            rows.push(Categorical::new(row)?);
+        }
+
+        Ok(MarkovChain {
+            rng: Rng::new(),
+            rows,
+            state: initial_state,
+        })
+    }
+
+    /// Returns the current state of the chain.
+    ///
+    /// # Returns
+    ///
+    /// The current state as a `usize`.
+    pub fn state(&self) -> usize {
+        self.state
+    }
+
+    /// Sets the current state of the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - A `usize` representing the new state. It must be smaller than the number of states.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - if the state is valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the state is not smaller than the number of states.
+    pub fn set_state(&mut self, state: usize) -> Result<(), RngError> {
+        RngError::check_interval(state as f64, 0_f64, self.rows.len() as f64 - 1_f64)?;
+
+        self.state = state;
+        Ok(())
+    }
+
+    /// Moves the chain one step forward.
+    ///
+    /// # Returns
+    ///
+    /// The new state as a `usize`.
+    pub fn step(&mut self) -> usize {
+        let uniform: f64 = self.rng.generate();
+        self.state = self.rows[self.state].index_of(uniform);
+        self.state
+    }
+
+    /// Moves the chain multiple steps forward and records the visited states.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of steps.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<usize>` of length `n + 1` starting with the current state.
+    pub fn path(&mut self, n: usize) -> Vec<usize> {
+        let mut path: Vec<usize> = Vec::with_capacity(n + 1_usize);
+        path.push(self.state);
+
+        for _ in 0_usize..n {
+            path.push(self.step());
+        }
+        path
+    }
+
+    /// Estimates the stationary distribution of the chain by simulation.
+    ///
+    /// The chain is run for `n` steps from the current state and the relative frequencies of the visited states are returned.
+    /// For an irreducible and aperiodic chain these converge to the stationary distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of simulated steps.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` with the relative frequency of every state.
+    pub fn stationary_distribution(&mut self, n: usize) -> Vec<f64> {
+        let mut counts: Vec<usize> = vec![0_usize; self.rows.len()];
+
+        for _ in 0_usize..n {
+            counts[self.step()] += 1_usize;
+        }
+
+        counts
+            .into_iter()
+            .map(|count| count as f64 / n as f64)
+            .collect()
+    }
+}
@@ -0,0 +1,48 @@
+//! This module contains the transcendental functions used by the distributions.
+//!
+//! The methods like `f64::ln` or `f64::exp` call the math library of the platform,
+//! whose results may differ in the last bit between operating systems and architectures.
+//! Hence, the same seed generates the same samples on every platform up to a few ulps.
+//!
+//! With the `strict-reproducible` feature these functions use the `libm` crate instead,
+//! a pure Rust port of the musl math library, so the same seed generates bit-identical samples on every platform.
+//! The basic operations and `sqrt` are exactly rounded by IEEE 754 and need no replacement.
+
+/// Implements a transcendental function with the name of the `f64` method, which uses `libm` with the `strict-reproducible` feature.
+macro_rules! math_function {
+    ($name:ident, $libm:ident, $doc:literal) => {
+        #[doc = $doc]
+        #[inline]
+        pub(crate) fn $name(x: f64) -> f64 {
+            #[cfg(feature = "strict-reproducible")]
+            return libm::$libm(x);
+
+            #[cfg(not(feature = "strict-reproducible"))]
+            x.$name()
+        }
+    };
+    ($name:ident, $libm:ident, $doc:literal, 2) => {
+        #[doc = $doc]
+        #[inline]
+        pub(crate) fn $name(x: f64, y: f64) -> f64 {
+            #[cfg(feature = "strict-reproducible")]
+            return libm::$libm(x, y);
+
+            #[cfg(not(feature = "strict-reproducible"))]
+            x.$name(y)
+        }
+    };
+}
+
+math_function!(ln, log, "Returns the natural logarithm of `x`.");
+math_function!(ln_1p, log1p, "Returns `ln(1 + x)`, accurate for small `x`.");
+math_function!(log2, log2, "Returns the logarithm of `x` to the base 2.");
+math_function!(exp, exp, "Returns `e^x`.");
+math_function!(exp_m1, expm1, "Returns `e^x - 1`, accurate for small `x`.");
+math_function!(powf, pow, "Returns `x^y`.", 2);
+math_function!(cbrt, cbrt, "Returns the cube root of `x`.");
+math_function!(hypot, hypot, "Returns `sqrt(x² + y²)` without overflow.", 2);
+math_function!(sin, sin, "Returns the sine of `x` in radians.");
+math_function!(cos, cos, "Returns the cosine of `x` in radians.");
+math_function!(tan, tan, "Returns the tangent of `x` in radians.");
+math_function!(atan, atan, "Returns the arctangent of `x` in radians.");
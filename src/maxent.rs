@@ -0,0 +1,389 @@
+//! This module contains the construction of maximum entropy distributions from partial information.
+//!
+//! If only the range and maybe the mean and variance of an input are known,
+//! the least informative choice is the distribution with the highest entropy satisfying these constraints.
+//! On a bounded support [a, b] it has the exponential family density
+//! ```text
+//! f(x) ∝ exp(λ₁ x + λ₂ x²)
+//! ```
+//! where the terms of unused constraints are dropped.
+//! Without constraints this is the Uniform distribution, with a mean constraint a truncated Exponential distribution
+//! and with a mean and a variance constraint a truncated Normal distribution.
+//!
+//! The multipliers `λ` are found by Newton's method on the convex dual problem,
+//! using a discretization of the support into `GRID_SIZE` cells.
+//! The resulting `MaxEnt` distribution has a piecewise constant density on these cells.
+
+use crate::auto_rng_trait;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// The number of cells the support is discretized into.
+const GRID_SIZE: usize = 1_000_usize;
+
+/// The maximal number of Newton iterations.
+const MAX_ITERATIONS: usize = 200_usize;
+
+/// The maximal deviation of the moments from the constraints at which the Newton iteration stops.
+const TOLERANCE: f64 = 1e-12_f64;
+
+/// A constraint on a moment of a maximum entropy distribution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MomentConstraint {
+    /// The mean of the distribution.
+    /// It must lie strictly between the midpoints of the first and the last cell of the support.
+    Mean(f64),
+
+    /// The variance of the distribution.
+    /// It must lie strictly between the variances of the two extreme distributions with this mean on the midpoints of the cells:
+    /// the one on the two neighbouring midpoints of the mean and the one on the first and the last midpoint.
+    /// Without a `Mean` constraint the mean is taken to be the midpoint of the support.
+    Variance(f64),
+}
+
+/// A struct for generating random variables from a fitted maximum entropy distribution.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `low` - The lower bound of the support.
+/// * `high` - The upper bound of the support.
+/// * `probabilities` - The probabilities of the cells of the support.
+/// * `cdf` - The distribution function at the boundaries of the cells, starting with 0.
+#[derive(Debug, Clone)]
+pub struct MaxEnt {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The lower bound of the support.
+    low: f64,
+
+    /// The upper bound of the support.
+    high: f64,
+
+    /// The probabilities of the cells of the support.
+    probabilities: Vec<f64>,
+
+    /// The distribution function at the boundaries of the cells.
+    cdf: Vec<f64>,
+}
+
+auto_rng_trait!(MaxEnt);
+
+impl MaxEnt {
+    /// Generates a random value from the maximum entropy distribution.
+    ///
+    /// This uses the inverse transform of the piecewise linear distribution function.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value between `low` and `high`.
+    pub fn generate(&mut self) -> f64 {
+        let uni: f64 = self.rng.generate();
+        self.quantile(uni)
+    }
+
+    /// Returns the support of the distribution.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(low, high)` of the bounds of the support.
+    pub fn support(&self) -> (f64, f64) {
+        (self.low, self.high)
+    }
+}
+
+impl Quantile for MaxEnt {
+    /// Evaluates the quantile function of the maximum entropy distribution.
+    ///
+    /// The distribution function is linear on every cell, so it is inverted by finding the cell with a binary search
+    /// and interpolating linearly inside of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        let width: f64 = (self.high - self.low) / GRID_SIZE as f64;
+        let cell: usize = self
+            .cdf
+            .partition_point(|&value| value <= p)
+            .clamp(1_usize, GRID_SIZE)
+            - 1_usize;
+
+        let probability: f64 = self.probabilities[cell];
+        let fraction: f64 = if probability > 0_f64 {
+            ((p - self.cdf[cell]) / probability).clamp(0_f64, 1_f64)
+        } else {
+            0_f64
+        };
+        self.low + (cell as f64 + fraction) * width
+    }
+}
+
+/// Fits the maximum entropy distribution on a bounded support satisfying the given moment constraints.
+///
+/// This function initializes the underlying random number generator using a system-generated seed.
+///
+/// # Arguments
+///
+/// * `support` - A tuple `(a, b)` of the bounds of the support. `a` must be smaller than `b`.
+/// * `moment_constraints` - A slice of `MomentConstraint`. If a constraint is given multiple times, the last one is used.
+///
+/// # Returns
+///
+/// * `Ok(MaxEnt)` - Returns the maximum entropy distribution.
+/// * `Err(RngError)` - Returns an `OrderError` if the support is empty, the mean is not strictly inside of the outer midpoints or the variance is too small or too big, or a `PositiveError` if the variance is not positive.
+/// * `Err(RngError)` - Returns a `ConvergenceError` if Newton's method does not find the multipliers, e.g. for constraints very close to these bounds.
+///
+/// # Examples
+///
+/// ```
+/// use random::maxent::{MomentConstraint, fit};
+/// use random::RngTrait;
+///
+/// let mut distribution = fit((0_f64, 10_f64), &[MomentConstraint::Mean(3_f64)]).unwrap();
+/// let samples: Vec<f64> = distribution.generate_multiple(10);
+/// assert!(samples.iter().all(|x| (0_f64..=10_f64).contains(x)));
+/// ```
+pub fn fit(
+    support: (f64, f64),
+    moment_constraints: &[MomentConstraint],
+) -> Result<MaxEnt, RngError> {
+    let (low, high) = support;
+    RngError::check_order(low, high)?;
+
+    let mut mean: Option<f64> = None;
+    let mut variance: Option<f64> = None;
+    for constraint in moment_constraints {
+        match *constraint {
+            MomentConstraint::Mean(value) => mean = Some(value),
+            MomentConstraint::Variance(value) => variance = Some(value),
+        }
+    }
+
+    // The moments are matched on the midpoints of the cells, so they are bounded by the outer midpoints
+    let width: f64 = high - low;
+    let cell_width: f64 = width / GRID_SIZE as f64;
+    let first: f64 = low + 0.5_f64 * cell_width;
+    let last: f64 = high - 0.5_f64 * cell_width;
+    if let Some(mean) = mean {
+        RngError::check_order(first, mean)?;
+        RngError::check_order(mean, last)?;
+    }
+
+    // The moments of the support scaled to [0, 1]
+    let mut targets: Vec<f64> = Vec::with_capacity(2_usize);
+    if let Some(variance) = variance {
+        let mean: f64 = mean.unwrap_or(0.5_f64 * (low + high));
+        RngError::check_positive(variance)?;
+        RngError::check_order(variance, (mean - first) * (last - mean))?;
+
+        let cell: f64 = ((mean - first) / cell_width)
+            .floor()
+            .min((GRID_SIZE - 2_usize) as f64);
+        let below: f64 = first + cell * cell_width;
+        RngError::check_order((mean - below) * (below + cell_width - mean), variance)?;
+
+        let scaled_mean: f64 = (mean - low) / width;
+        targets.push(scaled_mean);
+        targets.push(variance / (width * width) + scaled_mean * scaled_mean);
+    } else if let Some(mean) = mean {
+        targets.push((mean - low) / width);
+    }
+
+    let probabilities: Vec<f64> = solve_multipliers(&targets)?;
+
+    let mut cdf: Vec<f64> = Vec::with_capacity(GRID_SIZE + 1_usize);
+    let mut sum: f64 = 0_f64;
+    cdf.push(sum);
+    for probability in probabilities.iter() {
+        sum += probability;
+        cdf.push(sum);
+    }
+
+    Ok(MaxEnt {
+        rng: Rng::new(),
+        low,
+        high,
+        probabilities,
+        cdf,
+    })
+}
+
+/// Finds the cell probabilities of the maximum entropy distribution on [0, 1] with `E[tᵏ] = targets[k - 1]`.
+///
+/// The multipliers minimize the convex dual function
+/// ```text
+/// D(λ) = ln Σ exp(λ · f(t_i)) - λ · targets
+/// ```
+/// whose gradient is the deviation of the moments from the targets and whose Hessian is the covariance matrix of `f(t) = (t, t², ...)`.
+/// Newton's method is used with step halving to guarantee a decrease of `D`.
+///
+/// The probabilities are only returned if they are finite and all moments are within `TOLERANCE` of the targets,
+/// otherwise a `ConvergenceError` with the largest deviation is returned.
+fn solve_multipliers(targets: &[f64]) -> Result<Vec<f64>, RngError> {
+    let dimension: usize = targets.len();
+    let features: Vec<Vec<f64>> = (0_usize..GRID_SIZE)
+        .map(|i| {
+            let t: f64 = (i as f64 + 0.5_f64) / GRID_SIZE as f64;
+            (1_i32..=dimension as i32).map(|k| t.powi(k)).collect()
+        })
+        .collect();
+
+    let mut multipliers: Vec<f64> = vec![0_f64; dimension];
+    let (mut probabilities, mut dual) = evaluate(&features, &multipliers, targets);
+
+    for iteration in 0_usize..=MAX_ITERATIONS {
+        let moments: Vec<f64> = (0_usize..dimension)
+            .map(|k| {
+                features
+                    .iter()
+                    .zip(probabilities.iter())
+                    .map(|(f, p)| f[k] * p)
+                    .sum()
+            })
+            .collect();
+        let gradient: Vec<f64> = moments.iter().zip(targets).map(|(m, t)| m - t).collect();
+        if probabilities.iter().any(|p| !p.is_finite()) {
+            return Err(RngError::convergence(f64::NAN));
+        }
+        let residual: f64 = gradient.iter().fold(0_f64, |max, g| max.max(g.abs()));
+        if residual < TOLERANCE {
+            return Ok(probabilities);
+        } else if iteration == MAX_ITERATIONS {
+            return Err(RngError::convergence(residual));
+        }
+
+        let hessian: Vec<Vec<f64>> = (0_usize..dimension)
+            .map(|j| {
+                (0_usize..dimension)
+                    .map(|k| {
+                        features
+                            .iter()
+                            .zip(probabilities.iter())
+                            .map(|(f, p)| (f[j] - moments[j]) * (f[k] - moments[k]) * p)
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        let direction: Vec<f64> = solve_small_system(&hessian, &gradient);
+
+        let mut step: f64 = 1_f64;
+        loop {
+            let candidate: Vec<f64> = multipliers
+                .iter()
+                .zip(direction.iter())
+                .map(|(l, d)| l - step * d)
+                .collect();
+            let (candidate_probabilities, candidate_dual) =
+                evaluate(&features, &candidate, targets);
+
+            // Close to the minimum the decrease of a full step is below the rounding error of `D`,
+            // whose terms are bounded by the multipliers as the features lie in [0, 1]
+            let scale: f64 = dual.abs() + multipliers.iter().map(|l| l.abs()).sum::<f64>();
+            let rounding: f64 = 8_f64 * f64::EPSILON * scale;
+            if candidate_dual <= dual + rounding || step < TOLERANCE {
+                multipliers = candidate;
+                probabilities = candidate_probabilities;
+                dual = candidate_dual;
+                break;
+            }
+            step *= 0.5_f64;
+        }
+    }
+    Err(RngError::convergence(f64::NAN))
+}
+
+/// Calculates the cell probabilities and the value of the dual function for given multipliers.
+///
+/// The exponents are shifted by their maximum to prevent overflow.
+fn evaluate(features: &[Vec<f64>], multipliers: &[f64], targets: &[f64]) -> (Vec<f64>, f64) {
+    let exponents: Vec<f64> = features
+        .iter()
+        .map(|f| f.iter().zip(multipliers).map(|(x, l)| x * l).sum())
+        .collect();
+    let max: f64 = exponents.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let weights: Vec<f64> = exponents.iter().map(|e| math::exp(e - max)).collect();
+    let sum: f64 = weights.iter().sum();
+    let offset: f64 = multipliers
+        .iter()
+        .zip(targets)
+        .map(|(l, t)| l * t)
+        .sum::<f64>();
+
+    (
+        weights.into_iter().map(|w| w / sum).collect(),
+        max + math::ln(sum) - offset,
+    )
+}
+
+/// Solves the linear system `A x = b` with one or two unknowns.
+fn solve_small_system(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    match b.len() {
+        1_usize => vec![b[0] / a[0][0]],
+        2_usize => {
+            let determinant: f64 = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+            vec![
+                (a[1][1] * b[0] - a[0][1] * b[1]) / determinant,
+                (a[0][0] * b[1] - a[1][0] * b[0]) / determinant,
+            ]
+        }
+        _ => Vec::new(),
+    }
+}
+
+impl Density for MaxEnt {
+    /// Evaluates the density of the maximum entropy distribution.
+    ///
+    /// The density is constant on every cell of the support.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`, which is 0 outside of the support.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.low || x > self.high {
+            return 0_f64;
+        }
+
+        let width: f64 = (self.high - self.low) / GRID_SIZE as f64;
+        let cell: usize = (((x - self.low) / width) as usize).min(GRID_SIZE - 1_usize);
+        self.probabilities[cell] / width
+    }
+
+    /// Evaluates the distribution function of the maximum entropy distribution.
+    ///
+    /// The distribution function is linear on every cell of the support.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.low {
+            return 0_f64;
+        } else if x >= self.high {
+            return 1_f64;
+        }
+
+        let width: f64 = (self.high - self.low) / GRID_SIZE as f64;
+        let position: f64 = (x - self.low) / width;
+        let cell: usize = (position as usize).min(GRID_SIZE - 1_usize);
+        self.cdf[cell] + (position - cell as f64) * self.probabilities[cell]
+    }
+}
@@ -0,0 +1,203 @@
+//! This module contains the implementation of the `MaxwellBoltzmann` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::auxiliary::{ln_standard_normal_pdf, standard_normal_cdf, standard_normal_pdf};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// The number of bisection steps used to evaluate the quantile function.
+const QUANTILE_ITERATIONS: usize = 100_usize;
+
+/// A struct for generating random variables from a Maxwell-Boltzmann distribution.
+///
+/// The Maxwell-Boltzmann distribution is the distribution of the speed of particles in an ideal gas,
+/// i.e. of the length of a three-dimensional vector of independently Normal distributed components with standard deviation `a`.
+/// For particles of mass `m` at temperature `T` the scale is `a = sqrt(k T / m)`, where `k` is the Boltzmann constant.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `scale` - The scale (a) of the distribution. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct MaxwellBoltzmann {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The scale (a) of the distribution.
+    scale: f64,
+}
+
+auto_rng_trait!(MaxwellBoltzmann);
+auto_builder!(MaxwellBoltzmann, MaxwellBoltzmannBuilder { scale: f64 });
+auto_params!(MaxwellBoltzmann { scale: f64 });
+auto_display!(MaxwellBoltzmann { "a" = scale });
+
+impl MaxwellBoltzmann {
+    /// Creates a new `MaxwellBoltzmann` instance with a given scale.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - A `f64` representing the scale (a) of the distribution. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MaxwellBoltzmann)` - Returns an instance of `MaxwellBoltzmann` if the scale is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the scale is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(scale: f64) -> Result<MaxwellBoltzmann, RngError> {
+        RngError::check_finite(scale)?;
+        RngError::check_positive(scale)?;
+
+        Ok(MaxwellBoltzmann {
+            rng: Rng::new(),
+            scale,
+        })
+    }
+
+    /// Generates a random value from the Maxwell-Boltzmann distribution.
+    ///
+    /// This uses the definition
+    /// ```text
+    /// X = a sqrt(Z₁² + Z₂² + Z₃²)
+    /// ```
+    /// where `Z₁`, `Z₂` and `Z₃` are independently standard normal distributed, i.e. `X / a` is Chi distributed with 3 degrees of freedom.
+    ///
+    /// # Returns
+    ///
+    /// A non-negative `f64` value generated from the Maxwell-Boltzmann distribution.
+    pub fn generate(&mut self) -> f64 {
+        let x: f64 = self.rng.gen_standard_normal();
+        let y: f64 = self.rng.gen_standard_normal();
+        let z: f64 = self.rng.gen_standard_normal();
+
+        self.scale * (x * x + y * y + z * z).sqrt()
+    }
+}
+
+impl Default for MaxwellBoltzmann {
+    /// Creates a standard `MaxwellBoltzmann` instance with scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        MaxwellBoltzmann::new(1_f64).unwrap()
+    }
+}
+
+impl Quantile for MaxwellBoltzmann {
+    /// Evaluates the quantile function of the Maxwell-Boltzmann distribution.
+    ///
+    /// There is no closed form, so the distribution function is inverted by bisection.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        if p <= 0_f64 {
+            return 0_f64;
+        } else if p >= 1_f64 {
+            return f64::INFINITY;
+        }
+
+        let mut low: f64 = 0_f64;
+        let mut high: f64 = self.scale;
+        while self.cdf(high) < p {
+            low = high;
+            high *= 2_f64;
+        }
+
+        for _ in 0_usize..QUANTILE_ITERATIONS {
+            let middle: f64 = 0.5_f64 * (low + high);
+            if self.cdf(middle) < p {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        0.5_f64 * (low + high)
+    }
+}
+
+impl Density for MaxwellBoltzmann {
+    /// Evaluates the density of the Maxwell-Boltzmann distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 2 x² / a³ φ(x / a)    for x >= 0
+    /// ```
+    /// where `φ` is the density of the standard Normal distribution, i.e. `f(x) = sqrt(2/π) x² / a³ exp(-x² / (2 a²))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            return 0_f64;
+        }
+
+        let z: f64 = x / self.scale;
+        2_f64 * z * z * standard_normal_pdf(z) / self.scale
+    }
+
+    /// Evaluates the logarithm of the density of the Maxwell-Boltzmann distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln 2 + 2 ln z + ln φ(z) - ln a
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            return f64::NEG_INFINITY;
+        }
+
+        let z: f64 = x / self.scale;
+        std::f64::consts::LN_2 + 2_f64 * math::ln(z) + ln_standard_normal_pdf(z)
+            - math::ln(self.scale)
+    }
+
+    /// Evaluates the distribution function of the Maxwell-Boltzmann distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 2 Φ(x / a) - 1 - 2 x / a φ(x / a)    for x >= 0
+    /// ```
+    /// where `Φ` and `φ` are the distribution function and the density of the standard Normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0_f64 {
+            return 0_f64;
+        }
+
+        let z: f64 = x / self.scale;
+        (2_f64 * standard_normal_cdf(z) - 1_f64 - 2_f64 * z * standard_normal_pdf(z)).max(0_f64)
+    }
+}
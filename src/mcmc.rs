@@ -0,0 +1,777 @@
+//! This module contains Markov chain Monte Carlo (MCMC) samplers for densities without a direct sampling method.
+//!
+//! A sampler only needs the logarithm of the target density up to an additive constant,
+//! e.g. an unnormalized posterior density, and moves a Markov chain whose stationary distribution is the target.
+//! The values of the chain are therefore correlated and only follow the target after an initial burn-in.
+//!
+//! Every univariate sampler implements `Sampler`, whose `chain` method returns a lazy `Chain` iterator with options for the burn-in and the thinning.
+//! The `GibbsSampler` updates several named variables from their full conditional distributions and records their traces.
+//!
+//! ```
+//! use random::mcmc::{MetropolisHastings, Sampler};
+//! use random::Normal;
+//!
+//! // A standard Normal target with a random walk proposal
+//! let mut sampler: MetropolisHastings =
+//!     MetropolisHastings::new(|x: f64| -0.5 * x * x, Normal::new(0.0, 4.0).unwrap(), 0.0).unwrap();
+//! let samples: Vec<f64> = sampler.chain().burn_in(1_000).thinning(10).take(1_000).collect();
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::auto_seed_methods;
+use crate::density::Density;
+use crate::math;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A trait for Markov chain Monte Carlo samplers.
+pub trait Sampler {
+    /// Moves the Markov chain by one step.
+    ///
+    /// # Returns
+    ///
+    /// The new state of the chain as a `f64`.
+    fn step(&mut self) -> f64;
+
+    /// Returns a lazy iterator over the states of the chain.
+    ///
+    /// By default every step is returned, starting with the state after the first step.
+    ///
+    /// # Returns
+    ///
+    /// An infinite `Chain` iterator, whose burn-in and thinning can be set before iterating.
+    fn chain(&mut self) -> Chain<'_, Self>
+    where
+        Self: Sized,
+    {
+        Chain {
+            sampler: self,
+            burn_in: 0_usize,
+            thinning: 1_usize,
+        }
+    }
+}
+
+/// An infinite iterator over the states of a Markov chain.
+///
+/// It is created by the `chain` method of `Sampler`.
+/// The steps are only taken while iterating, so the iterator is usually limited by `Iterator::take`.
+pub struct Chain<'a, S: Sampler> {
+    /// The sampler moving the chain.
+    sampler: &'a mut S,
+
+    /// The number of steps discarded before the first value.
+    burn_in: usize,
+
+    /// The number of steps per returned value.
+    thinning: usize,
+}
+
+impl<S: Sampler> Chain<'_, S> {
+    /// Sets the number of steps discarded before the first value.
+    ///
+    /// The first states depend on the initial state, which is rarely typical for the target distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - A `usize` representing the number of discarded steps.
+    ///
+    /// # Returns
+    ///
+    /// The `Chain` with the burn-in.
+    pub fn burn_in(mut self, steps: usize) -> Self {
+        self.burn_in = steps;
+        self
+    }
+
+    /// Sets the number of steps per returned value, i.e. only every `k`-th state is returned.
+    ///
+    /// Thinning reduces the correlation of consecutive values and the memory of stored samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - A `usize` representing the number of steps per value. 0 is treated as 1.
+    ///
+    /// # Returns
+    ///
+    /// The `Chain` with the thinning.
+    pub fn thinning(mut self, k: usize) -> Self {
+        self.thinning = k.max(1_usize);
+        self
+    }
+}
+
+impl<S: Sampler> Iterator for Chain<'_, S> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        for _ in 0_usize..self.burn_in {
+            self.sampler.step();
+        }
+        self.burn_in = 0_usize;
+
+        for _ in 1_usize..self.thinning {
+            self.sampler.step();
+        }
+        Some(self.sampler.step())
+    }
+}
+
+/// A struct for sampling from an arbitrary log-density with the Metropolis-Hastings algorithm.
+///
+/// In every step a candidate `y = x + D` is proposed by adding an increment `D` of the proposal distribution with density `g`
+/// to the current state `x`. The candidate becomes the new state with the probability
+/// ```text
+/// min(1, exp(ln π(y) - ln π(x) + ln g(-D) - ln g(D)))
+/// ```
+/// where `ln π` is the logarithm of the target density, otherwise the chain stays at `x`.
+/// For a symmetric proposal, e.g. a Normal distribution with mean 0, the correction `ln g(-D) - ln g(D)` vanishes
+/// and this is the random walk Metropolis algorithm.
+///
+/// The target density does not need to be normalized, as only differences of its logarithm are used.
+/// The scale of the proposal should be of the order of the scale of the target.
+/// An acceptance rate of about a quarter to a half is a good sign, see `MetropolisHastings::acceptance_rate`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `ln_target` - The logarithm of the (unnormalized) target density.
+/// * `proposal` - The distribution of the increments of the candidates.
+/// * `state` - The current state of the chain.
+/// * `ln_density` - The logarithm of the target density at the current state.
+/// * `proposed` - The number of proposed candidates.
+/// * `accepted` - The number of accepted candidates.
+#[derive(Clone)]
+pub struct MetropolisHastings {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The logarithm of the (unnormalized) target density.
+    ln_target: Rc<dyn Fn(f64) -> f64>,
+
+    /// The distribution of the increments.
+    proposal: Rc<dyn Density>,
+
+    /// The current state of the chain.
+    state: f64,
+
+    /// The logarithm of the target density at the current state.
+    ln_density: f64,
+
+    /// The number of proposed candidates.
+    proposed: u64,
+
+    /// The number of accepted candidates.
+    accepted: u64,
+}
+
+auto_seed_methods!(MetropolisHastings);
+
+impl Debug for MetropolisHastings {
+    /// Formats the state of the `MetropolisHastings` sampler, leaving out the target and the proposal.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("MetropolisHastings")
+            .field("rng", &self.rng)
+            .field("state", &self.state)
+            .field("ln_density", &self.ln_density)
+            .field("proposed", &self.proposed)
+            .field("accepted", &self.accepted)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MetropolisHastings {
+    /// Creates a new `MetropolisHastings` instance with a given log-target, proposal distribution and initial state.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ln_target` - A closure representing the logarithm of the (unnormalized) target density, negative infinity outside the support.
+    /// * `proposal` - A distribution implementing `Density`, whose values are added to the state as increments.
+    /// * `initial` - A `f64` representing the initial state. The target density must be positive there.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MetropolisHastings)` - Returns an instance of `MetropolisHastings` if the initial state is valid.
+    /// * `Err(RngError)` - Returns a `NotFiniteError` if the initial state or the log-target at it is not finite.
+    pub fn new<F: Fn(f64) -> f64 + 'static, P: Density + 'static>(
+        ln_target: F,
+        proposal: P,
+        initial: f64,
+    ) -> Result<MetropolisHastings, RngError> {
+        RngError::check_finite(initial)?;
+        let ln_density: f64 = ln_target(initial);
+        RngError::check_finite(ln_density)?;
+
+        Ok(MetropolisHastings {
+            rng: Rng::new(),
+            ln_target: Rc::new(ln_target),
+            proposal: Rc::new(proposal),
+            state: initial,
+            ln_density,
+            proposed: 0_u64,
+            accepted: 0_u64,
+        })
+    }
+
+    /// Returns the current state of the chain.
+    ///
+    /// # Returns
+    ///
+    /// The current state as a `f64`.
+    pub fn state(&self) -> f64 {
+        self.state
+    }
+
+    /// Returns the fraction of the proposed candidates that were accepted.
+    ///
+    /// A rate close to 1 indicates a too small proposal scale and a rate close to 0 a too big one,
+    /// both of which let the chain explore the target slowly.
+    ///
+    /// # Returns
+    ///
+    /// The acceptance rate as a `f64` between 0 and 1. It is NaN before the first step.
+    pub fn acceptance_rate(&self) -> f64 {
+        self.accepted as f64 / self.proposed as f64
+    }
+}
+
+impl Sampler for MetropolisHastings {
+    /// Proposes a candidate and accepts or rejects it.
+    ///
+    /// A candidate with a log-target of NaN or negative infinity is always rejected.
+    ///
+    /// # Returns
+    ///
+    /// The new state of the chain, which is the old one if the candidate was rejected.
+    fn step(&mut self) -> f64 {
+        let increment: f64 = self.proposal.quantile(self.rng.generate());
+        let candidate: f64 = self.state + increment;
+        let ln_candidate: f64 = (self.ln_target)(candidate);
+        let ln_ratio: f64 = ln_candidate - self.ln_density + self.proposal.ln_pdf(-increment)
+            - self.proposal.ln_pdf(increment);
+
+        self.proposed += 1_u64;
+        // 1 - U is in (0, 1], so its logarithm is finite
+        if math::ln(1_f64 - self.rng.generate()) < ln_ratio {
+            self.state = candidate;
+            self.ln_density = ln_candidate;
+            self.accepted += 1_u64;
+        }
+        self.state
+    }
+}
+
+/// A struct for sampling from an arbitrary univariate log-density with Neal's slice sampler.
+///
+/// In every step a height `ln y = ln π(x) + ln U` below the target density at the current state `x` is drawn.
+/// The next state is uniformly distributed on the slice `{x' : ln π(x') > ln y}`:
+/// An interval of the given width is randomly placed around `x` and stepped out by the width until both ends are outside the slice.
+/// Candidates are drawn uniformly from the interval, which is shrunk towards `x` after every rejected candidate.
+///
+/// Unlike `MetropolisHastings`, the sampler has no proposal scale to tune and never stays at the same state.
+/// The width only affects the number of evaluations of the target, as the interval adapts by stepping out and shrinking.
+/// A width of the order of the standard deviation of the target is efficient.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `ln_target` - The logarithm of the (unnormalized) target density.
+/// * `width` - The width of the initial interval and of the steps out.
+/// * `state` - The current state of the chain.
+/// * `ln_density` - The logarithm of the target density at the current state.
+///
+/// # Notes
+///
+/// Every interval is stepped out by at most `SliceSampler::MAX_STEPS_OUT` widths,
+/// so for a multimodal target the width should be big enough to bridge the gaps between the modes.
+#[derive(Clone)]
+pub struct SliceSampler {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The logarithm of the (unnormalized) target density.
+    ln_target: Rc<dyn Fn(f64) -> f64>,
+
+    /// The width of the initial interval and of the steps out.
+    width: f64,
+
+    /// The current state of the chain.
+    state: f64,
+
+    /// The logarithm of the target density at the current state.
+    ln_density: f64,
+}
+
+auto_seed_methods!(SliceSampler);
+
+impl Debug for SliceSampler {
+    /// Formats the state of the `SliceSampler`, leaving out the target.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("SliceSampler")
+            .field("rng", &self.rng)
+            .field("width", &self.width)
+            .field("state", &self.state)
+            .field("ln_density", &self.ln_density)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SliceSampler {
+    /// The maximal number of widths by which an interval is stepped out in one step.
+    pub const MAX_STEPS_OUT: usize = 64_usize;
+
+    /// Creates a new `SliceSampler` instance with a given log-target, width and initial state.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ln_target` - A closure representing the logarithm of the (unnormalized) target density, negative infinity outside the support.
+    /// * `width` - A `f64` representing the width of the initial interval. It must be a positive number.
+    /// * `initial` - A `f64` representing the initial state. The target density must be positive there.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SliceSampler)` - Returns an instance of `SliceSampler` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the width is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `NotFiniteError` if the width, the initial state or the log-target at it is not finite.
+    pub fn new<F: Fn(f64) -> f64 + 'static>(
+        ln_target: F,
+        width: f64,
+        initial: f64,
+    ) -> Result<SliceSampler, RngError> {
+        RngError::check_finite(width)?;
+        RngError::check_positive(width)?;
+        RngError::check_finite(initial)?;
+        let ln_density: f64 = ln_target(initial);
+        RngError::check_finite(ln_density)?;
+
+        Ok(SliceSampler {
+            rng: Rng::new(),
+            ln_target: Rc::new(ln_target),
+            width,
+            state: initial,
+            ln_density,
+        })
+    }
+
+    /// Returns the current state of the chain.
+    ///
+    /// # Returns
+    ///
+    /// The current state as a `f64`.
+    pub fn state(&self) -> f64 {
+        self.state
+    }
+
+    /// Returns whether a point lies on the slice above a given height.
+    fn on_slice(&self, x: f64, ln_height: f64) -> bool {
+        (self.ln_target)(x) > ln_height
+    }
+}
+
+impl Sampler for SliceSampler {
+    /// Draws the next state uniformly from a slice below the target density.
+    ///
+    /// # Returns
+    ///
+    /// The new state of the chain.
+    fn step(&mut self) -> f64 {
+        // U is in [0, 1), so the current state always lies on the slice
+        let ln_height: f64 = self.ln_density + math::ln(self.rng.generate());
+
+        // Step out, splitting the maximal number of steps randomly between both sides
+        let mut left: f64 = self.state - self.width * self.rng.generate();
+        let mut right: f64 = left + self.width;
+        let mut left_steps: usize = self.rng.gen_below(Self::MAX_STEPS_OUT as u64) as usize;
+        let mut right_steps: usize = Self::MAX_STEPS_OUT - 1_usize - left_steps;
+        while left_steps > 0_usize && self.on_slice(left, ln_height) {
+            left -= self.width;
+            left_steps -= 1_usize;
+        }
+        while right_steps > 0_usize && self.on_slice(right, ln_height) {
+            right += self.width;
+            right_steps -= 1_usize;
+        }
+
+        // Shrink towards the current state until a candidate lies on the slice
+        loop {
+            let candidate: f64 = left + self.rng.generate() * (right - left);
+            let ln_candidate: f64 = (self.ln_target)(candidate);
+            // Rounding can shrink the interval to the current state itself
+            if ln_candidate > ln_height || candidate == self.state {
+                self.state = candidate;
+                self.ln_density = ln_candidate;
+                return self.state;
+            }
+            if candidate < self.state {
+                left = candidate;
+            } else {
+                right = candidate;
+            }
+        }
+    }
+}
+
+/// A struct for sampling from an arbitrary univariate log-density with the adaptive Metropolis algorithm of Haario, Saksman and Tamminen.
+///
+/// The candidates are proposed by a Normal random walk like in `MetropolisHastings`, whose scale is learned from the chain itself.
+/// During the first `AdaptiveMetropolis::ADAPTATION_START` steps the initial scale is used,
+/// afterwards the variance of the proposal is
+/// ```text
+/// σ² = 2.4² (s² + ε)
+/// ```
+/// where `s²` is the variance of all previous states of the chain and `ε` a small constant keeping the proposal from collapsing.
+/// The factor `2.4²` is the optimal scaling of a random walk for a Normal target.
+///
+/// Therefore, even a badly chosen initial scale results in an efficient sampler after a burn-in.
+/// The chain is not Markovian, but ergodic for bounded targets, so its values still follow the target distribution.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `ln_target` - The logarithm of the (unnormalized) target density.
+/// * `initial_scale` - The standard deviation of the proposal before the adaptation starts.
+/// * `state` - The current state of the chain.
+/// * `ln_density` - The logarithm of the target density at the current state.
+/// * `steps` - The number of steps taken.
+/// * `mean` - The mean of the states of the chain.
+/// * `squares` - The sum of the squared deviations of the states from their mean.
+/// * `accepted` - The number of accepted candidates.
+#[derive(Clone)]
+pub struct AdaptiveMetropolis {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The logarithm of the (unnormalized) target density.
+    ln_target: Rc<dyn Fn(f64) -> f64>,
+
+    /// The standard deviation of the proposal before the adaptation starts.
+    initial_scale: f64,
+
+    /// The current state of the chain.
+    state: f64,
+
+    /// The logarithm of the target density at the current state.
+    ln_density: f64,
+
+    /// The number of steps taken.
+    steps: u64,
+
+    /// The mean of the states, updated by Welford's algorithm.
+    mean: f64,
+
+    /// The sum of the squared deviations of the states, updated by Welford's algorithm.
+    squares: f64,
+
+    /// The number of accepted candidates.
+    accepted: u64,
+}
+
+auto_seed_methods!(AdaptiveMetropolis);
+
+impl Debug for AdaptiveMetropolis {
+    /// Formats the state of the `AdaptiveMetropolis` sampler, leaving out the target.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("AdaptiveMetropolis")
+            .field("rng", &self.rng)
+            .field("state", &self.state)
+            .field("ln_density", &self.ln_density)
+            .field("steps", &self.steps)
+            .field("proposal_scale", &self.proposal_scale())
+            .field("accepted", &self.accepted)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AdaptiveMetropolis {
+    /// The number of steps with the initial scale, before the proposal is adapted to the chain.
+    pub const ADAPTATION_START: u64 = 100_u64;
+
+    /// The constant `ε` added to the variance of the chain, which keeps the proposal scale positive.
+    pub const EPSILON: f64 = 1e-10_f64;
+
+    /// The scaling `2.4²` of the variance of the chain for the proposal.
+    const SCALING: f64 = 5.76_f64;
+
+    /// Creates a new `AdaptiveMetropolis` instance with a given log-target, initial proposal scale and initial state.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ln_target` - A closure representing the logarithm of the (unnormalized) target density, negative infinity outside the support.
+    /// * `initial_scale` - A `f64` representing the standard deviation of the proposal before the adaptation. It must be a positive number.
+    /// * `initial` - A `f64` representing the initial state. The target density must be positive there.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AdaptiveMetropolis)` - Returns an instance of `AdaptiveMetropolis` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the initial scale is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `NotFiniteError` if the initial scale, the initial state or the log-target at it is not finite.
+    pub fn new<F: Fn(f64) -> f64 + 'static>(
+        ln_target: F,
+        initial_scale: f64,
+        initial: f64,
+    ) -> Result<AdaptiveMetropolis, RngError> {
+        RngError::check_finite(initial_scale)?;
+        RngError::check_positive(initial_scale)?;
+        RngError::check_finite(initial)?;
+        let ln_density: f64 = ln_target(initial);
+        RngError::check_finite(ln_density)?;
+
+        Ok(AdaptiveMetropolis {
+            rng: Rng::new(),
+            ln_target: Rc::new(ln_target),
+            initial_scale,
+            state: initial,
+            ln_density,
+            steps: 0_u64,
+            mean: initial,
+            squares: 0_f64,
+            accepted: 0_u64,
+        })
+    }
+
+    /// Returns the current state of the chain.
+    ///
+    /// # Returns
+    ///
+    /// The current state as a `f64`.
+    pub fn state(&self) -> f64 {
+        self.state
+    }
+
+    /// Returns the standard deviation of the proposal of the next step.
+    ///
+    /// # Returns
+    ///
+    /// The initial scale during the first `ADAPTATION_START` steps, afterwards `2.4 sqrt(s² + ε)`.
+    pub fn proposal_scale(&self) -> f64 {
+        if self.steps < Self::ADAPTATION_START {
+            return self.initial_scale;
+        }
+        // The chain consists of the initial state and one state per step
+        let variance: f64 = self.squares / self.steps as f64;
+        (Self::SCALING * (variance + Self::EPSILON)).sqrt()
+    }
+
+    /// Returns the fraction of the proposed candidates that were accepted.
+    ///
+    /// # Returns
+    ///
+    /// The acceptance rate as a `f64` between 0 and 1. It is NaN before the first step.
+    pub fn acceptance_rate(&self) -> f64 {
+        self.accepted as f64 / self.steps as f64
+    }
+}
+
+impl Sampler for AdaptiveMetropolis {
+    /// Proposes a candidate with the adapted scale, accepts or rejects it and updates the variance of the chain.
+    ///
+    /// A candidate with a log-target of NaN or negative infinity is always rejected.
+    ///
+    /// # Returns
+    ///
+    /// The new state of the chain, which is the old one if the candidate was rejected.
+    fn step(&mut self) -> f64 {
+        let candidate: f64 = self.state + self.proposal_scale() * self.rng.gen_standard_normal();
+        let ln_candidate: f64 = (self.ln_target)(candidate);
+
+        // 1 - U is in (0, 1], so its logarithm is finite
+        if math::ln(1_f64 - self.rng.generate()) < ln_candidate - self.ln_density {
+            self.state = candidate;
+            self.ln_density = ln_candidate;
+            self.accepted += 1_u64;
+        }
+
+        self.steps += 1_u64;
+        let delta: f64 = self.state - self.mean;
+        self.mean += delta / (self.steps + 1_u64) as f64;
+        self.squares += delta * (self.state - self.mean);
+        self.state
+    }
+}
+
+/// The full conditional distribution of a variable of a `GibbsSampler`.
+///
+/// The closure receives the current values of all variables by name and a random number generator,
+/// and draws a new value of its variable, e.g. with `Quantile::sample` of a distribution of this crate.
+pub type FullConditional = Box<dyn Fn(&BTreeMap<String, f64>, &mut Rng) -> f64>;
+
+/// A full conditional shared by clones of a `GibbsSampler`.
+type SharedConditional = Rc<dyn Fn(&BTreeMap<String, f64>, &mut Rng) -> f64>;
+
+/// A struct for sampling from the joint distribution of several named variables with the Gibbs sampler.
+///
+/// Every sweep draws each variable in turn from its full conditional distribution
+/// given the current values of all other variables, in the alphabetical order of the names (systematic scan).
+/// The values of the sweeps form a Markov chain, whose stationary distribution is the joint distribution.
+/// This is enough for small Bayesian models with conjugate conditionals, e.g. a Normal model with unknown mean and precision:
+/// ```text
+/// μ | τ, y ~ Normal
+/// τ | μ, y ~ Gamma
+/// ```
+///
+/// The value of every updated variable after every recorded sweep is appended to its trace.
+/// Values without a conditional are never updated, so they can hold fixed data or hyperparameters of the model.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` passed to the full conditionals.
+/// * `conditionals` - The full conditionals in the order of the updates.
+/// * `values` - The current values of all variables.
+/// * `traces` - The recorded values of the updated variables.
+#[derive(Clone)]
+pub struct GibbsSampler {
+    /// The random number generator passed to the full conditionals.
+    rng: Rng,
+
+    /// The names and full conditionals of the updated variables.
+    conditionals: Vec<(String, SharedConditional)>,
+
+    /// The current values.
+    values: BTreeMap<String, f64>,
+
+    /// The recorded values.
+    traces: BTreeMap<String, Vec<f64>>,
+}
+
+auto_seed_methods!(GibbsSampler);
+
+impl Debug for GibbsSampler {
+    /// Formats the state of the `GibbsSampler`, leaving out the full conditionals and the traces.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("GibbsSampler")
+            .field("rng", &self.rng)
+            .field("values", &self.values)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GibbsSampler {
+    /// Creates a new `GibbsSampler` instance with given full conditionals and initial values.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `conditionals` - A `BTreeMap` from the names of the updated variables to their full conditionals.
+    /// * `initial` - A `BTreeMap` from the names of all variables to their finite initial values, including fixed ones.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GibbsSampler)` - Returns an instance of `GibbsSampler` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no conditionals.
+    /// * `Err(RngError)` - Returns a `MissingError` if an updated variable has no initial value.
+    /// * `Err(RngError)` - Returns a `NotFiniteError` if an initial value is not finite.
+    pub fn new(
+        conditionals: BTreeMap<String, FullConditional>,
+        initial: BTreeMap<String, f64>,
+    ) -> Result<GibbsSampler, RngError> {
+        if conditionals.is_empty() {
+            return Err(RngError::EmptyError);
+        }
+        for &value in initial.values() {
+            RngError::check_finite(value)?;
+        }
+        if conditionals.keys().any(|name| !initial.contains_key(name)) {
+            return Err(RngError::MissingError {
+                parameter: "initial",
+            });
+        }
+
+        Ok(GibbsSampler {
+            rng: Rng::new(),
+            traces: conditionals
+                .keys()
+                .map(|name| (name.clone(), Vec::new()))
+                .collect(),
+            conditionals: conditionals
+                .into_iter()
+                .map(|(name, conditional)| (name, Rc::from(conditional)))
+                .collect(),
+            values: initial,
+        })
+    }
+
+    /// Updates every variable once from its full conditional without recording the values.
+    fn sweep(&mut self) {
+        for (name, conditional) in self.conditionals.iter() {
+            let value: f64 = conditional(&self.values, &mut self.rng);
+            self.values.insert(name.clone(), value);
+        }
+    }
+
+    /// Runs a number of sweeps without recording them, e.g. to forget the initial values.
+    ///
+    /// # Arguments
+    ///
+    /// * `sweeps` - A `usize` representing the number of discarded sweeps.
+    pub fn burn_in(&mut self, sweeps: usize) {
+        for _ in 0_usize..sweeps {
+            self.sweep();
+        }
+    }
+
+    /// Runs a number of sweeps and appends the values of the updated variables after each of them to their traces.
+    ///
+    /// # Arguments
+    ///
+    /// * `sweeps` - A `usize` representing the number of recorded sweeps.
+    pub fn run(&mut self, sweeps: usize) {
+        for _ in 0_usize..sweeps {
+            self.sweep();
+            for (name, trace) in self.traces.iter_mut() {
+                trace.push(self.values[name]);
+            }
+        }
+    }
+
+    /// Returns the current values of all variables.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `BTreeMap` from the names to the current values.
+    pub fn values(&self) -> &BTreeMap<String, f64> {
+        &self.values
+    }
+
+    /// Returns the trace of an updated variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the variable.
+    ///
+    /// # Returns
+    ///
+    /// The values of the variable after every recorded sweep, or `None` if no variable of this name is updated.
+    pub fn trace(&self, name: &str) -> Option<&[f64]> {
+        self.traces.get(name).map(Vec::as_slice)
+    }
+
+    /// Returns the traces of all updated variables.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `BTreeMap` from the names to the values after every recorded sweep.
+    pub fn traces(&self) -> &BTreeMap<String, Vec<f64>> {
+        &self.traces
+    }
+
+    /// Removes all recorded values from the traces.
+    pub fn clear_traces(&mut self) {
+        for trace in self.traces.values_mut() {
+            trace.clear();
+        }
+    }
+}
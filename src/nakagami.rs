@@ -0,0 +1,91 @@
+//! This module contains the implementation of the `Nakagami` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a Nakagami distribution.
+///
+/// The Nakagami distribution with shape `m` and spread `Ω` is the distribution of the square root
+/// of a Gamma distributed random variable with shape `m` and scale `Ω / m`.
+/// In wireless communication it models the amplitude of signals in fading channels, where `Ω = E[X²]` is the mean power.
+/// For `m = 1` it is the Rayleigh distribution and for `m = 1/2` the half-normal distribution.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `m` - The shape (m) of the distribution. Must be at least 1/2.
+/// * `omega` - The spread (Ω) of the distribution. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct Nakagami {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The shape (m) of the distribution.
+    m: f64,
+
+    /// The spread (Ω) of the distribution.
+    omega: f64,
+}
+
+auto_rng_trait!(Nakagami);
+auto_builder!(Nakagami, NakagamiBuilder { m: f64, omega: f64 });
+auto_params!(Nakagami { m: f64, omega: f64 });
+auto_display!(Nakagami { "m" = m, "Ω" = omega });
+
+impl Nakagami {
+    /// Creates a new `Nakagami` instance with a given shape and spread.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - A `f64` representing the shape (m) of the distribution. It must be at least 1/2.
+    /// * `omega` - A `f64` representing the spread (Ω) of the distribution. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Nakagami)` - Returns an instance of `Nakagami` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if `m` is smaller than 1/2 or a `PositiveError` if `omega` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(m: f64, omega: f64) -> Result<Nakagami, RngError> {
+        RngError::check_finite(m)?;
+        RngError::check_finite(omega)?;
+        RngError::check_interval(m, 0.5_f64, f64::INFINITY)?;
+        RngError::check_positive(omega)?;
+
+        Ok(Nakagami {
+            rng: Rng::new(),
+            m,
+            omega,
+        })
+    }
+
+    /// Generates a random value from the Nakagami distribution.
+    ///
+    /// This uses the definition
+    /// ```text
+    /// X = sqrt(Ω / m G)
+    /// ```
+    /// where `G` is Gamma(m, 1) distributed, generated with the method of Marsaglia and Tsang.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the Nakagami distribution.
+    pub fn generate(&mut self) -> f64 {
+        let gamma: f64 = self.rng.gen_standard_gamma(self.m);
+
+        (self.omega / self.m * gamma).sqrt()
+    }
+}
+
+impl Default for Nakagami {
+    /// Creates a `Nakagami` instance with m = 1 and Ω = 1, which is Rayleigh distributed.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Nakagami::new(1_f64, 1_f64).unwrap()
+    }
+}
@@ -1,6 +1,18 @@
 //! This module contains the implementation of the `Normal` struct and its methods.
 
+use std::f64::consts::{E, PI};
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::{
+    ln_standard_normal_pdf, mean, standard_normal_cdf, standard_normal_pdf,
+    standard_normal_quantile, variance,
+};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -16,6 +28,7 @@ use crate::rng_error::RngError;
 /// * `mean` - The mean (μ) of the Normal distribution.
 /// * `variance` - The variance (σ²) of the Normal distribution. Must be a positive number.
 /// * `std` - The standard deviation (σ) of the Normal distribution, pre-computed to optimize performance by avoiding repeated square rooting.
+#[derive(Debug, Clone)]
 pub struct Normal {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -31,6 +44,18 @@ pub struct Normal {
 }
 
 auto_rng_trait!(Normal);
+auto_builder!(
+    Normal,
+    NormalBuilder {
+        mean: f64,
+        variance: f64
+    }
+);
+auto_params!(Normal {
+    mean: f64,
+    variance: f64
+});
+auto_display!(Normal { "μ" = mean, "σ²" = variance });
 
 impl Normal {
     /// Creates a new `Normal` instance with a given mean and variance.
@@ -46,8 +71,10 @@ impl Normal {
     /// # Returns
     ///
     /// * `Ok(Normal)` - Returns an instance of `Normal` if the variance is valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the variance is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the variance is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(mean: f64, variance: f64) -> Result<Normal, RngError> {
+        RngError::check_finite(mean)?;
+        RngError::check_finite(variance)?;
         RngError::check_positive(variance)?;
 
         Ok(Normal {
@@ -86,4 +113,138 @@ impl Normal {
 
         self.std * normal + self.mean
     }
+
+    /// Estimates the parameters of a `Normal` distribution from samples using maximum likelihood.
+    ///
+    /// The estimators are
+    /// ```text
+    /// μ = 1/n Σ x_i
+    /// σ² = 1/n Σ (x_i - μ)²
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Normal)` - Returns the fitted instance of `Normal`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if all samples are equal.
+    pub fn fit(samples: &[f64]) -> Result<Normal, RngError> {
+        RngError::check_empty(samples)?;
+
+        let sample_mean: f64 = mean(samples);
+        Normal::new(sample_mean, variance(samples, sample_mean))
+    }
+}
+
+impl NormalBuilder {
+    /// Sets the standard deviation (σ) of the Normal distribution instead of the variance.
+    ///
+    /// This replaces a variance set before.
+    ///
+    /// # Arguments
+    ///
+    /// * `std_dev` - A `f64` representing the standard deviation (σ). It must be a positive number.
+    pub fn std_dev(self, std_dev: f64) -> Self {
+        // Keeping the sign lets `build` reject a non-positive standard deviation
+        self.variance(std_dev * std_dev.abs())
+    }
+}
+
+impl Default for Normal {
+    /// Creates a standard `Normal` instance with mean 0 and variance 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Normal::standard_normal()
+    }
+}
+
+impl Quantile for Normal {
+    /// Evaluates the quantile function of the Normal distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = μ + σ Φ⁻¹(p)
+    /// ```
+    /// where `Φ⁻¹` is the quantile function of the standard Normal distribution, approximated with the algorithm of Acklam.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.mean + self.std * standard_normal_quantile(p)
+    }
+}
+
+impl Density for Normal {
+    /// Evaluates the density of the Normal distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = φ((x - μ) / σ) / σ
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        standard_normal_pdf((x - self.mean) / self.std) / self.std
+    }
+
+    /// Evaluates the logarithm of the density of the Normal distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln φ((x - μ) / σ) - ln σ
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        ln_standard_normal_pdf((x - self.mean) / self.std) - math::ln(self.std)
+    }
+
+    /// Evaluates the distribution function of the Normal distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = Φ((x - μ) / σ)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        standard_normal_cdf((x - self.mean) / self.std)
+    }
+
+    /// Calculates the differential entropy of the Normal distribution in nats.
+    ///
+    /// ```text
+    /// H = ln(2πe σ²) / 2
+    /// ```
+    fn entropy(&self) -> f64 {
+        0.5_f64 * math::ln(2_f64 * PI * E * self.variance)
+    }
 }
@@ -0,0 +1,103 @@
+//! This module contains the implementation of the `NormalTail` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::math;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from the tail of the standard Normal distribution.
+///
+/// The values are standard normal distributed conditioned on being bigger than the threshold `t`, i.e. `Z | Z > t`.
+/// Generating standard normal values until one exceeds the threshold needs on average `1 / P(Z > t)` tries,
+/// which is about 31,000 for `t = 4` and 3.5 million for `t = 5`.
+/// This struct needs less than 3.3 tries on average for every threshold.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `threshold` - The threshold (t) of the tail.
+#[derive(Debug, Clone)]
+pub struct NormalTail {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The threshold (t) of the tail.
+    threshold: f64,
+}
+
+auto_rng_trait!(NormalTail);
+auto_builder!(NormalTail, NormalTailBuilder { threshold: f64 });
+auto_params!(NormalTail { threshold: f64 });
+auto_display!(NormalTail { "t" = threshold });
+
+impl NormalTail {
+    /// The threshold from which on Marsaglia's tail method is used instead of rejecting standard normal values.
+    const CROSSOVER: f64 = 0.5_f64;
+}
+
+impl NormalTail {
+    /// Creates a new `NormalTail` instance with a given threshold.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - A `f64` representing the threshold (t) of the tail. It must be finite.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(NormalTail)` - Returns an instance of `NormalTail` if the threshold is valid.
+    /// * `Err(RngError)` - Returns a `NotFiniteError` if the threshold is infinite or NaN.
+    pub fn new(threshold: f64) -> Result<NormalTail, RngError> {
+        RngError::check_finite(threshold)?;
+
+        Ok(NormalTail {
+            rng: Rng::new(),
+            threshold,
+        })
+    }
+
+    /// Generates a random value from the tail of the standard Normal distribution.
+    ///
+    /// For thresholds below `CROSSOVER` standard normal values are generated until one exceeds the threshold.
+    /// Otherwise, Marsaglia's tail method is used: The candidate
+    /// ```text
+    /// X = sqrt(t² - 2 ln U)
+    /// ```
+    /// has the density `x exp(-(x² - t²) / 2)` on [t, ∞), which is accepted with the probability `t / X` by checking
+    /// ```text
+    /// V X <= t
+    /// ```
+    /// where `U` and `V` are independently uniformly distributed on [0, 1].
+    /// The candidate is calculated as `t sqrt(1 - 2 ln(U) / t²)`, as `t²` overflows for `t` above about `1.3e154`.
+    /// For large `t` the excess `X - t` is about `-ln(U) / t`, which can be below the spacing of the floating point numbers.
+    /// A candidate rounded to `t` is therefore returned as the smallest `f64` bigger than `t`.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value bigger than the threshold.
+    pub fn generate(&mut self) -> f64 {
+        if self.threshold < Self::CROSSOVER {
+            loop {
+                let z: f64 = self.rng.gen_standard_normal();
+                if z > self.threshold {
+                    return z;
+                }
+            }
+        }
+
+        loop {
+            let u: f64 = self.rng.generate();
+            let v: f64 = self.rng.generate();
+            let ratio: f64 = -2_f64 * math::ln(u) / self.threshold / self.threshold;
+            let x: f64 = self.threshold * (1_f64 + ratio).sqrt();
+
+            if v * x <= self.threshold {
+                return x.max(self.threshold.next_up());
+            }
+        }
+    }
+}
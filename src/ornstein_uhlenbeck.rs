@@ -0,0 +1,154 @@
+//! This module contains the implementation of the `OrnsteinUhlenbeck` struct and its methods.
+
+use crate::auto_seed_methods;
+use crate::math;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating paths of an Ornstein-Uhlenbeck process.
+///
+/// The process solves the stochastic differential equation
+/// ```text
+/// dX = θ (μ - X) dt + σ dW
+/// ```
+/// and reverts to its long-term mean `μ` with speed `θ`.
+/// It is simulated on the grid `0, dt, 2 dt, ...` using the exact discretization
+/// ```text
+/// X(t + dt) = μ + (X(t) - μ) exp(-θ dt) + σ sqrt((1 - exp(-2 θ dt)) / (2 θ)) Z
+/// ```
+/// where `Z` is standard normal distributed.
+/// Therefore, there is no discretization error, regardless of the size of `dt`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `theta` - The speed of mean reversion (θ). Must be a positive number.
+/// * `mu` - The long-term mean (μ).
+/// * `sigma` - The volatility (σ). Must be a positive number.
+/// * `dt` - The time step. Must be a positive number.
+/// * `decay` - The factor `exp(-θ dt)`, pre-computed to optimize performance.
+/// * `std_step` - The standard deviation of a step, pre-computed to optimize performance.
+#[derive(Debug, Clone)]
+pub struct OrnsteinUhlenbeck {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The speed of mean reversion (θ).
+    theta: f64,
+
+    /// The long-term mean (μ).
+    mu: f64,
+
+    /// The volatility (σ).
+    sigma: f64,
+
+    /// The time step.
+    dt: f64,
+
+    /// The factor `exp(-θ dt)`.
+    decay: f64,
+
+    /// The standard deviation of a step.
+    std_step: f64,
+}
+
+auto_seed_methods!(OrnsteinUhlenbeck);
+
+impl OrnsteinUhlenbeck {
+    /// Creates a new `OrnsteinUhlenbeck` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `theta` - A `f64` representing the speed of mean reversion (θ). It must be a positive number.
+    /// * `mu` - A `f64` representing the long-term mean (μ).
+    /// * `sigma` - A `f64` representing the volatility (σ). It must be a positive number.
+    /// * `dt` - A `f64` representing the time step. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(OrnsteinUhlenbeck)` - Returns an instance of `OrnsteinUhlenbeck` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `theta`, `sigma` or `dt` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(theta: f64, mu: f64, sigma: f64, dt: f64) -> Result<OrnsteinUhlenbeck, RngError> {
+        RngError::check_finite(theta)?;
+        RngError::check_finite(mu)?;
+        RngError::check_finite(sigma)?;
+        RngError::check_finite(dt)?;
+        RngError::check_positive(theta)?;
+        RngError::check_positive(sigma)?;
+        RngError::check_positive(dt)?;
+
+        let decay: f64 = math::exp(-theta * dt);
+        let std_step: f64 = sigma * ((1_f64 - decay * decay) / (2_f64 * theta)).sqrt();
+
+        Ok(OrnsteinUhlenbeck {
+            rng: Rng::new(),
+            theta,
+            mu,
+            sigma,
+            dt,
+            decay,
+            std_step,
+        })
+    }
+
+    /// Generates the value of the process one time step after a given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A `f64` representing the current value of the process.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the value of the process after one time step.
+    pub fn step(&mut self, value: f64) -> f64 {
+        self.mu + (value - self.mu) * self.decay + self.std_step * self.rng.gen_standard_normal()
+    }
+
+    /// Generates a path of the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - A `f64` representing the initial value of the process.
+    /// * `n_steps` - A `usize` representing the number of time steps.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `n_steps + 1` containing the values at the times `0, dt, ..., n_steps dt`, starting with `start`.
+    pub fn path(&mut self, start: f64, n_steps: usize) -> Vec<f64> {
+        let mut path: Vec<f64> = Vec::with_capacity(n_steps + 1_usize);
+        path.push(start);
+        path.extend(self.iter(start).take(n_steps));
+        path
+    }
+
+    /// Returns an infinite iterator over the values of the process at the times `dt, 2 dt, ...`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - A `f64` representing the initial value of the process.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding the values of the process after each time step.
+    pub fn iter(&mut self, start: f64) -> impl Iterator<Item = f64> + '_ {
+        let mut value: f64 = start;
+
+        std::iter::from_fn(move || {
+            value = self.step(value);
+            Some(value)
+        })
+    }
+
+    /// Returns the standard deviation of the stationary distribution.
+    ///
+    /// The stationary distribution of the process is Normal with mean `μ` and variance `σ² / (2 θ)`.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the stationary standard deviation `σ / sqrt(2 θ)`.
+    pub fn stationary_std(&self) -> f64 {
+        self.sigma / (2_f64 * self.theta).sqrt()
+    }
+}
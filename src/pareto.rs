@@ -1,6 +1,12 @@
 //! This module contains the implementation of the `Pareto` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -17,6 +23,7 @@ use crate::rng_error::RngError;
 /// * `shape` - The shape (α) of the Pareto distribution. Must be a positive number.
 /// * `inverse_shape` - The inverse of the `shape` value, pre-computed to optimize performance by avoiding repeated division.
 
+#[derive(Debug, Clone)]
 pub struct Pareto {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -33,6 +40,18 @@ pub struct Pareto {
 }
 
 auto_rng_trait!(Pareto);
+auto_builder!(
+    Pareto,
+    ParetoBuilder {
+        scale: f64,
+        shape: f64
+    }
+);
+auto_params!(Pareto {
+    scale: f64,
+    shape: f64
+});
+auto_display!(Pareto { "x_m" = scale, "α" = shape });
 
 impl Pareto {
     /// Creates a new `Pareto` instance with a given scale and shape.
@@ -49,8 +68,10 @@ impl Pareto {
     /// # Returns
     ///
     /// * `Ok(Pareto)` - Returns an instance of `Pareto` if the scale and shape are valid.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the scale or shape are less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the scale or shape are less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(scale: f64, shape: f64) -> Result<Pareto, RngError> {
+        RngError::check_finite(scale)?;
+        RngError::check_finite(shape)?;
         RngError::check_positive(scale)?;
         RngError::check_positive(shape)?;
 
@@ -74,6 +95,111 @@ impl Pareto {
     pub fn generate(&mut self) -> f64 {
         let uni: f64 = self.rng.generate();
 
-        self.scale / uni.powf(self.inverse_shape)
+        self.scale / math::powf(uni, self.inverse_shape)
+    }
+
+    /// Estimates the parameters of a `Pareto` distribution from samples using maximum likelihood.
+    ///
+    /// The estimators are
+    /// ```text
+    /// x_m = min x_i
+    /// α = n / Σ ln(x_i / x_m)
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of positive `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Pareto)` - Returns the fitted instance of `Pareto`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if a sample is not positive or all samples are equal.
+    pub fn fit(samples: &[f64]) -> Result<Pareto, RngError> {
+        RngError::check_empty(samples)?;
+
+        let scale: f64 = samples.iter().copied().fold(f64::INFINITY, f64::min);
+        RngError::check_positive(scale)?;
+
+        let log_sum: f64 = samples.iter().map(|x| math::ln(x / scale)).sum();
+        RngError::check_positive(log_sum)?;
+
+        Pareto::new(scale, samples.len() as f64 / log_sum)
+    }
+}
+
+impl Default for Pareto {
+    /// Creates a standard `Pareto` instance with scale 1 and shape 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Pareto::new(1_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for Pareto {
+    /// Evaluates the quantile function of the Pareto distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = scale / (1 - p)^(1 / shape)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.scale / math::powf(1_f64 - p, self.inverse_shape)
+    }
+}
+
+impl Density for Pareto {
+    /// Evaluates the density of the Pareto distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = α x_m^α / x^(α + 1)    for x >= x_m
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.scale {
+            0_f64
+        } else {
+            self.shape / x * math::powf(self.scale / x, self.shape)
+        }
+    }
+
+    /// Evaluates the distribution function of the Pareto distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 1 - (x_m / x)^α    for x >= x_m
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x < self.scale {
+            0_f64
+        } else {
+            1_f64 - math::powf(self.scale / x, self.shape)
+        }
     }
 }
@@ -0,0 +1,240 @@
+//! This module contains the implementation of the `Pert` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::auxiliary::fit_three_point;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+use crate::special::{beta_quantile, ln_gamma, regularized_incomplete_beta};
+
+/// A struct for generating random variables from a PERT distribution.
+///
+/// The PERT distribution is a Beta distribution rescaled to the interval [a, b] with the mode `c`.
+/// It is a smoother alternative to the Triangle distribution, commonly used for three-point estimates in risk analysis.
+/// The shape parameters of the Beta distribution are
+/// ```text
+/// α = 1 + 4 (c - a) / (b - a)
+/// β = 1 + 4 (b - c) / (b - a)
+/// ```
+/// so that the mean is `(a + 4 c + b) / 6`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `a` - The minimum of the PERT distribution.
+/// * `b` - The maximum of the PERT distribution. Must be bigger than a.
+/// * `c` - The mode of the PERT distribution. Must be between a and b.
+/// * `alpha` - The alpha (α) of the underlying Beta distribution, pre-computed to optimize performance.
+/// * `beta` - The beta (β) of the underlying Beta distribution, pre-computed to optimize performance.
+#[derive(Debug, Clone)]
+pub struct Pert {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The minimum of the distribution.
+    a: f64,
+
+    /// The maximum of the distribution.
+    b: f64,
+
+    /// The mode of the distribution.
+    c: f64,
+
+    /// The alpha (α) of the underlying Beta distribution.
+    alpha: f64,
+
+    /// The beta (β) of the underlying Beta distribution.
+    beta: f64,
+}
+
+auto_rng_trait!(Pert);
+auto_builder!(
+    Pert,
+    PertBuilder {
+        a: f64,
+        b: f64,
+        c: f64
+    }
+);
+auto_params!(Pert {
+    a: f64,
+    b: f64,
+    c: f64
+});
+auto_display!(Pert { "a" = a, "b" = b, "c" = c });
+
+impl Pert {
+    /// Creates a new `Pert` instance with a given minimum, maximum and mode.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A `f64` representing the minimum of the PERT distribution.
+    /// * `b` - A `f64` representing the maximum of the PERT distribution. It must be bigger than a.
+    /// * `c` - A `f64` representing the mode of the PERT distribution. It must be between a and b.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Pert)` - Returns an instance of `Pert` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `OrderError` or `IntervalError` if the parameters are invalid. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(a: f64, b: f64, c: f64) -> Result<Self, RngError> {
+        RngError::check_finite(a)?;
+        RngError::check_finite(b)?;
+        RngError::check_finite(c)?;
+        RngError::check_order(a, b)?;
+        RngError::check_interval(c, a, b)?;
+
+        Ok(Pert {
+            rng: Rng::new(),
+            a,
+            b,
+            c,
+            alpha: 1_f64 + 4_f64 * (c - a) / (b - a),
+            beta: 1_f64 + 4_f64 * (b - c) / (b - a),
+        })
+    }
+
+    /// Creates a new `Pert` instance from a three-point estimate.
+    ///
+    /// The parameters are chosen such that the 10 %, 50 % and 90 % quantiles of the distribution match the estimates exactly.
+    /// The relative position of the mode `(c - a) / (b - a)` is found by bisection,
+    /// then `a` and `b` follow from the P10 and P90 values.
+    ///
+    /// This is only possible if the estimate is not too skewed, i.e.
+    /// ```text
+    /// 0.31 <= (P50 - P10) / (P90 - P10) <= 0.69
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `p10` - A `f64` representing the 10 % quantile.
+    /// * `p50` - A `f64` representing the median. It must be bigger than `p10`.
+    /// * `p90` - A `f64` representing the 90 % quantile. It must be bigger than `p50`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Pert)` - Returns an instance of `Pert` if the estimates are valid.
+    /// * `Err(RngError)` - Returns an `OrderError` if the estimates are not increasing or an `IntervalError` if they are too skewed.
+    pub fn from_p10_p50_p90(p10: f64, p50: f64, p90: f64) -> Result<Self, RngError> {
+        let (a, width, mode) = fit_three_point(p10, p50, p90, |p, mode| {
+            beta_quantile(p, 1_f64 + 4_f64 * mode, 5_f64 - 4_f64 * mode)
+        })?;
+
+        Pert::new(a, a + width, a + mode * width)
+    }
+
+    /// Generates a random value from the PERT distribution.
+    ///
+    /// This uses the fact that
+    /// ```text
+    /// Beta(α, β) = X / (X + Y)
+    /// ```
+    /// where `X ~ Gamma(α, 1)` and `Y ~ Gamma(β, 1)` are independent.
+    /// The result is rescaled from [0, 1] to [a, b].
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the PERT distribution.
+    pub fn generate(&mut self) -> f64 {
+        let x: f64 = self.rng.gen_standard_gamma(self.alpha);
+        let y: f64 = self.rng.gen_standard_gamma(self.beta);
+
+        self.a + (self.b - self.a) * x / (x + y)
+    }
+
+    /// Returns the mode of the PERT distribution.
+    ///
+    /// # Returns
+    ///
+    /// The mode `c` as a `f64`.
+    pub fn mode(&self) -> f64 {
+        self.c
+    }
+}
+
+impl Default for Pert {
+    /// Creates a symmetric `Pert` instance on [0, 1] with the mode 0.5.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Pert::new(0_f64, 1_f64, 0.5_f64).unwrap()
+    }
+}
+
+impl Quantile for Pert {
+    /// Evaluates the quantile function of the PERT distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = a + (b - a) I⁻¹(p; α, β)
+    /// ```
+    /// where `I⁻¹` is the inverse of the regularized incomplete beta function, calculated by bisection.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.a + (self.b - self.a) * beta_quantile(p, self.alpha, self.beta)
+    }
+}
+
+impl Density for Pert {
+    /// Evaluates the density of the PERT distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = t^(α - 1) (1 - t)^(β - 1) / (B(α, β) (b - a))    with t = (x - a) / (b - a)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            return 0_f64;
+        }
+
+        let t: f64 = (x - self.a) / (self.b - self.a);
+        let ln_beta: f64 =
+            ln_gamma(self.alpha) + ln_gamma(self.beta) - ln_gamma(self.alpha + self.beta);
+        math::powf(t, self.alpha - 1_f64)
+            * math::powf(1_f64 - t, self.beta - 1_f64)
+            * math::exp(-ln_beta)
+            / (self.b - self.a)
+    }
+
+    /// Evaluates the distribution function of the PERT distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = I_t(α, β)    with t = (x - a) / (b - a)
+    /// ```
+    /// where `I` is the regularized incomplete beta function.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        regularized_incomplete_beta((x - self.a) / (self.b - self.a), self.alpha, self.beta)
+    }
+}
@@ -1,8 +1,36 @@
 //! This module contains the implementation of the `Poisson` struct and its methods.
 
+use std::ops::RangeInclusive;
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::mean;
+use crate::discrete_distribution::DiscreteDistribution;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
+use crate::special::ln_gamma;
+use crate::tie_breaking::TieBreaking;
+
+/// The algorithms available to generate values of a `Poisson` distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoissonAlgorithm {
+    /// Chooses the fastest algorithm for the rate: `Inversion` below `Poisson::CROSSOVER`, `Ptrs` above.
+    Auto,
+
+    /// Inverse transform sampling by sequential search. It takes `O(λ)` time.
+    Inversion,
+
+    /// Hörmann's transformed rejection with squeeze (PTRS). It takes `O(1)` time, but is only valid for `λ >= 10`.
+    Ptrs,
+
+    /// A binary search in a cached cumulative distribution function. It takes `O(log λ)` time,
+    /// but the table of about `λ + 10 sqrt(λ)` entries is built on the first draw after the rate changed.
+    Table,
+}
 
 /// A struct for generating random variables from a Poisson distribution.
 ///
@@ -14,6 +42,10 @@ use crate::rng_error::RngError;
 ///
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `rate` - The rate (λ) of the Poisson distribution.
+/// * `algorithm` - The algorithm used to generate values.
+/// * `cdf` - The cumulative distribution function cached by `PoissonAlgorithm::Table`.
+/// * `tie_breaking` - The rule for a uniform number on a step of the cumulative distribution function.
+#[derive(Debug, Clone)]
 pub struct Poisson {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -23,9 +55,28 @@ pub struct Poisson {
 
     /// The value of `exp(- lambda)`, pre-computed to optimize performance by avoiding repeated exponentiation.
     exp: f64,
+
+    /// The algorithm used to generate values.
+    algorithm: PoissonAlgorithm,
+
+    /// The cumulative distribution function used by `PoissonAlgorithm::Table`.
+    ///
+    /// It is empty until the first value is generated with the table, so the other algorithms do not pay for it.
+    cdf: Vec<f64>,
+
+    /// The rule for a uniform number on a step of the cumulative distribution function.
+    tie_breaking: TieBreaking,
 }
 
 auto_rng_trait!(Poisson);
+auto_builder!(Poisson, PoissonBuilder { rate: f64 });
+auto_params!(Poisson { rate: f64 } keep algorithm, tie_breaking);
+auto_display!(Poisson { "λ" = rate });
+
+impl Poisson {
+    /// The rate from which on `PoissonAlgorithm::Auto` uses `Ptrs` instead of `Inversion`.
+    pub const CROSSOVER: f64 = 10_f64;
+}
 
 impl Poisson {
     /// Creates a new `Poisson` instance with a given alpha and Poisson.
@@ -39,39 +90,366 @@ impl Poisson {
     /// # Returns
     ///
     /// * `Ok(Poisson)` - Returns an instance of `Poisson` if the rate is positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the rate is negative.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the rate is negative. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(rate: f64) -> Result<Self, RngError> {
+        RngError::check_finite(rate)?;
         RngError::check_positive(rate)?;
 
-        let exp: f64 = (-rate).exp();
+        let exp: f64 = math::exp(-rate);
 
         Ok(Poisson {
             rng: Rng::new(),
             rate,
             exp,
+            algorithm: PoissonAlgorithm::Auto,
+            cdf: Vec::new(),
+            tie_breaking: TieBreaking::Upper,
         })
     }
 
+    /// Sets the algorithm used to generate values.
+    ///
+    /// By default `PoissonAlgorithm::Auto` chooses the algorithm by the rate.
+    /// Overriding it is useful to benchmark the algorithms or to compare their results.
+    /// `PoissonAlgorithm::Ptrs` falls back to `Inversion` for rates below 10, where it is not valid.
+    /// `PoissonAlgorithm::Table` pays off if many values are generated with a fixed rate, e.g. in agent-based simulations.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - The `PoissonAlgorithm` to use.
+    pub fn set_algorithm(&mut self, algorithm: PoissonAlgorithm) {
+        self.algorithm = algorithm;
+    }
+
+    /// Returns the algorithm used to generate values.
+    ///
+    /// # Returns
+    ///
+    /// The `PoissonAlgorithm` that is used for the rate, which is never `Auto`.
+    pub fn algorithm(&self) -> PoissonAlgorithm {
+        match self.algorithm {
+            PoissonAlgorithm::Inversion => PoissonAlgorithm::Inversion,
+            PoissonAlgorithm::Table => PoissonAlgorithm::Table,
+            _ if self.rate < Self::CROSSOVER => PoissonAlgorithm::Inversion,
+            _ => PoissonAlgorithm::Ptrs,
+        }
+    }
+
+    /// Sets the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// By default `TieBreaking::Upper` generates the next value, see `TieBreaking` for the bias of both rules.
+    /// It applies to `PoissonAlgorithm::Inversion` and `Table`, as `Ptrs` does not invert the cumulative distribution function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tie_breaking` - The `TieBreaking` rule to use.
+    pub fn set_tie_breaking(&mut self, tie_breaking: TieBreaking) {
+        self.tie_breaking = tie_breaking;
+    }
+
+    /// Returns the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// # Returns
+    ///
+    /// The `TieBreaking` rule in use.
+    pub fn tie_breaking(&self) -> TieBreaking {
+        self.tie_breaking
+    }
+
     /// Generates a random value from the Poisson distribution.
     ///
-    /// This uses Knuth's algorithm.
+    /// The algorithm is chosen by `Poisson::algorithm`.
     ///
     /// # Returns
     ///
-    /// A `f64` value generated from the Poisson distribution.
+    /// A `i32` value generated from the Poisson distribution.
     pub fn generate(&mut self) -> i32 {
+        match self.algorithm() {
+            PoissonAlgorithm::Ptrs => self.generate_ptrs(),
+            PoissonAlgorithm::Table => self.generate_table(),
+            _ => self.generate_inversion(),
+        }
+    }
+
+    /// Generates a random value using the cached cumulative distribution function as a lookup table.
+    ///
+    /// The value is the smallest `k` with `P(X <= k) > U`, or `P(X <= k) >= U` with `TieBreaking::Lower`,
+    /// which is found by a binary search.
+    /// The table ends where the remaining tail probability is below the machine precision,
+    /// and the rare values beyond it are found by the same summation as `Quantile::quantile`.
+    fn generate_table(&mut self) -> i32 {
+        if self.cdf.is_empty() {
+            self.cdf = Self::get_cdf(self.rate);
+        }
+
+        let uniform: f64 = self.rng.generate();
+        let k: usize = self
+            .cdf
+            .partition_point(|&cdf| self.tie_breaking.precedes(cdf, uniform));
+        if k < self.cdf.len() {
+            k as i32
+        } else {
+            self.search(uniform, self.tie_breaking) as i32
+        }
+    }
+
+    /// Computes the cumulative distribution function of the Poisson distribution up to the machine precision.
+    ///
+    /// The probabilities are calculated recursively in log-space like in `Quantile::quantile`,
+    /// so large rates do not underflow.
+    ///
+    /// # Parameters
+    ///
+    /// * `rate` - The rate (λ).
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the cumulative probabilities `P(X <= k)` for `k = 0, 1, ...`.
+    fn get_cdf(rate: f64) -> Vec<f64> {
+        let ln_rate: f64 = math::ln(rate);
+        let mut ln_probability: f64 = -rate;
+        let mut sum: f64 = math::exp(ln_probability);
+        let mut cdf: Vec<f64> = vec![sum];
+
+        let mut k: f64 = 0_f64;
+        while sum < 1_f64 - f64::EPSILON {
+            k += 1_f64;
+            ln_probability += ln_rate - math::ln(k);
+            let probability: f64 = math::exp(ln_probability);
+
+            // Beyond the mode the sum can not grow anymore due to rounding
+            if probability == 0_f64 && k > rate {
+                break;
+            }
+            sum += probability;
+            cdf.push(sum);
+        }
+        cdf
+    }
+
+    /// Finds the value of a probability by summing up the probabilities of the Poisson distribution.
+    ///
+    /// The probabilities are calculated recursively in log-space, so that large rates do not underflow.
+    ///
+    /// # Parameters
+    ///
+    /// * `p` - A probability below 1.
+    /// * `tie_breaking` - The rule if `p` is on a step of the cumulative distribution function.
+    ///
+    /// # Returns
+    ///
+    /// The smallest `k` with `P(X <= k) > p`, or `P(X <= k) >= p` with `TieBreaking::Lower`.
+    fn search(&self, p: f64, tie_breaking: TieBreaking) -> f64 {
+        let ln_rate: f64 = math::ln(self.rate);
+        let mut ln_probability: f64 = -self.rate;
+        let mut cdf: f64 = math::exp(ln_probability);
+        let mut k: f64 = 0_f64;
+
+        while tie_breaking.precedes(cdf, p) {
+            k += 1_f64;
+            ln_probability += ln_rate - math::ln(k);
+            let probability: f64 = math::exp(ln_probability);
+
+            // Beyond the mode the sum can not grow anymore due to rounding
+            if probability == 0_f64 && k > self.rate {
+                break;
+            }
+            cdf += probability;
+        }
+        k
+    }
+
+    /// Generates a random value by inverse transform sampling.
+    ///
+    /// Starting at 0, the probabilities are summed up until they exceed a uniformly distributed number,
+    /// or reach it with `TieBreaking::Lower`.
+    /// For rates where `exp(-λ)` underflows, the summation is done in log-space like in `Quantile::quantile`.
+    fn generate_inversion(&mut self) -> i32 {
+        let mut uni: f64 = self.rng.generate();
+        if self.exp == 0_f64 {
+            return self.search(uni, self.tie_breaking) as i32;
+        }
+
         let mut k: i32 = 0_i32;
-        let mut p: f64 = 1_f64;
+        let mut probability: f64 = self.exp;
+        while self.tie_breaking.precedes(probability, uni) {
+            uni -= probability;
+            k += 1_i32;
+            probability *= self.rate / k as f64;
+
+            // Rounding can leave a remainder beyond the mode
+            if probability == 0_f64 {
+                break;
+            }
+        }
+        k
+    }
+
+    /// Generates a random value with Hörmann's transformed rejection with squeeze (PTRS).
+    ///
+    /// A candidate is generated from a transformed uniform variable, whose density is a hat of the Poisson probabilities:
+    /// ```text
+    /// k = floor((2 a / (0.5 - |U|) + b) U + λ + 0.43)
+    /// ```
+    /// with `U` uniformly distributed on [-0.5, 0.5].
+    /// Most candidates are accepted by a cheap squeeze, the rest by comparing with the exact probability.
+    fn generate_ptrs(&mut self) -> i32 {
+        let sqrt_rate: f64 = self.rate.sqrt();
+        let ln_rate: f64 = math::ln(self.rate);
+        let b: f64 = 0.931_f64 + 2.53_f64 * sqrt_rate;
+        let a: f64 = -0.059_f64 + 0.02483_f64 * b;
+        let inverse_alpha: f64 = 1.1239_f64 + 1.1328_f64 / (b - 3.4_f64);
+        let squeeze: f64 = 0.9277_f64 - 3.6224_f64 / (b - 2_f64);
 
         loop {
-            let uni: f64 = self.rng.generate();
+            let u: f64 = self.rng.generate() - 0.5_f64;
+            let v: f64 = self.rng.generate();
+            let distance: f64 = 0.5_f64 - u.abs();
+            let k: f64 = ((2_f64 * a / distance + b) * u + self.rate + 0.43_f64).floor();
 
-            k += 1_i32;
-            p *= uni;
+            if distance >= 0.07_f64 && v <= squeeze {
+                return k as i32;
+            }
+            if k < 0_f64 || (distance < 0.013_f64 && v > distance) {
+                continue;
+            }
 
-            if p <= self.exp {
-                return k - 1_i32;
+            let ln_hat: f64 =
+                math::ln(v) + math::ln(inverse_alpha) - math::ln(a / (distance * distance) + b);
+            if ln_hat <= -self.rate + k * ln_rate - ln_gamma(k + 1_f64) {
+                return k as i32;
             }
         }
     }
+
+    /// Creates the distribution of the number of events kept when every event is kept independently with a given probability.
+    ///
+    /// By the thinning identity the kept events are Poisson distributed with the rate `p λ`.
+    ///
+    /// This method initializes the underlying random number generator of the new instance using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` representing the probability of keeping an event. It must be a number in (0, 1].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Poisson)` - Returns the thinned instance of `Poisson`.
+    /// * `Err(RngError)` - Returns a `ProbabilityError` if `p` is not a probability or a `PositiveError` if `p` is 0.
+    pub fn thin(&self, p: f64) -> Result<Poisson, RngError> {
+        RngError::check_probability(p)?;
+
+        Poisson::new(p * self.rate)
+    }
+
+    /// Creates the distribution of the total number of events of independent Poisson distributed sources.
+    ///
+    /// By the superposition identity the sum is Poisson distributed with the rate `λ₁ + ... + λₙ`.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rates` - A slice of `f64` representing the rates of the sources. They must be positive numbers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Poisson)` - Returns the superposed instance of `Poisson`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no rates or a `PositiveError` if a rate is less than or equal to 0.
+    pub fn superpose(rates: &[f64]) -> Result<Poisson, RngError> {
+        RngError::check_empty(rates)?;
+        for &rate in rates {
+            RngError::check_positive(rate)?;
+        }
+
+        Poisson::new(rates.iter().sum())
+    }
+
+    /// Estimates the rate of a `Poisson` distribution from samples using maximum likelihood.
+    ///
+    /// The estimator is the sample mean
+    /// ```text
+    /// λ = 1/n Σ x_i
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of non-negative `f64` values the distribution is fitted to. Usually these are counts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Poisson)` - Returns the fitted instance of `Poisson`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty, a `NonNegativeError` if a sample is negative or a `PositiveError` if all samples are 0.
+    pub fn fit(samples: &[f64]) -> Result<Poisson, RngError> {
+        RngError::check_empty(samples)?;
+        for &sample in samples {
+            RngError::check_non_negative(sample)?;
+        }
+
+        Poisson::new(mean(samples))
+    }
+}
+
+impl Default for Poisson {
+    /// Creates a `Poisson` instance with rate 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Poisson::new(1_f64).unwrap()
+    }
+}
+
+impl Quantile for Poisson {
+    /// Evaluates the quantile function of the Poisson distribution.
+    ///
+    /// The quantile is the smallest `k` with `P(X <= k) >= p`.
+    /// It is found by summing up the probabilities
+    /// ```text
+    /// P(X = k) = λᵏ exp(-λ) / k!
+    /// ```
+    /// which are calculated recursively in log-space, so that large rates do not underflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution. For `p = 1` this is infinity.
+    fn quantile(&self, p: f64) -> f64 {
+        if p >= 1_f64 {
+            return f64::INFINITY;
+        }
+
+        self.search(p, TieBreaking::Lower)
+    }
+}
+
+impl DiscreteDistribution for Poisson {
+    /// Evaluates the probability mass function of the Poisson distribution.
+    ///
+    /// It is calculated in log-space with `ln_pmf`, so that large rates do not overflow.
+    fn pmf(&self, k: i32) -> f64 {
+        math::exp(self.ln_pmf(k))
+    }
+
+    /// Evaluates the logarithm of the probability mass function of the Poisson distribution.
+    ///
+    /// ```text
+    /// ln P(X = k) = k ln λ - λ - ln Γ(k + 1)
+    /// ```
+    fn ln_pmf(&self, k: i32) -> f64 {
+        if k < 0_i32 {
+            return f64::NEG_INFINITY;
+        }
+        let k: f64 = k as f64;
+        k * math::ln(self.rate) - self.rate - ln_gamma(k + 1_f64)
+    }
+
+    /// Returns the unbounded support `0, 1, 2, ...` of the Poisson distribution.
+    fn support(&self) -> RangeInclusive<i32> {
+        0_i32..=i32::MAX
+    }
 }
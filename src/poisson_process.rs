@@ -0,0 +1,292 @@
+//! This module contains the implementation of the `PoissonProcess` and `NonHomogeneousPoissonProcess` structs and their methods.
+
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::math;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+use crate::{auto_rng_trait, auto_seed_methods};
+
+/// A struct for generating the events of a homogeneous Poisson process.
+///
+/// The inter-arrival gaps of a Poisson process with rate `λ` are independently Exponential(λ) distributed.
+/// The `generate` method generates one such gap.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `rate` - The rate (λ) of the process. Must be a positive number.
+/// * `inverse_rate` - The inverse of the `rate` value, pre-computed to optimize performance by avoiding repeated division.
+/// * `horizon` - The optional time horizon after which no more events are generated. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct PoissonProcess {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The rate (λ) of the process.
+    rate: f64,
+
+    /// The inverse of the rate.
+    inverse_rate: f64,
+
+    /// The optional time horizon.
+    horizon: Option<f64>,
+}
+
+auto_rng_trait!(PoissonProcess);
+
+impl PoissonProcess {
+    /// Creates a new `PoissonProcess` instance with a given rate and optional time horizon.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - A `f64` representing the rate (λ) of the process. It must be a positive number.
+    /// * `horizon` - An `Option<f64>` representing the time horizon. If it is `Some`, it must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PoissonProcess)` - Returns an instance of `PoissonProcess` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `rate` or the `horizon` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(rate: f64, horizon: Option<f64>) -> Result<PoissonProcess, RngError> {
+        RngError::check_finite(rate)?;
+        RngError::check_positive(rate)?;
+        if let Some(horizon) = horizon {
+            RngError::check_finite(horizon)?;
+            RngError::check_positive(horizon)?;
+        }
+
+        Ok(PoissonProcess {
+            rng: Rng::new(),
+            rate,
+            inverse_rate: 1_f64 / rate,
+            horizon,
+        })
+    }
+
+    /// Generates a random inter-arrival gap.
+    ///
+    /// This method generates a random variate according to the Exponential distribution using the formula:
+    /// ```text
+    /// X = -ln(U) / rate
+    /// ```
+    /// where `U` is a uniformly distributed random variable between [0, 1].
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the time between two consecutive events.
+    pub fn generate(&mut self) -> f64 {
+        -math::ln(self.rng.generate()) * self.inverse_rate
+    }
+
+    /// Generates sorted arrival times starting at time 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` representing the maximal number of arrivals.
+    ///
+    /// # Returns
+    ///
+    /// A sorted `Vec<f64>` of at most `number` arrival times.
+    /// If there is a time horizon, the arrivals stop at the horizon and the `Vec` may be shorter.
+    pub fn arrival_times(&mut self, number: usize) -> Vec<f64> {
+        let mut time: f64 = 0_f64;
+
+        self.gaps()
+            .take(number)
+            .map(|gap| {
+                time += gap;
+                time
+            })
+            .collect()
+    }
+
+    /// Creates the process of the events kept when every event is kept independently with a given probability.
+    ///
+    /// By the thinning identity the kept events form a Poisson process with the rate `p λ` and the same time horizon.
+    ///
+    /// This method initializes the underlying random number generator of the new instance using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` representing the probability of keeping an event. It must be a number in (0, 1].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PoissonProcess)` - Returns the thinned instance of `PoissonProcess`.
+    /// * `Err(RngError)` - Returns a `ProbabilityError` if `p` is not a probability or a `PositiveError` if `p` is 0.
+    pub fn thin(&self, p: f64) -> Result<PoissonProcess, RngError> {
+        RngError::check_probability(p)?;
+
+        PoissonProcess::new(p * self.rate, self.horizon)
+    }
+
+    /// Creates the process of the merged events of independent Poisson processes.
+    ///
+    /// By the superposition identity the merged events form a Poisson process with the rate `λ₁ + ... + λₙ`.
+    /// Every event of the merged process stems from the i-th process with probability `λᵢ / (λ₁ + ... + λₙ)`,
+    /// independently of all other events.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rates` - A slice of `f64` representing the rates of the processes. They must be positive numbers.
+    /// * `horizon` - An `Option<f64>` representing the time horizon. If it is `Some`, it must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PoissonProcess)` - Returns the superposed instance of `PoissonProcess`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no rates or a `PositiveError` if a rate or the `horizon` is less than or equal to 0.
+    pub fn superpose(rates: &[f64], horizon: Option<f64>) -> Result<PoissonProcess, RngError> {
+        RngError::check_empty(rates)?;
+        for &rate in rates {
+            RngError::check_positive(rate)?;
+        }
+
+        PoissonProcess::new(rates.iter().sum(), horizon)
+    }
+
+    /// Returns an iterator over the inter-arrival gaps.
+    ///
+    /// # Returns
+    ///
+    /// A `PoissonGaps` iterator. It is infinite if there is no time horizon,
+    /// otherwise it ends with the last event before the horizon.
+    pub fn gaps(&mut self) -> PoissonGaps<'_> {
+        PoissonGaps {
+            process: self,
+            time: 0_f64,
+        }
+    }
+}
+
+/// An iterator over the inter-arrival gaps of a `PoissonProcess`.
+///
+/// It is created by the `gaps` method of `PoissonProcess`.
+pub struct PoissonGaps<'a> {
+    /// The process generating the gaps.
+    process: &'a mut PoissonProcess,
+
+    /// The time of the last event.
+    time: f64,
+}
+
+impl Iterator for PoissonGaps<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        let gap: f64 = self.process.generate();
+        self.time += gap;
+
+        match self.process.horizon {
+            Some(horizon) if self.time > horizon => None,
+            _ => Some(gap),
+        }
+    }
+}
+
+/// A struct for generating the events of a non-homogeneous Poisson process.
+///
+/// The rate of the process is a function of time `λ(t)` bounded by `max_rate`.
+/// The events are generated by thinning (Lewis-Shedler):
+/// Candidates are generated by a homogeneous process with rate `max_rate`
+/// and a candidate at time `t` is kept with probability `λ(t) / max_rate`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `rate_function` - The rate λ(t) of the process.
+/// * `max_rate` - An upper bound of the rate function. Must be a positive number.
+/// * `horizon` - The time horizon after which no more events are generated. Must be a positive number.
+///
+/// # Notes
+///
+/// If the rate function exceeds `max_rate`, those times are generated with the rate `max_rate` instead.
+/// The closer `max_rate` is to the supremum of the rate function, the fewer candidates are rejected.
+#[derive(Clone)]
+pub struct NonHomogeneousPoissonProcess {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The rate λ(t) of the process.
+    rate_function: Rc<dyn Fn(f64) -> f64>,
+
+    /// An upper bound of the rate function.
+    max_rate: f64,
+
+    /// The time horizon.
+    horizon: f64,
+}
+
+auto_seed_methods!(NonHomogeneousPoissonProcess);
+
+impl Debug for NonHomogeneousPoissonProcess {
+    /// Formats the parameters of the `NonHomogeneousPoissonProcess`, leaving out the rate function.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        format
+            .debug_struct("NonHomogeneousPoissonProcess")
+            .field("rng", &self.rng)
+            .field("max_rate", &self.max_rate)
+            .field("horizon", &self.horizon)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NonHomogeneousPoissonProcess {
+    /// Creates a new `NonHomogeneousPoissonProcess` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_function` - A closure representing the rate λ(t) of the process. It should be non-negative.
+    /// * `max_rate` - A `f64` representing an upper bound of the rate function. It must be a positive number.
+    /// * `horizon` - A `f64` representing the time horizon. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(NonHomogeneousPoissonProcess)` - Returns an instance of `NonHomogeneousPoissonProcess` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `max_rate` or `horizon` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new<F: Fn(f64) -> f64 + 'static>(
+        rate_function: F,
+        max_rate: f64,
+        horizon: f64,
+    ) -> Result<NonHomogeneousPoissonProcess, RngError> {
+        RngError::check_finite(max_rate)?;
+        RngError::check_finite(horizon)?;
+        RngError::check_positive(max_rate)?;
+        RngError::check_positive(horizon)?;
+
+        Ok(NonHomogeneousPoissonProcess {
+            rng: Rng::new(),
+            rate_function: Rc::new(rate_function),
+            max_rate,
+            horizon,
+        })
+    }
+
+    /// Generates the sorted arrival times in [0, horizon].
+    ///
+    /// # Returns
+    ///
+    /// A sorted `Vec<f64>` of all arrival times up to the horizon.
+    pub fn arrival_times(&mut self) -> Vec<f64> {
+        let inverse_rate: f64 = 1_f64 / self.max_rate;
+        let mut arrivals: Vec<f64> = Vec::new();
+        let mut time: f64 = 0_f64;
+
+        loop {
+            time -= math::ln(self.rng.generate()) * inverse_rate;
+            if time > self.horizon {
+                return arrivals;
+            }
+
+            if self.rng.generate() * self.max_rate < (self.rate_function)(time) {
+                arrivals.push(time);
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! This module contains the Python bindings of the distributions.
+//!
+//! Every Python class wraps the distribution of the same name, e.g. `random_rs.Normal(0.0, 1.0, seed=42)`.
+//! `generate()` returns a single `float` and `generate_multiple(n)` fills a new NumPy array of `n` values in Rust,
+//! so large batches are generated without a Python loop.
+//! Invalid parameters raise a `ValueError` with the message of the `RngError`.
+//!
+//! This module requires the `python` feature. The extension module is built with `maturin build --features python`.
+
+use numpy::{PyArray1, PyArrayMethods};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::rng::RngTrait;
+use crate::rng_error::RngError;
+use crate::{
+    Bernoulli, Beta, Binomial, ChiSquared, Exponential, Gamma, Geometric, Laplace, LogNormal,
+    Normal, Poisson, StudentsT, Uniform, Weibull,
+};
+
+impl From<RngError> for PyErr {
+    /// Converts a `RngError` into a Python `ValueError`.
+    fn from(error: RngError) -> PyErr {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+/// Implements a Python class wrapping a distribution.
+///
+/// The constructor takes the parameters of `new` and an optional keyword argument `seed`.
+/// The output type is the return type of the `generate` method of the distribution.
+macro_rules! python_class {
+    ($py_name:ident, $name:literal, $distribution:ident ( $($param:ident: $ty:ty),* ) -> $output:ty) => {
+        #[doc = concat!("The Python class of `", stringify!($distribution), "`.")]
+        #[pyclass(name = $name, module = "random_rs")]
+        struct $py_name {
+            /// The wrapped distribution.
+            inner: $distribution,
+        }
+
+        #[pymethods]
+        impl $py_name {
+            /// Creates a new instance with the given parameters and an optional seed.
+            #[new]
+            #[pyo3(signature = ($($param,)* seed = None))]
+            fn new($($param: $ty,)* seed: Option<u64>) -> PyResult<Self> {
+                let mut inner: $distribution = $distribution::new($($param),*)?;
+                if let Some(seed) = seed {
+                    inner.set_seed(seed);
+                }
+                Ok($py_name { inner })
+            }
+
+            /// Generates a single random value, an `int` for discrete distributions.
+            fn generate(&mut self) -> $output {
+                self.inner.generate()
+            }
+
+            /// Generates `n` random values into a new NumPy array of `float64`.
+            fn generate_multiple<'py>(
+                &mut self,
+                py: Python<'py>,
+                n: usize,
+            ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+                let array: Bound<'py, PyArray1<f64>> = PyArray1::zeros(py, n, false);
+                self.inner.fill(array.readwrite().as_slice_mut()?);
+                Ok(array)
+            }
+
+            /// The seed of the random number generator.
+            #[getter]
+            fn seed(&self) -> u64 {
+                RngTrait::seed(&self.inner)
+            }
+
+            /// Sets the seed of the random number generator.
+            fn set_seed(&mut self, seed: u64) {
+                RngTrait::set_seed(&mut self.inner, seed);
+            }
+
+            /// Restarts the random number generator from its seed.
+            fn restart(&mut self) {
+                RngTrait::restart(&mut self.inner);
+            }
+
+            /// Returns the distribution in mathematical notation.
+            fn __repr__(&self) -> String {
+                self.inner.to_string()
+            }
+        }
+    };
+}
+
+python_class!(PyBernoulli, "Bernoulli", Bernoulli(probability: f64) -> u32);
+python_class!(PyBeta, "Beta", Beta(alpha: f64, beta: f64) -> f64);
+python_class!(PyBinomial, "Binomial", Binomial(n: i32, p: f64) -> i32);
+python_class!(PyChiSquared, "ChiSquared", ChiSquared(k: i32) -> f64);
+python_class!(PyExponential, "Exponential", Exponential(rate: f64) -> f64);
+python_class!(PyGamma, "Gamma", Gamma(shape: f64, scale: f64) -> f64);
+python_class!(PyGeometric, "Geometric", Geometric(probability: f64) -> i32);
+python_class!(PyLaplace, "Laplace", Laplace(location: f64, scale: f64) -> f64);
+python_class!(PyLogNormal, "LogNormal", LogNormal(mean: f64, variance: f64) -> f64);
+python_class!(PyNormal, "Normal", Normal(mean: f64, variance: f64) -> f64);
+python_class!(PyPoisson, "Poisson", Poisson(rate: f64) -> i32);
+python_class!(PyStudentsT, "StudentsT", StudentsT(k: f64) -> f64);
+python_class!(PyUniform, "Uniform", Uniform(a: f64, b: f64) -> f64);
+python_class!(PyWeibull, "Weibull", Weibull(shape: f64, scale: f64) -> f64);
+
+/// The Python module `random_rs` containing the distribution classes.
+#[pymodule]
+fn random_rs(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyBernoulli>()?;
+    module.add_class::<PyBeta>()?;
+    module.add_class::<PyBinomial>()?;
+    module.add_class::<PyChiSquared>()?;
+    module.add_class::<PyExponential>()?;
+    module.add_class::<PyGamma>()?;
+    module.add_class::<PyGeometric>()?;
+    module.add_class::<PyLaplace>()?;
+    module.add_class::<PyLogNormal>()?;
+    module.add_class::<PyNormal>()?;
+    module.add_class::<PyPoisson>()?;
+    module.add_class::<PyStudentsT>()?;
+    module.add_class::<PyUniform>()?;
+    module.add_class::<PyWeibull>()?;
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! This module contains the `Quantile` trait.
+
+use crate::rng::UniformSource;
+use crate::rng_error::RngError;
+use crate::stratified::Stratified;
+use crate::tabulated_quantile::TabulatedQuantile;
+
+/// A trait for distributions with a quantile function (inverse cumulative distribution function).
+///
+/// The quantile function `Q` maps a probability `p` to the smallest `x` with `P(X <= x) >= p`.
+/// Applying it to a uniformly distributed random variable yields a random variable of the distribution (inverse transform sampling).
+/// This makes it possible to combine distributions with other sources of uniform numbers, e.g. in a `GaussianCopula`.
+pub trait Quantile {
+    /// Evaluates the quantile function at a given probability.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64;
+
+    /// Generates a random value using the uniformly distributed numbers of a given source.
+    ///
+    /// This is inverse transform sampling, i.e. the quantile function is applied to the next number of the source.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Any source of uniformly distributed numbers implementing `UniformSource`.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` distributed according to the distribution.
+    fn sample<S: UniformSource>(&self, source: &mut S) -> f64
+    where
+        Self: Sized,
+    {
+        let random: f64 = self.quantile(source.next_uniform());
+        debug_assert!(!random.is_nan(), "generated a NaN value");
+        random
+    }
+
+    /// Turns the distribution into a `TabulatedQuantile`, which samples from a precomputed table of quantiles.
+    ///
+    /// This is only sensible for continuous distributions with an expensive quantile function.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - A `usize` representing the number of cells of the table. It must be at least 2.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TabulatedQuantile)` - Returns the tabulated distribution with a system-generated seed.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the number of cells is less than 2.
+    fn tabulate(self, cells: usize) -> Result<TabulatedQuantile, RngError>
+    where
+        Self: Sized + 'static,
+    {
+        TabulatedQuantile::new(self, cells)
+    }
+
+    /// Turns the distribution into a `Stratified` wrapper, which draws one value from each of `strata` strata per pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `strata` - A `usize` representing the number of strata. It must be at least 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Stratified)` - Returns the wrapped distribution with a system-generated seed.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the number of strata is 0.
+    fn stratify(self, strata: usize) -> Result<Stratified<Self>, RngError>
+    where
+        Self: Sized,
+    {
+        Stratified::new(self, strata)
+    }
+}
@@ -0,0 +1,123 @@
+//! This module contains the implementation of the `QuantileTable` struct and its methods.
+
+use crate::auto_rng_trait;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a distribution given by a table of quantiles.
+///
+/// The quantile function is interpolated linearly between the points `(pᵢ, xᵢ)` of the table,
+/// and values are generated by inverse transform sampling.
+/// This corresponds to a density which is constant between consecutive quantiles.
+/// Typical tables are percentile tables of risk reports, e.g. the P5, P50 and P95 values.
+///
+/// Probabilities outside of the range of the table are mapped to the first and the last value respectively,
+/// so if the table does not start at 0 or end at 1 the remaining probability is concentrated at these values.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `probabilities` - The strictly increasing probabilities of the table.
+/// * `values` - The non-decreasing quantiles of the table.
+#[derive(Debug, Clone)]
+pub struct QuantileTable {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The probabilities of the table.
+    probabilities: Vec<f64>,
+
+    /// The quantiles of the table.
+    values: Vec<f64>,
+}
+
+auto_rng_trait!(QuantileTable);
+
+impl QuantileTable {
+    /// Creates a new `QuantileTable` instance with given probabilities and quantiles.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `probabilities` - A `Vec<f64>` of strictly increasing probabilities between 0 and 1.
+    /// * `values` - A `Vec<f64>` of the corresponding quantiles. They must be non-decreasing.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QuantileTable)` - Returns an instance of `QuantileTable` if the table is valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if the table is empty, an `IntervalError` if the lengths do not match, a `ProbabilityError` if a probability is not between 0 and 1 or an `OrderError` if the probabilities are not increasing or the values are decreasing.
+    pub fn new(probabilities: Vec<f64>, values: Vec<f64>) -> Result<QuantileTable, RngError> {
+        RngError::check_empty(&probabilities)?;
+        RngError::check_interval(
+            values.len() as f64,
+            probabilities.len() as f64,
+            probabilities.len() as f64,
+        )?;
+
+        for &probability in probabilities.iter() {
+            RngError::check_probability(probability)?;
+        }
+        for window in probabilities.windows(2_usize) {
+            RngError::check_order(window[0], window[1])?;
+        }
+        for window in values.windows(2_usize) {
+            if window[1] < window[0] {
+                return Err(RngError::order(window[0], window[1]));
+            }
+        }
+
+        Ok(QuantileTable {
+            rng: Rng::new(),
+            probabilities,
+            values,
+        })
+    }
+
+    /// Generates a random value by interpolating the table at a uniformly distributed probability.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value between the first and the last value of the table.
+    pub fn generate(&mut self) -> f64 {
+        let uni: f64 = self.rng.generate();
+        self.quantile(uni)
+    }
+}
+
+impl Quantile for QuantileTable {
+    /// Evaluates the linearly interpolated quantile function of the table.
+    ///
+    /// For `pᵢ <= p <= pᵢ₊₁` the quantile function is given by
+    /// ```text
+    /// Q(p) = xᵢ + (p - pᵢ) / (pᵢ₊₁ - pᵢ) (xᵢ₊₁ - xᵢ)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        let n: usize = self.probabilities.len();
+        let index: usize = self
+            .probabilities
+            .partition_point(|&probability| probability <= p);
+
+        if index == 0_usize {
+            return self.values[0_usize];
+        } else if index == n {
+            return self.values[n - 1_usize];
+        }
+
+        let (p0, p1) = (
+            self.probabilities[index - 1_usize],
+            self.probabilities[index],
+        );
+        let (x0, x1) = (self.values[index - 1_usize], self.values[index]);
+        x0 + (p - p0) / (p1 - p0) * (x1 - x0)
+    }
+}
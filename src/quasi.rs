@@ -0,0 +1,346 @@
+//! This module contains the low-discrepancy (quasi-random) sequences `Sobol` and `Halton`.
+//!
+//! A low-discrepancy sequence fills the unit cube `[0, 1)ᵈ` much more evenly than independent uniformly distributed points.
+//! Using it instead of a pseudo-random number generator (quasi-Monte Carlo) reduces the error of an integral estimate
+//! with `n` points from about `1 / sqrt(n)` to about `ln(n)ᵈ / n`.
+//!
+//! Both sequences return the coordinates of their points one after another through `generate`,
+//! so every `d` consecutive numbers form one point.
+//! They implement `UniformSource` and can therefore drive every distribution implementing `Quantile`.
+//! A model consuming `d` uniform numbers per sample should use a sequence of dimension `d`.
+//!
+//! The sequences are deterministic and start with the point after the origin,
+//! so no coordinate is ever 0, which would be mapped to an infinite value by many quantile functions.
+
+use crate::rng::UniformSource;
+use crate::rng_error::RngError;
+
+/// The polynomial degree `s`, the coefficients `a` and the initial direction numbers `m` of the Sobol sequence
+/// for the dimensions 2 to 16, taken from the table `new-joe-kuo-6.21201` of Joe and Kuo.
+const SOBOL_PARAMETERS: [(usize, u32, &[u32]); 15] = [
+    (1_usize, 0_u32, &[1_u32]),
+    (2_usize, 1_u32, &[1_u32, 3_u32]),
+    (3_usize, 1_u32, &[1_u32, 3_u32, 1_u32]),
+    (3_usize, 2_u32, &[1_u32, 1_u32, 1_u32]),
+    (4_usize, 1_u32, &[1_u32, 1_u32, 3_u32, 3_u32]),
+    (4_usize, 4_u32, &[1_u32, 3_u32, 5_u32, 13_u32]),
+    (5_usize, 2_u32, &[1_u32, 1_u32, 5_u32, 5_u32, 17_u32]),
+    (5_usize, 4_u32, &[1_u32, 1_u32, 5_u32, 5_u32, 5_u32]),
+    (5_usize, 7_u32, &[1_u32, 1_u32, 7_u32, 11_u32, 19_u32]),
+    (5_usize, 11_u32, &[1_u32, 1_u32, 5_u32, 1_u32, 1_u32]),
+    (5_usize, 13_u32, &[1_u32, 1_u32, 1_u32, 3_u32, 11_u32]),
+    (5_usize, 14_u32, &[1_u32, 3_u32, 5_u32, 5_u32, 31_u32]),
+    (6_usize, 1_u32, &[1_u32, 3_u32, 3_u32, 9_u32, 7_u32, 49_u32]),
+    (
+        6_usize,
+        13_u32,
+        &[1_u32, 1_u32, 1_u32, 15_u32, 21_u32, 21_u32],
+    ),
+    (
+        6_usize,
+        16_u32,
+        &[1_u32, 3_u32, 1_u32, 13_u32, 27_u32, 49_u32],
+    ),
+];
+
+/// The number of bits of the Sobol points.
+const SOBOL_BITS: usize = 32_usize;
+
+/// A struct for generating the Sobol sequence in up to 16 dimensions.
+///
+/// The points are generated in Gray code order, which only needs a single XOR per coordinate and point.
+///
+/// # Fields
+///
+/// * `directions` - The direction numbers of every dimension.
+/// * `point` - The current point as 32-bit integers.
+/// * `index` - The index of the current point.
+/// * `coordinate` - The coordinate of the current point returned by the next call of `generate`.
+///
+/// # Notes
+///
+/// The direction numbers have 32 bits, so the sequence has `MAX_POINTS = 2³² - 1` distinct points.
+/// After the last one it starts again from the beginning, like after `restart`.
+/// A run needing more points should split them among several dimensions or use `Halton` instead.
+#[derive(Debug, Clone)]
+pub struct Sobol {
+    /// The direction numbers of every dimension.
+    directions: Vec<[u32; SOBOL_BITS]>,
+
+    /// The current point as 32-bit integers.
+    point: Vec<u32>,
+
+    /// The index of the current point.
+    index: u32,
+
+    /// The coordinate returned by the next call of `generate`.
+    coordinate: usize,
+}
+
+impl Sobol {
+    /// The maximal supported dimension.
+    pub const MAX_DIMENSION: usize = SOBOL_PARAMETERS.len() + 1_usize;
+
+    /// The number of distinct points, after which the sequence starts again from the beginning.
+    pub const MAX_POINTS: u64 = u32::MAX as u64;
+
+    /// The factor `2^(-32)` to map the integer coordinates to [0, 1).
+    const INV_2_POW_32: f64 = 1_f64 / (1_u64 << 32_u32) as f64;
+}
+
+impl Sobol {
+    /// Creates a new `Sobol` instance with a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - A `usize` representing the dimension of the points. It must be between 1 and `MAX_DIMENSION`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Sobol)` - Returns an instance of `Sobol` if the dimension is valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if `dimension` is not between 1 and `MAX_DIMENSION`.
+    pub fn new(dimension: usize) -> Result<Sobol, RngError> {
+        RngError::check_interval(dimension as f64, 1_f64, Self::MAX_DIMENSION as f64)?;
+
+        let mut directions: Vec<[u32; SOBOL_BITS]> = Vec::with_capacity(dimension);
+
+        // The first dimension is the van der Corput sequence in base 2
+        let mut first: [u32; SOBOL_BITS] = [0_u32; SOBOL_BITS];
+        for (i, direction) in first.iter_mut().enumerate() {
+            *direction = 1_u32 << (31_usize - i);
+        }
+        directions.push(first);
+
+        for &(s, a, m) in SOBOL_PARAMETERS.iter().take(dimension - 1_usize) {
+            directions.push(Self::direction_numbers(s, a, m));
+        }
+
+        Ok(Sobol {
+            directions,
+            point: vec![0_u32; dimension],
+            index: 0_u32,
+            coordinate: 0_usize,
+        })
+    }
+
+    /// Returns the next coordinate of the sequence.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` in (0, 1).
+    pub fn generate(&mut self) -> f64 {
+        if self.coordinate == 0_usize {
+            self.advance();
+        }
+
+        let value: f64 = self.point[self.coordinate] as f64 * Self::INV_2_POW_32;
+        self.coordinate = (self.coordinate + 1_usize) % self.point.len();
+        value
+    }
+
+    /// Returns the next point of the sequence.
+    ///
+    /// If the coordinates of the current point were only partly returned by `generate`, the rest is skipped.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `dimension` with coordinates in (0, 1).
+    pub fn generate_point(&mut self) -> Vec<f64> {
+        self.advance();
+        self.coordinate = 0_usize;
+
+        self.point
+            .iter()
+            .map(|&value| value as f64 * Self::INV_2_POW_32)
+            .collect()
+    }
+
+    /// Returns the dimension of the points.
+    ///
+    /// # Returns
+    ///
+    /// The dimension as a `usize`.
+    pub fn dimension(&self) -> usize {
+        self.point.len()
+    }
+
+    /// Restarts the sequence from the beginning.
+    pub fn restart(&mut self) {
+        self.point.fill(0_u32);
+        self.index = 0_u32;
+        self.coordinate = 0_usize;
+    }
+
+    /// Moves to the next point by flipping the direction number of the rightmost zero bit of the current index.
+    ///
+    /// After the last point the index has no zero bit left, so the sequence is restarted first.
+    fn advance(&mut self) {
+        if self.index == u32::MAX {
+            self.restart();
+        }
+
+        let bit: usize = self.index.trailing_ones() as usize;
+        self.index += 1_u32;
+
+        for (value, directions) in self.point.iter_mut().zip(self.directions.iter()) {
+            *value ^= directions[bit];
+        }
+    }
+
+    /// Calculates the direction numbers of a dimension from its primitive polynomial and initial direction numbers.
+    ///
+    /// The first `s` direction numbers are given by `m`, the others follow from the recurrence
+    /// ```text
+    /// v(i) = a₁ v(i - 1) ⊕ ... ⊕ aₛ₋₁ v(i - s + 1) ⊕ v(i - s) ⊕ (v(i - s) >> s)
+    /// ```
+    fn direction_numbers(s: usize, a: u32, m: &[u32]) -> [u32; SOBOL_BITS] {
+        let mut directions: [u32; SOBOL_BITS] = [0_u32; SOBOL_BITS];
+
+        for (i, &initial) in m.iter().enumerate() {
+            directions[i] = initial << (31_usize - i);
+        }
+        for i in s..SOBOL_BITS {
+            let mut direction: u32 = directions[i - s] ^ (directions[i - s] >> s);
+            for k in 1_usize..s {
+                if (a >> (s - 1_usize - k)) & 1_u32 == 1_u32 {
+                    direction ^= directions[i - k];
+                }
+            }
+            directions[i] = direction;
+        }
+        directions
+    }
+}
+
+impl UniformSource for Sobol {
+    /// Returns the next coordinate of the sequence using `Sobol::generate`.
+    fn next_uniform(&mut self) -> f64 {
+        self.generate()
+    }
+}
+
+/// A struct for generating the Halton sequence.
+///
+/// The i-th coordinate of the n-th point is the radical inverse of `n` in the i-th prime base,
+/// i.e. the digits of `n` in this base are mirrored at the decimal point.
+///
+/// # Fields
+///
+/// * `bases` - The prime base of every dimension.
+/// * `index` - The index of the current point.
+/// * `coordinate` - The coordinate of the current point returned by the next call of `generate`.
+///
+/// # Notes
+///
+/// In high dimensions the coordinates with large bases are strongly correlated for the first points.
+/// The Halton sequence is therefore mostly used in less than about 10 dimensions.
+#[derive(Debug, Clone)]
+pub struct Halton {
+    /// The prime base of every dimension.
+    bases: Vec<u64>,
+
+    /// The index of the current point.
+    index: u64,
+
+    /// The coordinate returned by the next call of `generate`.
+    coordinate: usize,
+}
+
+impl Halton {
+    /// Creates a new `Halton` instance with a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - A `usize` representing the dimension of the points. It must be a positive integer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Halton)` - Returns an instance of `Halton` if the dimension is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `dimension` is 0.
+    pub fn new(dimension: usize) -> Result<Halton, RngError> {
+        RngError::check_positive(dimension as f64)?;
+
+        let mut bases: Vec<u64> = Vec::with_capacity(dimension);
+        let mut candidate: u64 = 2_u64;
+        while bases.len() < dimension {
+            if bases.iter().all(|&prime| !candidate.is_multiple_of(prime)) {
+                bases.push(candidate);
+            }
+            candidate += 1_u64;
+        }
+
+        Ok(Halton {
+            bases,
+            index: 0_u64,
+            coordinate: 0_usize,
+        })
+    }
+
+    /// Returns the next coordinate of the sequence.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` in (0, 1).
+    pub fn generate(&mut self) -> f64 {
+        if self.coordinate == 0_usize {
+            self.index += 1_u64;
+        }
+
+        let value: f64 = Self::radical_inverse(self.index, self.bases[self.coordinate]);
+        self.coordinate = (self.coordinate + 1_usize) % self.bases.len();
+        value
+    }
+
+    /// Returns the next point of the sequence.
+    ///
+    /// If the coordinates of the current point were only partly returned by `generate`, the rest is skipped.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `dimension` with coordinates in (0, 1).
+    pub fn generate_point(&mut self) -> Vec<f64> {
+        self.index += 1_u64;
+        self.coordinate = 0_usize;
+
+        self.bases
+            .iter()
+            .map(|&base| Self::radical_inverse(self.index, base))
+            .collect()
+    }
+
+    /// Returns the dimension of the points.
+    ///
+    /// # Returns
+    ///
+    /// The dimension as a `usize`.
+    pub fn dimension(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Restarts the sequence from the beginning.
+    pub fn restart(&mut self) {
+        self.index = 0_u64;
+        self.coordinate = 0_usize;
+    }
+
+    /// Calculates the radical inverse of `n` in a given base.
+    fn radical_inverse(mut n: u64, base: u64) -> f64 {
+        let inverse_base: f64 = 1_f64 / base as f64;
+        let mut factor: f64 = inverse_base;
+        let mut result: f64 = 0_f64;
+
+        while n > 0_u64 {
+            result += factor * (n % base) as f64;
+            n /= base;
+            factor *= inverse_base;
+        }
+        result
+    }
+}
+
+impl UniformSource for Halton {
+    /// Returns the next coordinate of the sequence using `Halton::generate`.
+    fn next_uniform(&mut self) -> f64 {
+        self.generate()
+    }
+}
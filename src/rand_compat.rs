@@ -0,0 +1,92 @@
+//! This module contains the adapters between this crate and the `rand` ecosystem.
+//!
+//! `Rng` implements `rand_core::RngCore` and `rand_core::SeedableRng`, so it can drive any distribution of `rand` or `rand_distr`.
+//! Conversely, `RandSource` wraps any `rand_core::RngCore` as a `UniformSource`, so a `rand` generator can drive the `Quantile::sample` of the distributions here.
+//! This module requires the `rand-compat` feature.
+
+use rand_core::{RngCore, SeedableRng, impls};
+
+use crate::rng::{Rng, UniformSource};
+
+impl RngCore for Rng {
+    /// Returns the high 32 bits of the next state, see `Rng::next_u32`.
+    fn next_u32(&mut self) -> u32 {
+        Rng::next_u32(self)
+    }
+
+    /// Returns the high 32 bits of the next two states combined, see `Rng::next_u64`.
+    fn next_u64(&mut self) -> u64 {
+        Rng::next_u64(self)
+    }
+
+    /// Fills the bytes with the little-endian bytes of `next_u64`.
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+impl SeedableRng for Rng {
+    /// The little-endian bytes of the `u64` seed.
+    type Seed = [u8; 8];
+
+    /// Creates a new `Rng` from the little-endian bytes of a seed.
+    ///
+    /// `Rng::from_seed(seed.to_le_bytes())` generates the same values as `Rng::new_seed(seed)`.
+    fn from_seed(seed: Self::Seed) -> Rng {
+        Rng::new_seed(u64::from_le_bytes(seed))
+    }
+
+    /// Creates a new `Rng` using `Rng::new_seed`, so both generate the same values.
+    fn seed_from_u64(state: u64) -> Rng {
+        Rng::new_seed(state)
+    }
+}
+
+/// A wrapper using any `rand_core::RngCore` as the uniformly distributed source of this crate.
+///
+/// The high 53 bits of every `u64` of the wrapped generator are scaled to a value in [0, 1),
+/// e.g. `RandSource::new(rand::rngs::StdRng::seed_from_u64(42))` can be passed to `Quantile::sample`.
+/// This struct requires the `rand-compat` feature.
+///
+/// # Fields
+///
+/// * `rng` - The wrapped `rand` generator.
+#[derive(Debug, Clone)]
+pub struct RandSource<R: RngCore> {
+    /// The wrapped `rand` generator.
+    rng: R,
+}
+
+impl<R: RngCore> RandSource<R> {
+    /// The scale of the high 53 bits to a value between 0 and 1, i.e. `2^(-53)`.
+    const INV_2_POW_53: f64 = 1_f64 / (1_u64 << 53_u32) as f64;
+
+    /// Creates a new `RandSource` wrapping a `rand` generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - Any generator implementing `rand_core::RngCore`.
+    ///
+    /// # Returns
+    ///
+    /// A new `RandSource` instance.
+    pub fn new(rng: R) -> RandSource<R> {
+        RandSource { rng }
+    }
+
+    /// Returns the wrapped generator.
+    ///
+    /// # Returns
+    ///
+    /// The wrapped `rand` generator.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> UniformSource for RandSource<R> {
+    /// Returns the high 53 bits of the next `u64` of the wrapped generator scaled to [0, 1).
+    fn next_uniform(&mut self) -> f64 {
+        (self.rng.next_u64() >> 11_u32) as f64 * Self::INV_2_POW_53
+    }
+}
@@ -1,9 +1,12 @@
 //! This module contains the implementation of the `RandInt` struct and its methods.
 
+use crate::auto_display;
 use crate::auto_rng_trait;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
+use crate::tie_breaking::TieBreaking;
 
+#[derive(Debug, Clone)]
 pub struct RandInt {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -16,9 +19,13 @@ pub struct RandInt {
 
     /// Precomputes (b - a + 1) and stores it to speed up generations.
     range: f64,
+
+    /// The rule for a uniform number on a step of the cumulative distribution function.
+    tie_breaking: TieBreaking,
 }
 
 auto_rng_trait!(RandInt);
+auto_display!(RandInt { "a" = a, "b" = b });
 
 impl RandInt {
     /// Creates a new `RandInt` instance with given parameters.
@@ -46,17 +53,41 @@ impl RandInt {
             a,
             b,
             range,
+            tie_breaking: TieBreaking::Upper,
         })
     }
 
+    /// Sets the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// By default `TieBreaking::Upper` generates the next value, see `TieBreaking` for the bias of both rules.
+    /// With `TieBreaking::Lower` the value is `a + ceil((b - a + 1) U) - 1` instead, but at least `a`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tie_breaking` - The `TieBreaking` rule to use.
+    pub fn set_tie_breaking(&mut self, tie_breaking: TieBreaking) {
+        self.tie_breaking = tie_breaking;
+    }
+
+    /// Returns the rule for a uniformly distributed number exactly on a step of the cumulative distribution function.
+    ///
+    /// # Returns
+    ///
+    /// The `TieBreaking` rule in use.
+    pub fn tie_breaking(&self) -> TieBreaking {
+        self.tie_breaking
+    }
+
     /// Generates a random integer between `a` and `b`.
     ///
+    /// The value is `a + floor((b - a + 1) U)` for a uniformly distributed `U`, see `RandInt::set_tie_breaking` for ties.
+    ///
     /// # Returns
     ///
     /// A `i32` value generated from the `RandInt` distribution.
     pub fn generate(&mut self) -> i32 {
         let uni: f64 = self.rng.generate();
 
-        (self.range * uni).floor() as i32 + self.a
+        self.tie_breaking.cell(self.range * uni) as i32 + self.a
     }
 }
@@ -0,0 +1,183 @@
+//! This module contains the implementation of the `RandomCorrelation` struct and its methods.
+
+use crate::auto_seed_methods;
+use crate::auxiliary::cholesky;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating random correlation matrices.
+///
+/// The matrices are symmetric positive definite with ones on the diagonal,
+/// so they can be passed directly to e.g. `GaussianCopula` or `CorrelatedCounts`.
+/// They follow the LKJ distribution with the density
+/// ```text
+/// f(R) ∝ det(R)^(η - 1)
+/// ```
+/// For `η = 1` all correlation matrices are equally likely.
+/// Bigger values of `η` concentrate the matrices around the identity, smaller ones favour strong correlations.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `dimension` - The number of rows and columns of the matrices. Must be a positive integer.
+/// * `eta` - The concentration (η) of the LKJ distribution. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct RandomCorrelation {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The number of rows and columns of the matrices.
+    dimension: usize,
+
+    /// The concentration (η) of the LKJ distribution.
+    eta: f64,
+}
+
+auto_seed_methods!(RandomCorrelation);
+
+impl RandomCorrelation {
+    /// Creates a new `RandomCorrelation` instance generating uniformly distributed correlation matrices.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - A `usize` representing the number of rows and columns. It must be a positive integer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RandomCorrelation)` - Returns an instance of `RandomCorrelation` if the dimension is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `dimension` is 0.
+    pub fn new(dimension: usize) -> Result<RandomCorrelation, RngError> {
+        RandomCorrelation::with_eta(dimension, 1_f64)
+    }
+
+    /// Creates a new `RandomCorrelation` instance with a given concentration.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - A `usize` representing the number of rows and columns. It must be a positive integer.
+    /// * `eta` - A `f64` representing the concentration (η) of the LKJ distribution. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RandomCorrelation)` - Returns an instance of `RandomCorrelation` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `dimension` is 0 or `eta` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn with_eta(dimension: usize, eta: f64) -> Result<RandomCorrelation, RngError> {
+        RngError::check_finite(eta)?;
+        RngError::check_positive(dimension as f64)?;
+        RngError::check_positive(eta)?;
+
+        Ok(RandomCorrelation {
+            rng: Rng::new(),
+            dimension,
+            eta,
+        })
+    }
+
+    /// Generates a random correlation matrix with the onion method of Lewandowski, Kurowicka and Joe.
+    ///
+    /// The matrix is grown one row and column at a time.
+    /// Given a `k x k` correlation matrix `R = A Aᵀ`, the new column is
+    /// ```text
+    /// z = A sqrt(Y) u
+    /// ```
+    /// where `u` is uniformly distributed on the unit sphere in `k` dimensions and `Y` is Beta(k/2, β) distributed,
+    /// with `β` starting at `η + (d - 2) / 2` and decreasing by `1/2` in every step.
+    /// The first correlation is `2 B - 1` with `B` being Beta(β, β) distributed.
+    ///
+    /// # Returns
+    ///
+    /// A `d x d` correlation matrix in row-major order.
+    pub fn generate(&mut self) -> Vec<Vec<f64>> {
+        loop {
+            if let Some(matrix) = self.try_generate() {
+                return matrix;
+            }
+        }
+    }
+
+    /// Tries to generate a random correlation matrix with the onion method.
+    ///
+    /// For very small `η` the leading matrix can become singular due to rounding, in which case `None` is returned.
+    fn try_generate(&mut self) -> Option<Vec<Vec<f64>>> {
+        let d: usize = self.dimension;
+        let mut matrix: Vec<Vec<f64>> = vec![vec![0_f64; d]; d];
+        matrix[0][0] = 1_f64;
+        if d == 1_usize {
+            return Some(matrix);
+        }
+
+        let mut beta: f64 = self.eta + (d as f64 - 2_f64) / 2_f64;
+        let correlation: f64 = 2_f64 * self.gen_beta(beta, beta) - 1_f64;
+        matrix[0][1] = correlation;
+        matrix[1][0] = correlation;
+        matrix[1][1] = 1_f64;
+
+        for k in 2_usize..d {
+            beta -= 0.5_f64;
+            let y: f64 = self.gen_beta(k as f64 / 2_f64, beta);
+
+            // A uniformly distributed direction in k dimensions
+            let mut direction: Vec<f64> = (0_usize..k)
+                .map(|_| self.rng.gen_standard_normal())
+                .collect();
+            let norm: f64 = direction.iter().map(|x| x * x).sum::<f64>().sqrt();
+            for value in direction.iter_mut() {
+                *value *= y.sqrt() / norm;
+            }
+
+            let leading: Vec<Vec<f64>> = matrix[..k].iter().map(|row| row[..k].to_vec()).collect();
+            let factor: Vec<Vec<f64>> = cholesky(&leading).ok()?;
+
+            for i in 0_usize..k {
+                let z: f64 = (0_usize..=i).map(|j| factor[i][j] * direction[j]).sum();
+                matrix[i][k] = z;
+                matrix[k][i] = z;
+            }
+            matrix[k][k] = 1_f64;
+        }
+        Some(matrix)
+    }
+
+    /// Generates a random covariance matrix with given standard deviations.
+    ///
+    /// The covariance matrix is
+    /// ```text
+    /// Σ = D R D
+    /// ```
+    /// where `R` is a random correlation matrix and `D` the diagonal matrix of the standard deviations.
+    ///
+    /// # Arguments
+    ///
+    /// * `std_devs` - A slice of `d` positive `f64` standard deviations.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Vec<f64>>)` - Returns the `d x d` covariance matrix in row-major order.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the number of standard deviations is not `d` or a `PositiveError` if one is less than or equal to 0.
+    pub fn generate_covariance(&mut self, std_devs: &[f64]) -> Result<Vec<Vec<f64>>, RngError> {
+        let d: f64 = self.dimension as f64;
+        RngError::check_interval(std_devs.len() as f64, d, d)?;
+        for &std_dev in std_devs {
+            RngError::check_positive(std_dev)?;
+        }
+
+        let mut matrix: Vec<Vec<f64>> = self.generate();
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value *= std_devs[i] * std_devs[j];
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Generates a random value from the Beta distribution with real shapes as a ratio of Gamma values.
+    fn gen_beta(&mut self, alpha: f64, beta: f64) -> f64 {
+        let x: f64 = self.rng.gen_standard_gamma(alpha);
+        let y: f64 = self.rng.gen_standard_gamma(beta);
+        x / (x + y)
+    }
+}
@@ -0,0 +1,139 @@
+//! This module contains the implementation of the `RandomDag` struct and its methods.
+
+use crate::auto_seed_methods;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A directed acyclic graph generated by `RandomDag`, e.g. a task graph for scheduling.
+///
+/// The nodes are numbered `0, ..., n - 1` in a topological order,
+/// so every edge `(u, v)` satisfies `u < v`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskGraph {
+    /// The layer of every node. Edges only point from lower to strictly higher layers.
+    pub layers: Vec<usize>,
+
+    /// The edges `(from, to)` of the graph.
+    pub edges: Vec<(usize, usize)>,
+
+    /// The weight of every node, e.g. the duration of a task.
+    pub weights: Vec<f64>,
+}
+
+/// A struct for generating random layered directed acyclic graphs.
+///
+/// Every node is assigned to one of `max_depth` layers uniformly at random.
+/// Then every pair of nodes in different layers is connected with probability `edge_prob`,
+/// pointing from the lower to the higher layer.
+/// Therefore, the longest path in the graph has at most `max_depth` nodes.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `n_nodes` - The number of nodes. Must be a positive integer.
+/// * `edge_prob` - The probability of an edge between two nodes in different layers. Must be a probability.
+/// * `max_depth` - The number of layers. Must be a positive integer.
+#[derive(Debug, Clone)]
+pub struct RandomDag {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The number of nodes.
+    n_nodes: usize,
+
+    /// The probability of an edge between two nodes in different layers.
+    edge_prob: f64,
+
+    /// The number of layers.
+    max_depth: usize,
+}
+
+auto_seed_methods!(RandomDag);
+
+impl RandomDag {
+    /// Creates a new `RandomDag` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - A `usize` representing the number of nodes. It must be a positive integer.
+    /// * `edge_prob` - A `f64` representing the probability of an edge. It must be between 0 and 1.
+    /// * `max_depth` - A `usize` representing the number of layers. It must be a positive integer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RandomDag)` - Returns an instance of `RandomDag` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `n_nodes` or `max_depth` is 0 or a `ProbabilityError` if `edge_prob` is not a probability.
+    pub fn new(n_nodes: usize, edge_prob: f64, max_depth: usize) -> Result<RandomDag, RngError> {
+        RngError::check_positive(n_nodes as f64)?;
+        RngError::check_probability(edge_prob)?;
+        RngError::check_positive(max_depth as f64)?;
+
+        Ok(RandomDag {
+            rng: Rng::new(),
+            n_nodes,
+            edge_prob,
+            max_depth,
+        })
+    }
+
+    /// Generates a random directed acyclic graph with unit weights.
+    ///
+    /// # Returns
+    ///
+    /// A `TaskGraph` where every node has the weight 1.
+    pub fn generate(&mut self) -> TaskGraph {
+        let (layers, edges) = self.generate_structure();
+
+        TaskGraph {
+            layers,
+            edges,
+            weights: vec![1_f64; self.n_nodes],
+        }
+    }
+
+    /// Generates a random directed acyclic graph with weights drawn from a distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Any distribution implementing `RngTrait`, e.g. an `Exponential` for task durations.
+    ///
+    /// # Returns
+    ///
+    /// A `TaskGraph` where the node weights are generated by `weights`.
+    ///
+    /// # Notes
+    ///
+    /// The weights are generated by the random number generator of the given distribution.
+    /// To reproduce a graph both the `RandomDag` and the distribution have to be seeded.
+    pub fn generate_weighted<D: RngTrait>(&mut self, weights: &mut D) -> TaskGraph {
+        let (layers, edges) = self.generate_structure();
+
+        TaskGraph {
+            layers,
+            edges,
+            weights: weights.generate_multiple(self.n_nodes),
+        }
+    }
+
+    /// Generates the layers and the edges of a random graph.
+    ///
+    /// The layers are sorted, so that the node numbers are a topological order.
+    fn generate_structure(&mut self) -> (Vec<usize>, Vec<(usize, usize)>) {
+        let mut layers: Vec<usize> = (0_usize..self.n_nodes)
+            .map(|_| self.rng.gen_below(self.max_depth as u64) as usize)
+            .collect();
+        layers.sort_unstable();
+
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for from in 0_usize..self.n_nodes {
+            for to in (from + 1_usize)..self.n_nodes {
+                if layers[from] < layers[to] && self.rng.generate() < self.edge_prob {
+                    edges.push((from, to));
+                }
+            }
+        }
+        (layers, edges)
+    }
+}
@@ -0,0 +1,140 @@
+//! This module contains the implementation of the `RandomKSat` struct and its methods.
+
+use crate::auto_seed_methods;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating random k-SAT instances.
+///
+/// Every clause consists of `k` distinct variables chosen uniformly at random,
+/// each of which is negated with probability 1/2.
+/// Literals are represented like in the DIMACS format:
+/// the variables are numbered `1, ..., n_vars` and a negative number is a negated variable.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `n_vars` - The number of variables. Must be a positive integer.
+/// * `n_clauses` - The number of clauses.
+/// * `k` - The number of literals per clause. Must be between 1 and `n_vars`.
+#[derive(Debug, Clone)]
+pub struct RandomKSat {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The number of variables.
+    n_vars: usize,
+
+    /// The number of clauses.
+    n_clauses: usize,
+
+    /// The number of literals per clause.
+    k: usize,
+}
+
+auto_seed_methods!(RandomKSat);
+
+impl RandomKSat {
+    /// Creates a new `RandomKSat` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vars` - A `usize` representing the number of variables. It must be a positive integer.
+    /// * `n_clauses` - A `usize` representing the number of clauses.
+    /// * `k` - A `usize` representing the number of literals per clause. It must be between 1 and `n_vars`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RandomKSat)` - Returns an instance of `RandomKSat` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `n_vars` is 0 or an `IntervalError` if `k` is not between 1 and `n_vars`.
+    pub fn new(n_vars: usize, n_clauses: usize, k: usize) -> Result<RandomKSat, RngError> {
+        RngError::check_positive(n_vars as f64)?;
+        RngError::check_interval(k as f64, 1_f64, n_vars as f64)?;
+
+        Ok(RandomKSat {
+            rng: Rng::new(),
+            n_vars,
+            n_clauses,
+            k,
+        })
+    }
+
+    /// Generates a uniformly random k-SAT instance.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Vec<i32>>` containing `n_clauses` clauses of `k` literals each.
+    pub fn generate(&mut self) -> Vec<Vec<i32>> {
+        (0_usize..self.n_clauses).map(|_| self.clause()).collect()
+    }
+
+    /// Generates a random k-SAT instance with a planted solution.
+    ///
+    /// A uniformly distributed assignment is generated first.
+    /// Then clauses are generated uniformly and rejected if the assignment does not satisfy them.
+    /// Therefore, the instance is guaranteed to be satisfiable.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the clauses and the planted assignment,
+    /// where the i-th entry of the assignment is the value of the variable `i + 1`.
+    pub fn generate_planted(&mut self) -> (Vec<Vec<i32>>, Vec<bool>) {
+        let assignment: Vec<bool> = (0_usize..self.n_vars)
+            .map(|_| self.rng.generate() < 0.5_f64)
+            .collect();
+
+        let mut clauses: Vec<Vec<i32>> = Vec::with_capacity(self.n_clauses);
+        while clauses.len() < self.n_clauses {
+            let clause: Vec<i32> = self.clause();
+            let satisfied: bool = clause.iter().any(|&literal| {
+                assignment[literal.unsigned_abs() as usize - 1_usize] == (literal > 0_i32)
+            });
+            if satisfied {
+                clauses.push(clause);
+            }
+        }
+        (clauses, assignment)
+    }
+
+    /// Formats an instance in the DIMACS CNF format.
+    ///
+    /// # Arguments
+    ///
+    /// * `clauses` - The clauses generated by `generate` or `generate_planted`.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the header `p cnf <n_vars> <n_clauses>` followed by one clause per line, each terminated by 0.
+    pub fn to_dimacs(&self, clauses: &[Vec<i32>]) -> String {
+        let mut dimacs: String = format!("p cnf {} {}\n", self.n_vars, clauses.len());
+        for clause in clauses {
+            for literal in clause {
+                dimacs.push_str(&literal.to_string());
+                dimacs.push(' ');
+            }
+            dimacs.push_str("0\n");
+        }
+        dimacs
+    }
+
+    /// Generates a single clause of `k` distinct variables with random signs.
+    fn clause(&mut self) -> Vec<i32> {
+        let mut clause: Vec<i32> = Vec::with_capacity(self.k);
+
+        while clause.len() < self.k {
+            let variable: i32 = self.rng.gen_below(self.n_vars as u64) as i32 + 1_i32;
+            if clause.iter().any(|literal| literal.abs() == variable) {
+                continue;
+            }
+
+            if self.rng.generate() < 0.5_f64 {
+                clause.push(-variable);
+            } else {
+                clause.push(variable);
+            }
+        }
+        clause
+    }
+}
@@ -0,0 +1,135 @@
+//! This module contains the implementation of the `RandomRotation` struct and its methods.
+
+use std::f64::consts::PI;
+
+use crate::auto_seed_methods;
+use crate::math;
+use crate::rng::Rng;
+
+/// A struct for generating uniformly distributed rotations in three dimensions.
+///
+/// The rotations are distributed according to the Haar measure, i.e. a random rotation applied to a fixed vector
+/// results in a uniformly distributed direction and no orientation is preferred.
+/// They are represented as unit quaternions `(w, x, y, z)` or as 3 x 3 rotation matrices.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+#[derive(Debug, Clone)]
+pub struct RandomRotation {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+}
+
+auto_seed_methods!(RandomRotation);
+
+impl Default for RandomRotation {
+    /// Creates a new `RandomRotation` instance using the system time as the seed.
+    fn default() -> Self {
+        RandomRotation::new()
+    }
+}
+
+impl RandomRotation {
+    /// Creates a new `RandomRotation` instance.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Returns
+    ///
+    /// A `RandomRotation` instance.
+    pub fn new() -> RandomRotation {
+        RandomRotation { rng: Rng::new() }
+    }
+
+    /// Generates a uniformly distributed unit quaternion with Shoemake's method.
+    ///
+    /// With `U₁`, `U₂` and `U₃` independently uniformly distributed on [0, 1] the quaternion is
+    /// ```text
+    /// w = sqrt(1 - U₁) sin(2π U₂)
+    /// x = sqrt(1 - U₁) cos(2π U₂)
+    /// y = sqrt(U₁) sin(2π U₃)
+    /// z = sqrt(U₁) cos(2π U₃)
+    /// ```
+    /// which is uniformly distributed on the unit sphere in four dimensions.
+    /// The quaternions `q` and `-q` describe the same rotation.
+    ///
+    /// # Returns
+    ///
+    /// A unit quaternion `[w, x, y, z]`.
+    pub fn generate(&mut self) -> [f64; 4] {
+        let u1: f64 = self.rng.generate();
+        let angle2: f64 = 2_f64 * PI * self.rng.generate();
+        let angle3: f64 = 2_f64 * PI * self.rng.generate();
+
+        let r1: f64 = (1_f64 - u1).sqrt();
+        let r2: f64 = u1.sqrt();
+
+        [
+            r1 * math::sin(angle2),
+            r1 * math::cos(angle2),
+            r2 * math::sin(angle3),
+            r2 * math::cos(angle3),
+        ]
+    }
+
+    /// Generates multiple uniformly distributed unit quaternions.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` of the number of quaternions.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of unit quaternions `[w, x, y, z]`.
+    pub fn generate_multiple(&mut self, number: usize) -> Vec<[f64; 4]> {
+        (0_usize..number).map(|_| self.generate()).collect()
+    }
+
+    /// Generates a uniformly distributed rotation matrix.
+    ///
+    /// # Returns
+    ///
+    /// An orthogonal 3 x 3 matrix with determinant 1 in row-major order.
+    pub fn generate_matrix(&mut self) -> [[f64; 3]; 3] {
+        let quaternion: [f64; 4] = self.generate();
+        RandomRotation::to_matrix(quaternion)
+    }
+
+    /// Converts a unit quaternion to its rotation matrix.
+    ///
+    /// ```text
+    ///     | 1 - 2(y² + z²)   2(xy - wz)       2(xz + wy)     |
+    /// R = | 2(xy + wz)       1 - 2(x² + z²)   2(yz - wx)     |
+    ///     | 2(xz - wy)       2(yz + wx)       1 - 2(x² + y²) |
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `quaternion` - A unit quaternion `[w, x, y, z]`.
+    ///
+    /// # Returns
+    ///
+    /// The rotation matrix in row-major order.
+    pub fn to_matrix(quaternion: [f64; 4]) -> [[f64; 3]; 3] {
+        let [w, x, y, z] = quaternion;
+
+        [
+            [
+                1_f64 - 2_f64 * (y * y + z * z),
+                2_f64 * (x * y - w * z),
+                2_f64 * (x * z + w * y),
+            ],
+            [
+                2_f64 * (x * y + w * z),
+                1_f64 - 2_f64 * (x * x + z * z),
+                2_f64 * (y * z - w * x),
+            ],
+            [
+                2_f64 * (x * z - w * y),
+                2_f64 * (y * z + w * x),
+                1_f64 - 2_f64 * (x * x + y * y),
+            ],
+        ]
+    }
+}
@@ -0,0 +1,191 @@
+//! This module contains the implementation of the `RandomTree` struct and its methods.
+
+use crate::auto_seed_methods;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating random trees on the nodes `0, ..., n - 1`.
+///
+/// Labeled trees are generated uniformly among all `n^(n - 2)` labeled trees by decoding a uniformly distributed Prüfer sequence.
+/// Random binary search trees are generated as treaps, i.e. as the Cartesian tree of uniformly distributed priorities.
+/// This has the same distribution as inserting the keys in a uniformly random order.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `n` - The number of nodes. Must be a positive integer.
+#[derive(Debug, Clone)]
+pub struct RandomTree {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The number of nodes.
+    n: usize,
+}
+
+auto_seed_methods!(RandomTree);
+
+impl RandomTree {
+    /// Creates a new `RandomTree` instance with a given number of nodes.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of nodes. It must be a positive integer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RandomTree)` - Returns an instance of `RandomTree` if `n` is positive.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `n` is 0.
+    pub fn new(n: usize) -> Result<RandomTree, RngError> {
+        RngError::check_positive(n as f64)?;
+
+        Ok(RandomTree { rng: Rng::new(), n })
+    }
+
+    /// Generates a uniformly distributed labeled tree.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(usize, usize)>` containing the `n - 1` edges of the tree.
+    pub fn generate(&mut self) -> Vec<(usize, usize)> {
+        let sequence: Vec<usize> = self.generate_prufer();
+        Self::decode_prufer(self.n, &sequence)
+    }
+
+    /// Generates a uniformly distributed Prüfer sequence.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<usize>` of length `n - 2` (or empty for `n <= 2`) with entries in `0..n`.
+    pub fn generate_prufer(&mut self) -> Vec<usize> {
+        (0_usize..self.n.saturating_sub(2_usize))
+            .map(|_| self.index(self.n))
+            .collect()
+    }
+
+    /// Generates uniformly distributed treap priorities for the keys `0, ..., n - 1`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` where the i-th entry is the priority of the key `i`.
+    pub fn generate_priorities(&mut self) -> Vec<f64> {
+        (0_usize..self.n).map(|_| self.rng.generate()).collect()
+    }
+
+    /// Generates a random binary search tree on the keys `0, ..., n - 1`.
+    ///
+    /// The tree is the treap of uniformly distributed priorities, where the key with the smallest priority is the root.
+    /// It is built in `O(n)` with the stack-based Cartesian tree construction.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Option<usize>>` where the i-th entry is the parent of the key `i` and `None` for the root.
+    pub fn generate_bst(&mut self) -> Vec<Option<usize>> {
+        let priorities: Vec<f64> = self.generate_priorities();
+        Self::cartesian_tree(&priorities)
+    }
+
+    /// Generates a uniformly distributed insertion order for the keys `0, ..., n - 1`.
+    ///
+    /// Inserting the keys in this order into an unbalanced binary search tree results in a random binary search tree.
+    /// This uses the Fisher-Yates shuffle.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<usize>` containing a uniformly distributed permutation of `0..n`.
+    pub fn generate_insertion_order(&mut self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0_usize..self.n).collect();
+        for i in (1_usize..self.n).rev() {
+            let j: usize = self.index(i + 1_usize);
+            order.swap(i, j);
+        }
+        order
+    }
+
+    /// Generates a uniformly distributed index in `0..bound`.
+    fn index(&mut self, bound: usize) -> usize {
+        self.rng.gen_below(bound as u64) as usize
+    }
+
+    /// Decodes a Prüfer sequence into the edges of a labeled tree.
+    ///
+    /// This uses the linear time algorithm which keeps track of the smallest leaf with a moving pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of nodes.
+    /// * `sequence` - The Prüfer sequence of length `n - 2`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(usize, usize)>` containing the `n - 1` edges of the tree.
+    fn decode_prufer(n: usize, sequence: &[usize]) -> Vec<(usize, usize)> {
+        if n < 2_usize {
+            return Vec::new();
+        }
+
+        let mut degree: Vec<usize> = vec![1_usize; n];
+        for &node in sequence {
+            degree[node] += 1_usize;
+        }
+
+        let mut edges: Vec<(usize, usize)> = Vec::with_capacity(n - 1_usize);
+        let mut pointer: usize = 0_usize;
+        while degree[pointer] != 1_usize {
+            pointer += 1_usize;
+        }
+        let mut leaf: usize = pointer;
+
+        for &node in sequence {
+            edges.push((leaf, node));
+            degree[node] -= 1_usize;
+            if degree[node] == 1_usize && node < pointer {
+                leaf = node;
+            } else {
+                pointer += 1_usize;
+                while degree[pointer] != 1_usize {
+                    pointer += 1_usize;
+                }
+                leaf = pointer;
+            }
+        }
+
+        // The last remaining leaf is always connected to the largest node
+        edges.push((leaf, n - 1_usize));
+        edges
+    }
+
+    /// Builds the Cartesian tree (min-heap ordered by priority) of a sequence of priorities.
+    ///
+    /// # Arguments
+    ///
+    /// * `priorities` - The priorities of the keys `0, ..., n - 1`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Option<usize>>` where the i-th entry is the parent of the key `i`.
+    fn cartesian_tree(priorities: &[f64]) -> Vec<Option<usize>> {
+        let mut parents: Vec<Option<usize>> = vec![None; priorities.len()];
+        let mut stack: Vec<usize> = Vec::with_capacity(priorities.len());
+
+        for key in 0_usize..priorities.len() {
+            let mut last: Option<usize> = None;
+            while let Some(&top) = stack.last() {
+                if priorities[top] <= priorities[key] {
+                    break;
+                }
+                last = stack.pop();
+            }
+            if let Some(child) = last {
+                parents[child] = Some(key);
+            }
+            if let Some(&top) = stack.last() {
+                parents[key] = Some(top);
+            }
+            stack.push(key);
+        }
+        parents
+    }
+}
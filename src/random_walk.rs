@@ -0,0 +1,154 @@
+//! This module contains the implementation of the `RandomWalk` struct and its methods.
+
+use crate::auto_seed_methods;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating random walks in 1, 2 or 3 dimensions starting at the origin.
+///
+/// Two kinds of walks are supported:
+/// * The simple symmetric walk on the lattice `ℤᵈ`, which moves to one of its `2 d` neighbours with equal probability.
+/// * The Gaussian walk, whose increments are independently Normal distributed in every coordinate.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `dimension` - The dimension of the walk. Must be 1, 2 or 3.
+/// * `std_dev` - The standard deviation of a coordinate of a Gaussian increment, or `None` for a lattice walk.
+#[derive(Debug, Clone)]
+pub struct RandomWalk {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The dimension of the walk.
+    dimension: usize,
+
+    /// The standard deviation of the Gaussian increments.
+    std_dev: Option<f64>,
+}
+
+auto_seed_methods!(RandomWalk);
+
+impl RandomWalk {
+    /// Creates a new simple symmetric `RandomWalk` instance on the lattice.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - A `usize` representing the dimension of the walk. It must be 1, 2 or 3.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RandomWalk)` - Returns an instance of `RandomWalk` if the dimension is valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if `dimension` is not 1, 2 or 3.
+    pub fn lattice(dimension: usize) -> Result<RandomWalk, RngError> {
+        RngError::check_interval(dimension as f64, 1_f64, 3_f64)?;
+
+        Ok(RandomWalk {
+            rng: Rng::new(),
+            dimension,
+            std_dev: None,
+        })
+    }
+
+    /// Creates a new `RandomWalk` instance with Gaussian increments.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - A `usize` representing the dimension of the walk. It must be 1, 2 or 3.
+    /// * `std_dev` - A `f64` representing the standard deviation of every coordinate of an increment. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RandomWalk)` - Returns an instance of `RandomWalk` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if `dimension` is not 1, 2 or 3 or a `PositiveError` if `std_dev` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn gaussian(dimension: usize, std_dev: f64) -> Result<RandomWalk, RngError> {
+        RngError::check_finite(std_dev)?;
+        RngError::check_interval(dimension as f64, 1_f64, 3_f64)?;
+        RngError::check_positive(std_dev)?;
+
+        Ok(RandomWalk {
+            rng: Rng::new(),
+            dimension,
+            std_dev: Some(std_dev),
+        })
+    }
+
+    /// Generates a single increment of the walk.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `dimension`.
+    pub fn generate(&mut self) -> Vec<f64> {
+        match self.std_dev {
+            None => {
+                let mut step: Vec<f64> = vec![0_f64; self.dimension];
+                let directions: usize = 2_usize * self.dimension;
+                let direction: usize = self.rng.gen_below(directions as u64) as usize;
+
+                step[direction / 2_usize] = if direction.is_multiple_of(2_usize) {
+                    1_f64
+                } else {
+                    -1_f64
+                };
+                step
+            }
+            Some(std_dev) => (0_usize..self.dimension)
+                .map(|_| std_dev * self.rng.gen_standard_normal())
+                .collect(),
+        }
+    }
+
+    /// Generates a path of the walk.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of steps.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Vec<f64>>` of length `n + 1` containing the positions, starting with the origin.
+    pub fn path(&mut self, n: usize) -> Vec<Vec<f64>> {
+        let mut path: Vec<Vec<f64>> = Vec::with_capacity(n + 1_usize);
+        let mut position: Vec<f64> = vec![0_f64; self.dimension];
+        path.push(position.clone());
+
+        for _ in 0_usize..n {
+            self.add_step(&mut position);
+            path.push(position.clone());
+        }
+        path
+    }
+
+    /// Generates the position of the walk after a number of steps.
+    ///
+    /// Unlike `path`, the intermediate positions are not stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of steps.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `dimension` representing the final position.
+    pub fn final_position(&mut self, n: usize) -> Vec<f64> {
+        let mut position: Vec<f64> = vec![0_f64; self.dimension];
+
+        for _ in 0_usize..n {
+            self.add_step(&mut position);
+        }
+        position
+    }
+
+    /// Adds a single increment to a given position.
+    fn add_step(&mut self, position: &mut [f64]) {
+        let step: Vec<f64> = self.generate();
+
+        for (coordinate, increment) in position.iter_mut().zip(step) {
+            *coordinate += increment;
+        }
+    }
+}
@@ -1,7 +1,14 @@
 //! This module contains the implementation of the `Rayleigh` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::mean;
 use crate::auxiliary::simple_ln;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -15,6 +22,7 @@ use crate::rng_error::RngError;
 ///
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `scale` - The scale (σ) of the Rayleigh distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Rayleigh {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -24,6 +32,9 @@ pub struct Rayleigh {
 }
 
 auto_rng_trait!(Rayleigh);
+auto_builder!(Rayleigh, RayleighBuilder { scale: f64 });
+auto_params!(Rayleigh { scale: f64 });
+auto_display!(Rayleigh { "σ" = scale });
 
 impl Rayleigh {
     /// Creates a new `Rayleigh` instance with a given rate.
@@ -37,8 +48,9 @@ impl Rayleigh {
     /// # Returns
     ///
     /// * `Ok(Rayleigh)` - Returns an instance of `Rayleigh` if the `scale` are positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the `scale` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(scale: f64) -> Result<Rayleigh, RngError> {
+        RngError::check_finite(scale)?;
         RngError::check_positive(scale)?;
 
         Ok(Rayleigh {
@@ -67,4 +79,130 @@ impl Rayleigh {
 
         self.scale * (-2_f64 * simple_ln(uni)).sqrt()
     }
+
+    /// Estimates the scale of a `Rayleigh` distribution from samples using maximum likelihood.
+    ///
+    /// The estimator is
+    /// ```text
+    /// σ = sqrt(1/(2n) Σ x_i²)
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of non-negative `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Rayleigh)` - Returns the fitted instance of `Rayleigh`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty, a `NonNegativeError` if a sample is negative or a `PositiveError` if all samples are 0.
+    pub fn fit(samples: &[f64]) -> Result<Rayleigh, RngError> {
+        RngError::check_empty(samples)?;
+        for &sample in samples {
+            RngError::check_non_negative(sample)?;
+        }
+
+        let squares: Vec<f64> = samples.iter().map(|x| x * x).collect();
+        Rayleigh::new((0.5_f64 * mean(&squares)).sqrt())
+    }
+}
+
+impl Default for Rayleigh {
+    /// Creates a standard `Rayleigh` instance with scale 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Rayleigh::new(1_f64).unwrap()
+    }
+}
+
+impl Quantile for Rayleigh {
+    /// Evaluates the quantile function of the Rayleigh distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = scale sqrt(-2 ln(1 - p))
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.scale * (-2_f64 * math::ln(1_f64 - p)).sqrt()
+    }
+}
+
+impl Density for Rayleigh {
+    /// Evaluates the density of the Rayleigh distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = x / σ² exp(-x² / (2 σ²))    for x >= 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            return 0_f64;
+        }
+
+        let variance: f64 = self.scale * self.scale;
+        x / variance * math::exp(-0.5_f64 * x * x / variance)
+    }
+
+    /// Evaluates the logarithm of the density of the Rayleigh distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln x - 2 ln σ - x² / (2 σ²)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            return f64::NEG_INFINITY;
+        }
+
+        let variance: f64 = self.scale * self.scale;
+        math::ln(x) - math::ln(variance) - 0.5_f64 * x * x / variance
+    }
+
+    /// Evaluates the distribution function of the Rayleigh distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 1 - exp(-x² / (2 σ²))    for x >= 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            0_f64
+        } else {
+            1_f64 - math::exp(-0.5_f64 * x * x / (self.scale * self.scale))
+        }
+    }
 }
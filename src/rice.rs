@@ -0,0 +1,123 @@
+//! This module contains the implementation of the `Rice` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::math;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a Rice distribution.
+///
+/// The Rice distribution is the distribution of the length of a two-dimensional vector
+/// whose components are normal distributed with standard deviation `σ` around a point of distance `ν` from the origin.
+/// In wireless communication it models the amplitude of a signal with a line-of-sight component (Rician fading).
+/// For `ν = 0` it is the Rayleigh distribution with scale `σ`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `nu` - The distance (ν) of the center from the origin. Must be non-negative.
+/// * `sigma` - The standard deviation (σ) of the components. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct Rice {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The distance (ν) of the center from the origin.
+    nu: f64,
+
+    /// The standard deviation (σ) of the components.
+    sigma: f64,
+}
+
+auto_rng_trait!(Rice);
+auto_builder!(
+    Rice,
+    RiceBuilder {
+        nu: f64,
+        sigma: f64
+    }
+);
+auto_params!(Rice {
+    nu: f64,
+    sigma: f64
+});
+auto_display!(Rice { "ν" = nu, "σ" = sigma });
+
+impl Rice {
+    /// Creates a new `Rice` instance with a given distance and standard deviation.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `nu` - A `f64` representing the distance (ν) of the center from the origin. It must be non-negative.
+    /// * `sigma` - A `f64` representing the standard deviation (σ) of the components. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Rice)` - Returns an instance of `Rice` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `NonNegativeError` if `nu` is negative or a `PositiveError` if `sigma` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(nu: f64, sigma: f64) -> Result<Rice, RngError> {
+        RngError::check_finite(nu)?;
+        RngError::check_finite(sigma)?;
+        RngError::check_non_negative(nu)?;
+        RngError::check_positive(sigma)?;
+
+        Ok(Rice {
+            rng: Rng::new(),
+            nu,
+            sigma,
+        })
+    }
+
+    /// Creates a new `Rice` instance from the K-factor and the mean power, the usual parameters of fading channels.
+    ///
+    /// The K-factor is the ratio of the power of the line-of-sight component and the scattered components,
+    /// so the parameters are
+    /// ```text
+    /// ν² = K Ω / (K + 1)
+    /// σ² = Ω / (2 (K + 1))
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - A `f64` representing the K-factor. It must be non-negative.
+    /// * `omega` - A `f64` representing the mean power `Ω = E[X²]`. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Rice)` - Returns an instance of `Rice` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `NonNegativeError` if `k` is negative or a `PositiveError` if `omega` is less than or equal to 0.
+    pub fn from_k_factor(k: f64, omega: f64) -> Result<Rice, RngError> {
+        RngError::check_non_negative(k)?;
+        RngError::check_positive(omega)?;
+
+        Rice::new(
+            (k * omega / (k + 1_f64)).sqrt(),
+            (omega / (2_f64 * (k + 1_f64))).sqrt(),
+        )
+    }
+
+    /// Generates a random value from the Rice distribution.
+    ///
+    /// This uses the definition
+    /// ```text
+    /// X = sqrt((ν + σ Z₁)² + (σ Z₂)²)
+    /// ```
+    /// where `Z₁` and `Z₂` are independently standard normal distributed.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the Rice distribution.
+    pub fn generate(&mut self) -> f64 {
+        let x: f64 = self.nu + self.sigma * self.rng.gen_standard_normal();
+        let y: f64 = self.sigma * self.rng.gen_standard_normal();
+
+        math::hypot(x, y)
+    }
+}
@@ -1,15 +1,37 @@
 //! This module contains the implementation of the `Rng` struct and its methods.
 
+use crate::ascii::render_ascii_histogram;
 use crate::auxiliary::simple_ln;
+use crate::hashing::fnv1a;
+use crate::math;
+
+/// The number of columns of the histogram printed by `RngTrait::preview`.
+const PREVIEW_WIDTH: usize = 60_usize;
+
+/// The number of rows of the histogram printed by `RngTrait::preview`.
+const PREVIEW_HEIGHT: usize = 15_usize;
+
+/// The number of trials or the rate up to which `Rng::gen_binomial` and `Rng::gen_poisson` simulate directly.
+const SMALL_COUNT: u64 = 16_u64;
+
+/// The smallest running product of uniform values in `Rng::gen_erlang` before its logarithm is added to the sum, i.e. `2^(-969)`.
+///
+/// A further factor of at least `2^(-53)` keeps the product above the smallest normal `f64`, so it never underflows.
+const MIN_PRODUCT: f64 = f64::MIN_POSITIVE * (1_u64 << 53_u32) as f64;
 
 /// A struct for generating random variables from a uniform distribution between 0 and 1.
 ///
 /// This struct implements a simple Linear Congruential Generator (LCG) to generate random numbers.
 /// The numbers are generated based on a seed value,
-/// and each call to generate a new number produces a pseudo-random number between 0 and 1 (inclusive).
+/// and each call to generate a new number produces a pseudo-random number between 0 (inclusive) and 1 (exclusive).
 ///
 /// The `Rng` is not cryptographically secure, and if the same seed is used,
 /// the same sequence of random numbers will be generated.
+///
+/// Cloning a `Rng` copies its seed and state, so the clone generates the same numbers as the original.
+/// The same holds for all distributions, which clone their generator along with their parameters.
+/// For an independent stream, the clone has to be given a new seed, e.g. one from `Rng::derive_seed`.
+#[derive(Debug, Clone)]
 pub struct Rng {
     /// The seed of the random number generator.
     ///
@@ -18,14 +40,23 @@ pub struct Rng {
 
     /// The current state of the random number generator.
     ///
-    /// Initially, the state is set to the seed value, and it evolves with each call to `next()`.
+    /// Initially, the state is the seed mixed by `Rng::initial_state`, and it evolves with each call to `next()`.
     pub state: u64,
 
+    /// The number of values discarded after seeding or restarting the generator.
+    burn_in: u64,
+
     /// A cached value from a standard normal distribution.
     ///
     /// The here used Marsaglia-Polar-Method generates two random values at a time.
     /// To safe on time if one is generated the other is stored in this attribute.
     cached_normal: Option<f64>,
+
+    /// The number of steps of the generator since the seed was set.
+    ///
+    /// This is only counted with the `draw-count` feature, so the generator is not slowed down otherwise.
+    #[cfg(feature = "draw-count")]
+    draws: u64,
 }
 
 impl Rng {
@@ -37,25 +68,30 @@ impl Rng {
     /// It is used in the `next` method.
     const C: u64 = 1_u64;
 
-    /// The inverse of `u64::MAX`, used to scale the output to a value between 0 and 1.
-    const INV_U64_MAX: f64 = 1_f64 / u64::MAX as f64;
+    /// The scale of the high 53 bits to a value between 0 and 1, i.e. `2^(-53)`.
+    const INV_2_POW_53: f64 = 1_f64 / (1_u64 << 53_u32) as f64;
+
+    /// The golden ratio scaled to 64 bits, used as the increment in `derive_seed` and `initial_state`.
+    const GOLDEN_GAMMA: u64 = 0x9E3779B97F4A7C15_u64;
 }
 
 impl Rng {
     /// Creates a new `Rng` instance using the system time as the seed.
     ///
-    /// This method uses the current system time (in nanoseconds) as the seed for the RNG.
+    /// This method mixes the current system time (in nanoseconds) with a process-wide counter and a stack address,
+    /// so generators created in the same nanosecond still get different seeds.
+    /// With the `wasm` feature the seed is taken from the entropy source of the platform instead,
+    /// e.g. `crypto.getRandomValues` in the browser, see `Rng::entropy_seed`.
     ///
     /// # Returns
     ///
-    /// A new `Rng` instance initialized with the current system time as the seed.
+    /// A new `Rng` instance initialized with a seed derived from the current system time.
     ///
     /// # Warnings
     ///
     /// Because the seed is generated based on the system time, the random number generator is **not cryptographically secure**.
-    /// Programs started in the same nanosecond may generate the same sequence of random numbers.
     pub fn new() -> Self {
-        Self::new_seed(Self::current_time())
+        Self::new_seed(Self::entropy_seed())
     }
 
     /// Creates a new `Rng` instance using a specified seed.
@@ -73,21 +109,146 @@ impl Rng {
     pub fn new_seed(seed: u64) -> Self {
         Self {
             seed,
-            state: seed,
+            state: Self::initial_state(seed),
+            burn_in: 0_u64,
             cached_normal: None,
+            #[cfg(feature = "draw-count")]
+            draws: 0_u64,
         }
     }
 
-    /// Generates a uniformly distributed random number in the range [0, 1].
+    /// Creates a new `Rng` instance seeded by a label, e.g. `Rng::new_from_str("experiment-42")`.
+    ///
+    /// The seed is calculated by `Rng::seed_from_str`, so the same label always results in the same sequence
+    /// and experiments can be named instead of numbered.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A `&str` naming the sequence.
+    ///
+    /// # Returns
+    ///
+    /// A new `Rng` instance initialized with the seed of the label.
+    pub fn new_from_str(label: &str) -> Self {
+        Self::new_seed(Self::seed_from_str(label))
+    }
+
+    /// Calculates the seed belonging to a label.
+    ///
+    /// The UTF-8 bytes of the label are hashed by FNV-1a and the hash is mixed with the SplitMix64 finalizer,
+    /// so labels differing in a single character result in unrelated seeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A `&str` to hash.
+    ///
+    /// # Returns
+    ///
+    /// The seed as a `u64`.
+    pub fn seed_from_str(label: &str) -> u64 {
+        Self::mix64(fnv1a(label.as_bytes()))
+    }
+
+    /// Derives a child `Rng` for a label from the seed of this generator.
     ///
-    /// This method generates a random `u64` value using the `next` method,
-    /// and then scales it to a floating-point number between 0 and 1 by dividing by `u64::MAX`.
+    /// The seed of the child is `Rng::derive_seed(seed, h)`, where `h` is the FNV-1a hash of the label.
+    /// It only depends on the seed and the label, not on the current state,
+    /// so e.g. `rng.derive("arrivals")` and `rng.derive("service")` are reproducible independent streams.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A `&str` naming the child stream.
     ///
     /// # Returns
     ///
-    /// A random `f64` value in the range [0, 1].
+    /// A new `Rng` instance with the derived seed.
+    pub fn derive(&self, label: &str) -> Rng {
+        Rng::new_seed(Self::derive_seed(self.seed, fnv1a(label.as_bytes())))
+    }
+
+    /// Generates a uniformly distributed random number in the range [0, 1).
+    ///
+    /// The low bits of a LCG with a power of two modulus have short periods, e.g. the lowest bit alternates.
+    /// Therefore, only the high 53 bits of the next state are scaled by `2^(-53)`,
+    /// so every value is a multiple of `2^(-53)` and exactly representable as `f64`.
+    ///
+    /// # Returns
+    ///
+    /// A random `f64` value in the range [0, 1).
     pub fn generate(&mut self) -> f64 {
-        self.next() as f64 * Self::INV_U64_MAX
+        (self.next() >> 11_u32) as f64 * Self::INV_2_POW_53
+    }
+
+    /// Fills a given slice with uniformly distributed random numbers in the range [0, 1).
+    ///
+    /// The slice can be reused across iterations, so high-throughput loops do not allocate.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A mutable slice of `f64` which is overwritten completely.
+    pub fn fill(&mut self, buffer: &mut [f64]) {
+        for random in buffer.iter_mut() {
+            *random = self.generate();
+        }
+    }
+
+    /// Generates a fixed number of uniformly distributed random numbers in the range [0, 1) on the stack.
+    ///
+    /// Unlike collecting into a `Vec`, this does not allocate, e.g. for embedded systems or game loops.
+    ///
+    /// # Returns
+    ///
+    /// An array of `N` random `f64` values in the range [0, 1).
+    pub fn generate_array<const N: usize>(&mut self) -> [f64; N] {
+        let mut randoms: [f64; N] = [0_f64; N];
+        self.fill(&mut randoms);
+        randoms
+    }
+
+    /// Generates a uniformly distributed random `u64`.
+    ///
+    /// The low bits of a LCG with a power of two modulus have short periods.
+    /// Therefore, the value is assembled from the high 32 bits of two consecutive states and consumes two steps.
+    ///
+    /// # Returns
+    ///
+    /// A random `u64` value.
+    pub fn next_u64(&mut self) -> u64 {
+        let high: u64 = self.next() >> 32_u32;
+        let low: u64 = self.next() >> 32_u32;
+        (high << 32_u32) | low
+    }
+
+    /// Generates a uniformly distributed random `u32`.
+    ///
+    /// This is the high half of the next state, avoiding the weak low bits of the LCG.
+    ///
+    /// # Returns
+    ///
+    /// A random `u32` value.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next() >> 32_u32) as u32
+    }
+
+    /// Generates a uniformly distributed integer between 0 and `bound - 1`.
+    ///
+    /// Raw values in the top `2^64 mod bound` values are rejected, so every integer has exactly the same probability.
+    ///
+    /// # Arguments
+    ///
+    /// * `bound` - A positive `u64` representing the number of possible values.
+    ///
+    /// # Returns
+    ///
+    /// A random `u64` value smaller than `bound`.
+    pub(crate) fn gen_below(&mut self, bound: u64) -> u64 {
+        let zone: u64 = u64::MAX - (u64::MAX % bound + 1_u64) % bound;
+
+        let mut raw: u64 = self.next_u64();
+        while raw > zone {
+            raw = self.next_u64();
+        }
+        raw % bound
     }
 
     /// Returns the seed used to initialize the random number generator.
@@ -99,6 +260,162 @@ impl Rng {
         self.seed
     }
 
+    /// Returns the number of steps of the generator since the seed was set or the generator was restarted.
+    ///
+    /// Every uniformly distributed number consumes one step, `next_u64` consumes two.
+    /// Jumping ahead counts the skipped steps.
+    /// This is the position in the random number sequence,
+    /// so comparing it between two simulations shows whether they are still synchronized.
+    ///
+    /// This method requires the `draw-count` feature.
+    ///
+    /// # Returns
+    ///
+    /// The number of steps as a `u64`.
+    #[cfg(feature = "draw-count")]
+    pub fn draw_count(&self) -> u64 {
+        self.draws
+    }
+
+    /// Advances the random number generator as if a given number of values had been generated.
+    ///
+    /// The LCG can be advanced by `k` steps in `O(log k)` time, because `k` steps are again an affine map
+    /// ```text
+    /// state = (state * A^k + C (A^k - 1) / (A - 1)) % u64::MAX
+    /// ```
+    /// whose coefficients are computed by repeated squaring.
+    /// Jumping by `u64::MAX` steps and one more step returns to the same state, as the period is `2^64`.
+    ///
+    /// This method will automatically reset the `cached_normal` attribute to the `None` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - A `u64` representing the number of values to skip.
+    pub fn jump(&mut self, steps: u64) {
+        let mut multiplier: u64 = 1_u64;
+        let mut increment: u64 = 0_u64;
+        let mut step_multiplier: u64 = Self::A;
+        let mut step_increment: u64 = Self::C;
+        let mut remaining: u64 = steps;
+
+        while remaining > 0_u64 {
+            if remaining & 1_u64 == 1_u64 {
+                multiplier = multiplier.wrapping_mul(step_multiplier);
+                increment = increment
+                    .wrapping_mul(step_multiplier)
+                    .wrapping_add(step_increment);
+            }
+            step_increment = step_multiplier
+                .wrapping_add(1_u64)
+                .wrapping_mul(step_increment);
+            step_multiplier = step_multiplier.wrapping_mul(step_multiplier);
+            remaining >>= 1_u32;
+        }
+
+        self.state = multiplier.wrapping_mul(self.state).wrapping_add(increment);
+        #[cfg(feature = "draw-count")]
+        {
+            self.draws = self.draws.wrapping_add(steps);
+        }
+        self.cached_normal = None;
+    }
+
+    /// Derives the seed of an independent substream from a master seed.
+    ///
+    /// The master seed and the index of the substream are mixed with the SplitMix64 finalizer:
+    /// ```text
+    /// z = seed + (index + 1) γ
+    /// z = (z ⊕ (z >> 30)) 0xBF58476D1CE4E5B9
+    /// z = (z ⊕ (z >> 27)) 0x94D049BB133111EB
+    /// z = z ⊕ (z >> 31)
+    /// ```
+    /// where `γ` is the golden ratio scaled to 64 bits.
+    /// Neighbouring indices and master seeds therefore result in unrelated seeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` representing the master seed.
+    /// * `index` - A `u64` representing the index of the substream.
+    ///
+    /// # Returns
+    ///
+    /// The derived seed as a `u64`.
+    pub fn derive_seed(seed: u64, index: u64) -> u64 {
+        Self::mix64(seed.wrapping_add(index.wrapping_add(1_u64).wrapping_mul(Self::GOLDEN_GAMMA)))
+    }
+
+    /// Mixes the bits of a `u64` using the SplitMix64 finalizer.
+    ///
+    /// This is a bijection of the `u64` values, where every input bit affects every output bit.
+    pub(crate) fn mix64(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30_u32)).wrapping_mul(0xBF58476D1CE4E5B9_u64);
+        z = (z ^ (z >> 27_u32)).wrapping_mul(0x94D049BB133111EB_u64);
+        z ^ (z >> 31_u32)
+    }
+
+    /// Returns the initial state of the LCG for a seed.
+    ///
+    /// The seed is offset by `γ`, the golden ratio scaled to 64 bits, and mixed with the SplitMix64 finalizer,
+    /// see `Rng::derive_seed`. The offset moves the seed 0 away from the fixed point 0 of the finalizer.
+    /// Without the mixing, small seeds like 0, 1 and 2 start in neighbouring states,
+    /// whose first outputs are visibly correlated, as the LCG only slowly spreads differences in the low bits.
+    /// The mixing is a bijection, so distinct seeds still result in distinct initial states.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` representing the seed.
+    ///
+    /// # Returns
+    ///
+    /// The initial state as a `u64`.
+    pub fn initial_state(seed: u64) -> u64 {
+        Self::mix64(seed.wrapping_add(Self::GOLDEN_GAMMA))
+    }
+
+    /// Returns the seed whose initial state is a given state, i.e. the inverse of `Rng::initial_state`.
+    ///
+    /// This allows seeding a generator at any position of the sequence of another generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - A `u64` representing the initial state.
+    ///
+    /// # Returns
+    ///
+    /// The seed as a `u64`.
+    pub fn seed_of_state(state: u64) -> u64 {
+        let mut z: u64 = state;
+        z ^= (z >> 31_u32) ^ (z >> 62_u32);
+        z = z.wrapping_mul(0x319642B2D24D8EC3_u64);
+        z ^= (z >> 27_u32) ^ (z >> 54_u32);
+        z = z.wrapping_mul(0x96DE1B173F119089_u64);
+        z ^= (z >> 30_u32) ^ (z >> 60_u32);
+        z.wrapping_sub(Self::GOLDEN_GAMMA)
+    }
+
+    /// Returns the number of values discarded after seeding or restarting the generator.
+    ///
+    /// # Returns
+    ///
+    /// The burn-in as a `u64`.
+    pub fn burn_in(&self) -> u64 {
+        self.burn_in
+    }
+
+    /// Sets the number of values discarded after seeding or restarting the generator and restarts it.
+    ///
+    /// The values are skipped with `Rng::jump` in logarithmic time and are not counted as draws.
+    /// Some simulations discard the first values of a stream as a warm-up,
+    /// which this makes reproducible across `set_seed` and `restart`.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - A `u64` representing the number of discarded values.
+    pub fn set_burn_in(&mut self, steps: u64) {
+        self.burn_in = steps;
+        self.restart();
+    }
+
     /// Sets the seed of the random number generator to a given number.
     ///
     /// This method will automatically reset the `cached_normal` attribute to the `None` variant.
@@ -108,19 +425,23 @@ impl Rng {
     /// * seed - A `u64` representing the new seed.
     pub fn set_seed(&mut self, seed: u64) {
         self.seed = seed;
-        self.state = seed;
-        self.cached_normal = None;
+        self.restart();
     }
 
     /// Resets the random number generator to start from the beginning using the initial seed.
     ///
-    /// This method sets the state of the `Rng` back to the value of the seed,
+    /// This method sets the state of the `Rng` back to the mixed seed and discards the burn-in values,
     /// so the random number sequence starts over.
     ///
     /// Additionally, this method will reset the `cached_normal` attribute to the `None` variant.
     pub fn restart(&mut self) {
-        self.state = self.seed;
+        self.state = Self::initial_state(self.seed);
+        self.jump(self.burn_in);
         self.cached_normal = None;
+        #[cfg(feature = "draw-count")]
+        {
+            self.draws = 0_u64;
+        }
     }
 
     /// Generates the next random `u64` value in the sequence using the linear congruential generator (LCG).
@@ -136,25 +457,70 @@ impl Rng {
     ///
     /// The next random value in the sequence as a `u64`
     fn next(&mut self) -> u64 {
+        #[cfg(feature = "draw-count")]
+        {
+            self.draws = self.draws.wrapping_add(1_u64);
+        }
         self.state = Self::A.wrapping_mul(self.state).wrapping_add(Self::C);
         self.state
     }
 
+    /// Returns the seed used by `Rng::new()` from the best available source.
+    ///
+    /// With the `wasm` feature the seed is taken from the entropy source of the platform through `getrandom`.
+    /// Otherwise, or if it fails, the seed mixes three values:
+    /// * `t` - The current system time in nanoseconds since the UNIX epoch, 0 on `wasm32-unknown-unknown`, which has no clock.
+    /// * `a` - A stack address, which differs between runs if the addresses are randomized.
+    /// * `c` - A process-wide counter of the calls.
+    ///
+    /// The seed is `Rng::derive_seed(t ⊕ mix64(a), c)`.
+    /// As `derive_seed` is a bijection for a fixed index, generators created in the same nanosecond,
+    /// e.g. by constructing several distributions in a row, get different seeds.
+    /// Seeding never panics, even in the browser without the `wasm` feature.
+    ///
+    /// # Returns
+    ///
+    /// The seed as a `u64`.
+    fn entropy_seed() -> u64 {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        /// The number of seeds created from the time in this process.
+        static COUNTER: AtomicU64 = AtomicU64::new(0_u64);
+
+        #[cfg(feature = "wasm")]
+        if let Ok(seed) = getrandom::u64() {
+            return seed;
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        let time: u64 = Self::current_time().unwrap_or(0_u64);
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        let time: u64 = 0_u64;
+
+        let marker: u8 = 0_u8;
+        let address: u64 = std::ptr::addr_of!(marker) as usize as u64;
+        Self::derive_seed(
+            time ^ Self::mix64(address),
+            COUNTER.fetch_add(1_u64, Ordering::Relaxed),
+        )
+    }
+
     /// Returns the current system time in nanoseconds since the UNIX epoch.
     ///
     /// This is used internally to generate the seed when calling `Rng::new()`.
     ///
     /// # Returns
     ///
-    /// The current system time in nanoseconds since the UNIX epoch as a `u64`.
-    fn current_time() -> u64 {
+    /// * `Some(u64)` - The current system time in nanoseconds since the UNIX epoch.
+    /// * `None` - If the system clock is set before the UNIX epoch.
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    fn current_time() -> Option<u64> {
         use std::time::{SystemTime, UNIX_EPOCH};
 
-        let duration = SystemTime::now()
+        SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards.");
-
-        duration.as_nanos() as u64
+            .ok()
+            .map(|duration| duration.as_nanos() as u64)
     }
 
     /// Generates a random value from the standard Normal distribution.
@@ -197,16 +563,236 @@ impl Rng {
         }
 
         // Generate a new pair of values
+        let (x, y): (f64, f64) = self.gen_standard_normal_pair();
+        self.cached_normal = Some(y);
+        x
+    }
+
+    /// Fills a given slice with random values from the standard Normal distribution.
+    ///
+    /// Both values of every pair of the Marsaglia polar method are written directly into the slice,
+    /// so apart from its first and last value the cache is neither read nor written.
+    /// The slice receives exactly the values of as many calls to `gen_standard_normal`,
+    /// i.e. a cached value is used first and the second value of an incomplete last pair is cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A mutable slice of `f64` which is overwritten completely.
+    pub fn fill_normal(&mut self, buffer: &mut [f64]) {
+        let mut rest: &mut [f64] = buffer;
+        if let Some(first) = rest.first_mut()
+            && let Some(normal) = self.cached_normal.take()
+        {
+            *first = normal;
+            rest = &mut rest[1_usize..];
+        }
+
+        let mut pairs = rest.chunks_exact_mut(2_usize);
+        for pair in pairs.by_ref() {
+            (pair[0_usize], pair[1_usize]) = self.gen_standard_normal_pair();
+        }
+        if let [last] = pairs.into_remainder() {
+            *last = self.gen_standard_normal();
+        }
+    }
+
+    /// Generates a vector of random values from the standard Normal distribution.
+    ///
+    /// The values are generated by `fill_normal`, so they equal `n` calls to `gen_standard_normal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of values.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `n` values from the standard Normal distribution.
+    pub fn gen_standard_normal_batch(&mut self, n: usize) -> Vec<f64> {
+        let mut normals: Vec<f64> = vec![0_f64; n];
+        self.fill_normal(&mut normals);
+        normals
+    }
+
+    /// Generates a pair of independent values from the standard Normal distribution with the Marsaglia polar method.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(X, Y)` of two independent standard normally distributed values.
+    fn gen_standard_normal_pair(&mut self) -> (f64, f64) {
         loop {
             let u: f64 = 2_f64 * self.generate() - 1_f64;
             let v: f64 = 2_f64 * self.generate() - 1_f64;
             let s: f64 = u.powi(2_i32) + v.powi(2_i32);
             if s < 1_f64 {
                 let factor: f64 = (-2_f64 * simple_ln(s) / s).sqrt();
-                self.cached_normal = Some(v * factor);
-                return u * factor;
+                return (u * factor, v * factor);
+            }
+        }
+    }
+
+    /// Generates a random value from the Gamma distribution with an integer shape and scale 1, i.e. the Erlang distribution.
+    ///
+    /// This uses the fact that Gamma(1, 1) ~ Exp(1) and
+    /// ```text
+    /// Gamma(n, 1) = Exp(1) + ... + Exp(1) = - ln(U_1) - ... - ln(U_n)
+    /// ```
+    /// Because the evaluation of a natural logarithm is comparably slow, the uniform values are multiplied
+    /// and only the logarithm of the product is added to the sum once it falls below `MIN_PRODUCT`,
+    /// about every 670 values. So the product never underflows to 0, which would give an infinite value for large `n`.
+    /// The sum consists of few similar terms, so it needs no compensated summation.
+    /// `1 - U` is used in place of `U`, which is never 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `u64` representing the shape, i.e. the number of exponentially distributed summands.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the Gamma(n, 1) distribution.
+    pub fn gen_erlang(&mut self, n: u64) -> f64 {
+        let mut sum: f64 = 0_f64;
+        let mut product: f64 = 1_f64;
+
+        for _ in 0_u64..n {
+            product *= 1_f64 - self.generate();
+            if product < MIN_PRODUCT {
+                sum -= math::ln(product);
+                product = 1_f64;
             }
         }
+        sum - math::ln(product)
+    }
+
+    /// Generates a random value from the Gamma distribution with a given shape and scale 1.
+    ///
+    /// For `shape >= 1` this uses the method of Marsaglia and Tsang:
+    /// ```text
+    /// d = shape - 1/3
+    /// c = 1 / sqrt(9 d)
+    /// V = (1 + c Z)³
+    /// ```
+    /// where `Z` is standard normal distributed.
+    /// The value `X = d V` is accepted if `ln U < Z²/2 + d - d V + d ln V`, with `U` uniformly distributed on [0, 1].
+    /// The acceptance rate is above 95 % for all shapes.
+    ///
+    /// For `shape < 1` the boost `Gamma(shape) = Gamma(shape + 1) U^(1 / shape)` is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - A positive `f64` representing the shape (α).
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the Gamma(shape, 1) distribution.
+    pub fn gen_standard_gamma(&mut self, shape: f64) -> f64 {
+        if shape < 1_f64 {
+            let boost: f64 = math::powf(self.generate(), 1_f64 / shape);
+            return self.gen_standard_gamma(shape + 1_f64) * boost;
+        }
+
+        let d: f64 = shape - 1_f64 / 3_f64;
+        let c: f64 = 1_f64 / (9_f64 * d).sqrt();
+
+        loop {
+            let z: f64 = self.gen_standard_normal();
+            let cube_root: f64 = 1_f64 + c * z;
+            if cube_root <= 0_f64 {
+                continue;
+            }
+
+            let v: f64 = cube_root * cube_root * cube_root;
+            let uni: f64 = self.generate();
+            if math::ln(uni) < 0.5_f64 * z * z + d - d * v + d * math::ln(v) {
+                return d * v;
+            }
+        }
+    }
+
+    /// Generates a random value from the Binomial distribution with a given number of trials and probability of success.
+    ///
+    /// For more than `SMALL_COUNT` trials the number of trials is halved recursively, following Knuth:
+    /// The `a`-th smallest of `n` uniform values is `X ~ Beta(a, n + 1 - a)` with `a = 1 + n/2`.
+    /// If `X >= p`, the `a - 1` values below `X` are uniform on [0, X], so the count is Binomial(a - 1, p / X) distributed.
+    /// Otherwise `a` successes are certain and the remaining `n - a` values are uniform on [X, 1],
+    /// which leaves Binomial(n - a, (p - X) / (1 - X)).
+    /// The remaining trials are simulated directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `u64` representing the number of trials.
+    /// * `p` - A `f64` between 0 and 1 representing the probability of success.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` value generated from the Binomial(n, p) distribution.
+    pub fn gen_binomial(&mut self, n: u64, p: f64) -> u64 {
+        let mut n: u64 = n;
+        let mut p: f64 = p;
+        let mut successes: u64 = 0_u64;
+
+        while n > SMALL_COUNT {
+            let a: u64 = 1_u64 + n / 2_u64;
+            let b: u64 = n + 1_u64 - a;
+            let x: f64 = self.gen_standard_gamma(a as f64);
+            let y: f64 = self.gen_standard_gamma(b as f64);
+            let order_statistic: f64 = x / (x + y);
+
+            if order_statistic >= p {
+                n = a - 1_u64;
+                p /= order_statistic;
+            } else {
+                successes += a;
+                n = b - 1_u64;
+                p = (p - order_statistic) / (1_f64 - order_statistic);
+            }
+        }
+
+        for _ in 0_u64..n {
+            if self.generate() < p {
+                successes += 1_u64;
+            }
+        }
+        successes
+    }
+
+    /// Generates a random value from the Poisson distribution with a given rate.
+    ///
+    /// For rates above `SMALL_COUNT` the rate is reduced recursively, following Knuth:
+    /// The `m`-th arrival time of a unit rate Poisson process with `m = floor(7/8 λ)` is `G ~ Gamma(m, 1)`.
+    /// If `G < λ`, there are `m` arrivals before `G` and Poisson(λ - G) distributed ones after it.
+    /// Otherwise the arrivals before `λ` are Binomial(m - 1, λ / G) distributed.
+    /// Small rates use Knuth's multiplication of uniform values.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - A non-negative `f64` representing the rate (λ).
+    ///
+    /// # Returns
+    ///
+    /// A `u64` value generated from the Poisson(λ) distribution.
+    pub fn gen_poisson(&mut self, rate: f64) -> u64 {
+        let mut rate: f64 = rate;
+        let mut count: u64 = 0_u64;
+
+        while rate > SMALL_COUNT as f64 {
+            let m: u64 = (0.875_f64 * rate) as u64;
+            let arrival: f64 = self.gen_standard_gamma(m as f64);
+
+            if arrival < rate {
+                count += m;
+                rate -= arrival;
+            } else {
+                return count + self.gen_binomial(m - 1_u64, rate / arrival);
+            }
+        }
+
+        let limit: f64 = math::exp(-rate);
+        let mut product: f64 = self.generate();
+        while product > limit {
+            count += 1_u64;
+            product *= self.generate();
+        }
+        count
     }
 }
 
@@ -218,7 +804,10 @@ impl Rng {
 /// * `restart(&mut self)`
 /// * `reset(&mut self)`
 /// * `set_seed(&mut self, seed: u64)`
+/// * `set_burn_in(&mut self, steps: u64)`
+/// * `fill(&mut self, buffer: &mut [f64])`
 /// * `generate_multiple(&mut self, number: usize) -> Vec<f64>`
+/// * `generate_array<const N: usize>(&mut self) -> [f64; N]`
 ///
 /// # Notes
 ///
@@ -229,7 +818,51 @@ pub trait RngTrait {
     fn restart(&mut self);
     fn reset(&mut self);
     fn set_seed(&mut self, seed: u64);
+    fn set_burn_in(&mut self, steps: u64);
+    fn fill(&mut self, buffer: &mut [f64]);
     fn generate_multiple(&mut self, number: usize) -> Vec<f64>;
+    fn generate_array<const N: usize>(&mut self) -> [f64; N]
+    where
+        Self: Sized;
+
+    /// Returns the number of uniformly distributed numbers consumed since the seed was set or the generator was restarted.
+    ///
+    /// For rejection samplers this shows the average cost of a value.
+    /// This method requires the `draw-count` feature.
+    #[cfg(feature = "draw-count")]
+    fn draws_used(&self) -> u64;
+
+    /// Prints an ASCII histogram of generated values to the standard output.
+    ///
+    /// This is meant for a quick sanity check of a distribution during development.
+    /// The values are drawn from the generator, so it advances by `n` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - A `usize` representing the number of generated values.
+    fn preview(&mut self, n: usize) {
+        let samples: Vec<f64> = self.generate_multiple(n);
+        print!(
+            "{}",
+            render_ascii_histogram(&samples, PREVIEW_WIDTH, PREVIEW_HEIGHT)
+        );
+    }
+}
+
+/// A trait for sources of uniformly distributed numbers between 0 and 1.
+///
+/// Distributions implementing `Quantile` can be driven by any source through `Quantile::sample`,
+/// e.g. the pseudo-random `Rng` or the quasi-random sequences of the `quasi` module.
+pub trait UniformSource {
+    /// Returns the next uniformly distributed number between 0 and 1.
+    fn next_uniform(&mut self) -> f64;
+}
+
+impl UniformSource for Rng {
+    /// Returns the next uniformly distributed number using `Rng::generate`.
+    fn next_uniform(&mut self) -> f64 {
+        self.generate()
+    }
 }
 
 /// Automatically implements the `RngTrait` trait.
@@ -259,9 +892,18 @@ macro_rules! auto_rng_trait {
                 self.rng.set_seed(seed);
             }
 
+            /// Sets the number of values discarded after seeding or restarting the generator and restarts it.
+            ///
+            /// # Arguments
+            ///
+            /// * steps - A `u64` representing the number of discarded uniformly distributed values.
+            fn set_burn_in(&mut self, steps: u64) {
+                self.rng.set_burn_in(steps);
+            }
+
             /// Resets the random number generator to start from the beginning using the initial seed.
             ///
-            /// This method sets the state of the RNG back to the value of the seed,
+            /// This method sets the state of the RNG back to the mixed seed,
             /// so the random number sequence starts over.
             fn restart(&mut self) {
                 self.rng.restart();
@@ -274,9 +916,41 @@ macro_rules! auto_rng_trait {
                 self.rng.restart();
             }
 
+            /// Returns the number of uniformly distributed numbers consumed since the seed was set or the generator was restarted.
+            ///
+            /// This method requires the `draw-count` feature.
+            ///
+            /// # Returns
+            ///
+            /// The number of consumed numbers as a `u64`.
+            #[cfg(feature = "draw-count")]
+            fn draws_used(&self) -> u64 {
+                self.rng.draw_count()
+            }
+
+            /// Fills a given slice with random numbers of a given distribution.
+            ///
+            /// This calls the `generate` method once for every entry of the slice.
+            /// The slice can be reused across iterations, so high-throughput loops do not allocate.
+            ///
+            /// # Arguments
+            ///
+            /// * buffer - A mutable slice of `f64` which is overwritten completely.
+            ///
+            /// # Undesired Behavior
+            ///
+            /// All random numbers from the `gen` method are automatically converted to `f64`.
+            /// If the underlying distribution only returns integers or bools they should be converted back.
+            fn fill(&mut self, buffer: &mut [f64]) {
+                for random in buffer.iter_mut() {
+                    *random = self.generate() as f64;
+                    debug_assert!(!random.is_nan(), "generated a NaN value");
+                }
+            }
+
             /// Generates multiple random numbers of a given distribution.
             ///
-            /// This calls the `generate` method multiple times and safes the results in a `Vec<f64>`.
+            /// This fills a new `Vec<f64>` using the `fill` method.
             ///
             /// # Arguments
             ///
@@ -291,13 +965,251 @@ macro_rules! auto_rng_trait {
             /// All random numbers from the `gen` method are automatically converted to `f64`.
             /// If the underlying distribution only returns integers or bools they should be converted back.
             fn generate_multiple(&mut self, number: usize) -> Vec<f64> {
-                let mut randoms: Vec<f64> = Vec::with_capacity(number);
+                let mut randoms: Vec<f64> = vec![0_f64; number];
+                self.fill(&mut randoms);
+                randoms
+            }
 
-                for _ in 0_usize..number {
-                    randoms.push(self.generate() as f64);
-                }
+            /// Generates a fixed number of random numbers of a given distribution on the stack.
+            ///
+            /// This fills an array using the `fill` method without allocating, unlike `generate_multiple`.
+            ///
+            /// # Returns
+            ///
+            /// An array of `N` `f64` values randomly generated according to the underlying distribution.
+            ///
+            /// # Undesired Behavior
+            ///
+            /// All random numbers from the `gen` method are automatically converted to `f64`.
+            /// If the underlying distribution only returns integers or bools they should be converted back.
+            fn generate_array<const N: usize>(&mut self) -> [f64; N] {
+                let mut randoms: [f64; N] = [0_f64; N];
+                self.fill(&mut randoms);
                 randoms
             }
         }
     };
 }
+
+/// Automatically implements the `seed`, `set_seed` and `restart` methods as inherent methods.
+///
+/// This is meant for generators which do not return a single number per call and can therefore not implement `RngTrait`.
+/// For this to work the generator needs to have a `rng` attribute of type `Rng`.
+#[macro_export]
+macro_rules! auto_seed_methods {
+    ($t:ty) => {
+        impl $t {
+            /// Returns the seed used to initialize the random number generator.
+            ///
+            /// # Returns
+            ///
+            /// The seed value as a `u64`.
+            pub fn seed(&self) -> u64 {
+                self.rng.seed()
+            }
+
+            /// Sets the seed of the random number generator to a given number.
+            ///
+            /// # Arguments
+            ///
+            /// * seed - A `u64` representing the new seed.
+            pub fn set_seed(&mut self, seed: u64) {
+                self.rng.set_seed(seed);
+            }
+
+            /// Sets the number of values discarded after seeding or restarting the generator and restarts it.
+            ///
+            /// # Arguments
+            ///
+            /// * steps - A `u64` representing the number of discarded uniformly distributed values.
+            pub fn set_burn_in(&mut self, steps: u64) {
+                self.rng.set_burn_in(steps);
+            }
+
+            /// Resets the random number generator to start from the beginning using the initial seed.
+            pub fn restart(&mut self) {
+                self.rng.restart();
+            }
+
+            /// Returns the number of uniformly distributed numbers consumed since the seed was set or the generator was restarted.
+            ///
+            /// This method requires the `draw-count` feature.
+            ///
+            /// # Returns
+            ///
+            /// The number of consumed numbers as a `u64`.
+            #[cfg(feature = "draw-count")]
+            pub fn draws_used(&self) -> u64 {
+                self.rng.draw_count()
+            }
+        }
+    };
+}
+
+/// Automatically implements a builder for a distribution and the `builder` method returning it.
+///
+/// The builder has a setter for every parameter of the `new` constructor, in the given order,
+/// and the optional `seed` and `rng` setters. Validation is deferred to its `build` method.
+/// For this to work the distribution needs to have a `rng` attribute of type `Rng`
+/// and a `new` constructor taking the parameters and returning a `Result`.
+#[macro_export]
+macro_rules! auto_builder {
+    ($t:ident, $builder:ident { $($parameter:ident: $kind:ty),+ $(,)? }) => {
+        #[doc = concat!("A builder for `", stringify!($t), "` instances with named parameters.")]
+        ///
+        /// All parameters have to be set before calling `build`, the seed and the generator are optional.
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            $(
+                #[doc = concat!("The parameter `", stringify!($parameter), "`, if already set.")]
+                $parameter: Option<$kind>,
+            )+
+
+            /// The seed of the random number generator, if already set.
+            seed: Option<u64>,
+
+            /// The uniformly distributed random number generator, if already set.
+            rng: Option<Rng>,
+        }
+
+        impl $t {
+            #[doc = concat!("Creates a `", stringify!($builder), "` to construct a `", stringify!($t), "` with named parameters.")]
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("A `", stringify!($builder), "` without any parameters set.")]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $builder {
+            $(
+                #[doc = concat!("Sets the parameter `", stringify!($parameter), "`.")]
+                pub fn $parameter(mut self, $parameter: $kind) -> Self {
+                    self.$parameter = Some($parameter);
+                    self
+                }
+            )+
+
+            /// Sets the seed of the random number generator.
+            ///
+            /// The seed takes precedence over the seed of a generator given by the `rng` method.
+            pub fn seed(mut self, seed: u64) -> Self {
+                self.seed = Some(seed);
+                self
+            }
+
+            /// Sets the uniformly distributed random number generator, e.g. one handed out by a `StreamManager`.
+            pub fn rng(mut self, rng: Rng) -> Self {
+                self.rng = Some(rng);
+                self
+            }
+
+            #[doc = concat!("Validates the parameters and builds the `", stringify!($t), "` instance.")]
+            ///
+            /// Without a seed or a generator, the random number generator uses a system-generated seed.
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("* `Ok(", stringify!($t), ")` - Returns an instance of `", stringify!($t), "` if the parameters are valid.")]
+            #[doc = concat!("* `Err(RngError)` - Returns a `MissingError` if a parameter was not set or the error of `", stringify!($t), "::new` if a parameter is invalid.")]
+            pub fn build(self) -> Result<$t, RngError> {
+                let mut distribution: $t = $t::new(
+                    $(self.$parameter.ok_or(RngError::missing(stringify!($parameter)))?,)+
+                )?;
+
+                if let Some(rng) = self.rng {
+                    distribution.rng = rng;
+                }
+                if let Some(seed) = self.seed {
+                    distribution.rng.set_seed(seed);
+                }
+                Ok(distribution)
+            }
+        }
+    };
+}
+
+/// Automatically implements the `params` and `set_params` methods.
+///
+/// The parameters are given in the order of the `new` constructor and have to be attributes of the distribution.
+/// `set_params` validates the parameters with `new` and keeps the random number generator
+/// as well as the attributes listed after `keep`, e.g. a chosen algorithm.
+/// For this to work the distribution needs to have a `rng` attribute of type `Rng`.
+#[macro_export]
+macro_rules! auto_params {
+    ($t:ident { $parameter:ident: $kind:ty $(,)? } $(keep $($kept:ident),+)?) => {
+        impl $t {
+            #[doc = concat!("Returns the parameter `", stringify!($parameter), "` of the distribution.")]
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("The value of `", stringify!($parameter), "` as a `", stringify!($kind), "`.")]
+            pub fn params(&self) -> $kind {
+                self.$parameter
+            }
+        }
+
+        $crate::auto_params!(@set $t { $parameter: $kind } $(keep $($kept),+)?);
+    };
+    ($t:ident { $($parameter:ident: $kind:ty),+ $(,)? } $(keep $($kept:ident),+)?) => {
+        impl $t {
+            /// Returns the parameters of the distribution in the order of the `new` constructor.
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("A tuple of `(", stringify!($($parameter),+), ")`.")]
+            pub fn params(&self) -> ($($kind),+) {
+                ($(self.$parameter),+)
+            }
+        }
+
+        $crate::auto_params!(@set $t { $($parameter: $kind),+ } $(keep $($kept),+)?);
+    };
+    (@set $t:ident { $($parameter:ident: $kind:ty),+ } $(keep $($kept:ident),+)?) => {
+        impl $t {
+            /// Changes the parameters of the distribution.
+            ///
+            /// The random number generator keeps its state, so parameters can be swept
+            /// without rebuilding the distribution and reseeding in the middle of a run.
+            ///
+            /// # Arguments
+            ///
+            $(#[doc = concat!("* `", stringify!($parameter), "` - A `", stringify!($kind), "` representing the new value of `", stringify!($parameter), "`.")])+
+            ///
+            /// # Returns
+            ///
+            /// * `Ok(())` - If the parameters were changed.
+            #[doc = concat!("* `Err(RngError)` - Returns the error of `", stringify!($t), "::new` if a parameter is invalid. The parameters are not changed.")]
+            pub fn set_params(&mut self, $($parameter: $kind),+) -> Result<(), RngError> {
+                let mut updated: $t = $t::new($($parameter),+)?;
+
+                std::mem::swap(&mut updated.rng, &mut self.rng);
+                $($(updated.$kept = self.$kept;)+)?
+                *self = updated;
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Automatically implements the `Display` trait printing the parameters in mathematical notation.
+///
+/// Every parameter is given by its symbol and the attribute holding its value, e.g. `Normal { "μ" = mean, "σ²" = variance }`
+/// prints `Normal(μ=0, σ²=1)`. A precision given to the formatter, e.g. `{:.3}`, is applied to every value.
+#[macro_export]
+macro_rules! auto_display {
+    ($t:ident { $($symbol:literal = $parameter:ident),+ $(,)? }) => {
+        impl std::fmt::Display for $t {
+            /// Formats the distribution with the symbols and values of its parameters.
+            fn fmt(&self, format: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let parameters: Vec<String> = match format.precision() {
+                    Some(precision) => vec![$(format!("{}={:.*}", $symbol, precision, self.$parameter)),+],
+                    None => vec![$(format!("{}={}", $symbol, self.$parameter)),+],
+                };
+                write!(format, "{}({})", stringify!($t), parameters.join(", "))
+            }
+        }
+    };
+}
@@ -24,8 +24,28 @@ pub enum RngError {
     /// The given parameter should have been within a given interval.
     IntervalError { value: f64, min: f64, max: f64 },
 
+    /// The parameter should be a probability.
+    ///
+    /// `value` is the value that should have been between 0 and 1.
+    ProbabilityError { value: f64 },
+
+    /// The parameter should be a finite number.
+    ///
+    /// `value` is the value that was infinite or NaN.
+    NotFiniteError { value: f64 },
+
     /// The given vector is empty.
     EmptyError,
+
+    /// A required parameter was not given to a builder.
+    ///
+    /// `parameter` is the name of the missing parameter.
+    MissingError { parameter: &'static str },
+
+    /// An iterative method did not converge for the given parameters.
+    ///
+    /// `residual` is the remaining error of the last iteration.
+    ConvergenceError { residual: f64 },
 }
 
 impl Display for RngError {
@@ -49,10 +69,30 @@ impl Display for RngError {
                 "Interval Error: expected {} <= value <= {}, got {}",
                 min, max, value
             ),
+            RngError::ProbabilityError { value } => write!(
+                format,
+                "Probability Error: expected 0 <= value <= 1, got {}",
+                value
+            ),
             RngError::EmptyError => write!(
                 format,
                 "Empty Error: the vector is empty",
-            )
+            ),
+            RngError::NotFiniteError { value } => write!(
+                format,
+                "NotFinite Error: expected a finite value, got {}",
+                value
+            ),
+            RngError::MissingError { parameter } => write!(
+                format,
+                "Missing Error: the parameter {} was not set",
+                parameter
+            ),
+            RngError::ConvergenceError { residual } => write!(
+                format,
+                "Convergence Error: the iteration did not converge, the residual is {}",
+                residual
+            ),
         }
     }
 }
@@ -84,6 +124,30 @@ impl RngError {
         RngError::IntervalError { value, min, max }
     }
 
+    /// Creates a new `ProbabilityError`.
+    #[inline]
+    pub fn probability(value: f64) -> Self {
+        RngError::ProbabilityError { value }
+    }
+
+    /// Creates a new `NotFiniteError`.
+    #[inline]
+    pub fn not_finite(value: f64) -> Self {
+        RngError::NotFiniteError { value }
+    }
+
+    /// Creates a new `MissingError`.
+    #[inline]
+    pub fn missing(parameter: &'static str) -> Self {
+        RngError::MissingError { parameter }
+    }
+
+    /// Creates a new `ConvergenceError`.
+    #[inline]
+    pub fn convergence(residual: f64) -> Self {
+        RngError::ConvergenceError { residual }
+    }
+
     /// Checks whether a lower value is indeed lower than a higher one.
     ///
     /// # Arguments
@@ -167,18 +231,56 @@ impl RngError {
         }
     }
 
+    /// Checks whether a value is a probability.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value that should be a probability.
+    ///
+    /// # Returns
+    ///
+    /// * `()` - When 0 <= `value` <= 1.
+    /// * `ProbabilityError` - Otherwise.
+    #[inline]
+    pub fn check_probability(value: f64) -> Result<(), Self> {
+        if (0_f64..=1_f64).contains(&value) {
+            Ok(())
+        } else {
+            Err(Self::probability(value))
+        }
+    }
+
+    /// Checks whether a value is finite, i.e. neither infinite nor NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value that should be finite.
+    ///
+    /// # Returns
+    ///
+    /// * `()` - When `value` is finite.
+    /// * `NotFiniteError` - Otherwise.
+    #[inline]
+    pub fn check_finite(value: f64) -> Result<(), Self> {
+        if value.is_finite() {
+            Ok(())
+        } else {
+            Err(Self::not_finite(value))
+        }
+    }
+
     /// Checks whether a vector is empty.
     ///
     /// # Arguments
     ///
-    /// * `vec` - A reference to the vector.
+    /// * `vec` - A reference to the vector or slice.
     ///
     /// # Returns
     ///
     /// * `()` - When the vector is not empty.
     /// * `OrderError` - Otherwise.
     #[inline]
-    pub fn check_empty<T>(vec: &Vec<T>) -> Result<(), Self> {
+    pub fn check_empty<T>(vec: &[T]) -> Result<(), Self> {
         if !(vec.is_empty()) {
             Ok(())
         } else {
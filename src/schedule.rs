@@ -0,0 +1,222 @@
+//! This module contains the implementation of the `Schedule` struct and its methods.
+
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random event schedules over a number of days.
+///
+/// For every day the number of events is drawn from a count distribution (e.g. `Poisson`)
+/// and the time of every event is drawn from a time-of-day distribution on [0, 1] (e.g. `Beta` or `Triangle`),
+/// where 0 is midnight at the start and 1 midnight at the end of the day.
+/// Optionally, every day of the week has a rate multiplier between 0 and 1,
+/// and every event of this day is kept with this probability.
+/// For Poisson distributed counts this thinning results in Poisson distributed counts again.
+///
+/// The days are calendar days in a time zone given by a fixed offset to UTC.
+/// The schedule is returned as sorted Unix timestamps in seconds.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used for the thinning of the events.
+/// * `events_per_day` - The distribution of the number of events per day.
+/// * `time_of_day` - The distribution of the time of an event as a fraction of the day.
+/// * `horizon_days` - The number of simulated days. Must be a positive integer.
+/// * `start` - The Unix timestamp of midnight at the start of the first day.
+/// * `utc_offset` - The offset of the time zone to UTC in seconds.
+/// * `day_multipliers` - The rate multipliers from Monday to Sunday.
+///
+/// # Notes
+///
+/// The offset to UTC is fixed for the whole schedule, daylight saving time changes are not taken into account.
+pub struct Schedule<C: RngTrait, T: RngTrait> {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The distribution of the number of events per day.
+    events_per_day: C,
+
+    /// The distribution of the time of an event as a fraction of the day.
+    time_of_day: T,
+
+    /// The number of simulated days.
+    horizon_days: usize,
+
+    /// The Unix timestamp of midnight at the start of the first day.
+    start: i64,
+
+    /// The offset of the time zone to UTC in seconds.
+    utc_offset: i32,
+
+    /// The rate multipliers from Monday to Sunday.
+    day_multipliers: [f64; 7],
+}
+
+impl<C: RngTrait, T: RngTrait> Schedule<C, T> {
+    /// The number of seconds of a day.
+    const SECONDS_PER_DAY: i64 = 86_400_i64;
+
+    /// The largest offset of a time zone to UTC in seconds.
+    const MAX_UTC_OFFSET: i32 = 14_i32 * 3_600_i32;
+}
+
+impl<C: RngTrait, T: RngTrait> Schedule<C, T> {
+    /// Creates a new `Schedule` instance with given distributions and horizon.
+    ///
+    /// The schedule starts on 1970-01-01 in UTC and all days have the multiplier 1.
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `events_per_day` - Any distribution implementing `RngTrait`, used for the number of events per day. Its values are rounded and negative values are treated as 0.
+    /// * `time_of_day` - Any distribution implementing `RngTrait`, used for the time of an event as a fraction of the day. Its values are clamped to [0, 1).
+    /// * `horizon_days` - A `usize` representing the number of simulated days. It must be a positive integer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Schedule)` - Returns an instance of `Schedule` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `horizon_days` is 0.
+    pub fn new(
+        events_per_day: C,
+        time_of_day: T,
+        horizon_days: usize,
+    ) -> Result<Schedule<C, T>, RngError> {
+        RngError::check_positive(horizon_days as f64)?;
+
+        Ok(Schedule {
+            rng: Rng::new(),
+            events_per_day,
+            time_of_day,
+            horizon_days,
+            start: 0_i64,
+            utc_offset: 0_i32,
+            day_multipliers: [1_f64; 7],
+        })
+    }
+
+    /// Sets the first day of the schedule and its time zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - An `i64` Unix timestamp in seconds of any moment of the first day.
+    /// * `utc_offset` - An `i32` representing the offset of the time zone to UTC in seconds, e.g. `3600` for UTC+1. It must be between -14 and 14 hours.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - if the offset is valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the offset is not between -14 and 14 hours.
+    pub fn set_start(&mut self, timestamp: i64, utc_offset: i32) -> Result<(), RngError> {
+        RngError::check_interval(
+            utc_offset as f64,
+            -Self::MAX_UTC_OFFSET as f64,
+            Self::MAX_UTC_OFFSET as f64,
+        )?;
+
+        let local: i64 = timestamp + utc_offset as i64;
+        self.start =
+            local.div_euclid(Self::SECONDS_PER_DAY) * Self::SECONDS_PER_DAY - utc_offset as i64;
+        self.utc_offset = utc_offset;
+        Ok(())
+    }
+
+    /// Sets the rate multipliers of the days of the week.
+    ///
+    /// # Arguments
+    ///
+    /// * `multipliers` - The multipliers from Monday to Sunday. They must be between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - if the multipliers are valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if a multiplier is not between 0 and 1.
+    pub fn set_day_multipliers(&mut self, multipliers: [f64; 7]) -> Result<(), RngError> {
+        for &multiplier in multipliers.iter() {
+            RngError::check_interval(multiplier, 0_f64, 1_f64)?;
+        }
+
+        self.day_multipliers = multipliers;
+        Ok(())
+    }
+
+    /// Sets the rate multipliers of the working days and the weekend.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekday` - A `f64` representing the multiplier from Monday to Friday. It must be between 0 and 1.
+    /// * `weekend` - A `f64` representing the multiplier of Saturday and Sunday. It must be between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - if the multipliers are valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if a multiplier is not between 0 and 1.
+    pub fn set_weekday_multipliers(&mut self, weekday: f64, weekend: f64) -> Result<(), RngError> {
+        self.set_day_multipliers([
+            weekday, weekday, weekday, weekday, weekday, weekend, weekend,
+        ])
+    }
+
+    /// Generates a random schedule.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<i64>` of sorted Unix timestamps in seconds.
+    pub fn generate(&mut self) -> Vec<i64> {
+        let mut timestamps: Vec<i64> = Vec::new();
+
+        for day in 0_i64..self.horizon_days as i64 {
+            let midnight: i64 = self.start + day * Self::SECONDS_PER_DAY;
+            let multiplier: f64 = self.day_multipliers[self.weekday(day)];
+            let count: f64 = self.events_per_day.generate_multiple(1_usize)[0]
+                .round()
+                .max(0_f64);
+
+            for _ in 0_usize..count as usize {
+                if self.rng.generate() >= multiplier {
+                    continue;
+                }
+
+                let fraction: f64 =
+                    self.time_of_day.generate_multiple(1_usize)[0].clamp(0_f64, 1_f64);
+                let seconds: i64 = ((fraction * Self::SECONDS_PER_DAY as f64) as i64)
+                    .min(Self::SECONDS_PER_DAY - 1_i64);
+                timestamps.push(midnight + seconds);
+            }
+        }
+
+        timestamps.sort_unstable();
+        timestamps
+    }
+
+    /// Returns the seed used to initialize the random number generator of the thinning.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Sets the seed of the random number generator of the thinning to a given number.
+    ///
+    /// To reproduce a schedule, the count and time-of-day distributions have to be seeded as well.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng.set_seed(seed);
+    }
+
+    /// Resets the random number generator of the thinning to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.rng.restart();
+    }
+
+    /// Calculates the day of the week of a simulated day, where Monday is 0 and Sunday is 6.
+    ///
+    /// The 1970-01-01 was a Thursday.
+    fn weekday(&self, day: i64) -> usize {
+        let local_days: i64 =
+            (self.start + self.utc_offset as i64).div_euclid(Self::SECONDS_PER_DAY) + day;
+        (local_days + 3_i64).rem_euclid(7_i64) as usize
+    }
+}
@@ -0,0 +1,182 @@
+//! This module contains random sketching operators for randomized numerical linear algebra.
+//!
+//! A sketch is a random `m x n` matrix `S` with `m` much smaller than `n`,
+//! such that `|Sx|` is approximately `|x|` for every vector `x` with high probability.
+//! The functions take the random number generator as an argument, so that the results are reproducible by seeding it.
+
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A dense sketching operator.
+///
+/// # Fields
+///
+/// * `matrix` - The `m x n` sketching matrix in row-major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseSketch {
+    /// The sketching matrix.
+    pub matrix: Vec<Vec<f64>>,
+}
+
+impl DenseSketch {
+    /// Applies the sketch to a given vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of length `n`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `m` representing `Sx`.
+    pub fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.matrix
+            .iter()
+            .map(|row| row.iter().zip(x.iter()).map(|(s, x)| s * x).sum())
+            .collect()
+    }
+}
+
+/// Generates a Gaussian sketch.
+///
+/// The entries are independently Normal distributed with mean 0 and variance `1 / m`.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `m` - A `usize` representing the sketch dimension. It must be a positive integer.
+/// * `n` - A `usize` representing the dimension of the sketched vectors.
+///
+/// # Returns
+///
+/// * `Ok(DenseSketch)` - Returns the `m x n` sketch if the parameters are valid.
+/// * `Err(RngError)` - Returns a `PositiveError` if `m` is 0.
+pub fn gaussian(rng: &mut Rng, m: usize, n: usize) -> Result<DenseSketch, RngError> {
+    RngError::check_positive(m as f64)?;
+
+    let scale: f64 = 1_f64 / (m as f64).sqrt();
+    let matrix: Vec<Vec<f64>> = (0_usize..m)
+        .map(|_| {
+            (0_usize..n)
+                .map(|_| scale * rng.gen_standard_normal())
+                .collect()
+        })
+        .collect();
+
+    Ok(DenseSketch { matrix })
+}
+
+/// Generates a Rademacher sketch.
+///
+/// The entries are independently `1 / sqrt(m)` or `-1 / sqrt(m)` with equal probability.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator used.
+/// * `m` - A `usize` representing the sketch dimension. It must be a positive integer.
+/// * `n` - A `usize` representing the dimension of the sketched vectors.
+///
+/// # Returns
+///
+/// * `Ok(DenseSketch)` - Returns the `m x n` sketch if the parameters are valid.
+/// * `Err(RngError)` - Returns a `PositiveError` if `m` is 0.
+pub fn rademacher(rng: &mut Rng, m: usize, n: usize) -> Result<DenseSketch, RngError> {
+    RngError::check_positive(m as f64)?;
+
+    let scale: f64 = 1_f64 / (m as f64).sqrt();
+    let matrix: Vec<Vec<f64>> = (0_usize..m)
+        .map(|_| (0_usize..n).map(|_| random_sign(rng) * scale).collect())
+        .collect();
+
+    Ok(DenseSketch { matrix })
+}
+
+/// A sparse sketching operator, also known as CountSketch.
+///
+/// Every column of the sketching matrix contains exactly one non-zero entry, which is 1 or -1 with equal probability,
+/// in a uniformly distributed row.
+/// Therefore, it can be applied in `O(n)` time without storing the matrix.
+///
+/// # Fields
+///
+/// * `m` - The sketch dimension.
+/// * `buckets` - The row of the non-zero entry of every column.
+/// * `signs` - The sign of the non-zero entry of every column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CountSketch {
+    /// The sketch dimension.
+    m: usize,
+
+    /// The row of the non-zero entry of every column.
+    buckets: Vec<usize>,
+
+    /// The sign of the non-zero entry of every column.
+    signs: Vec<f64>,
+}
+
+impl CountSketch {
+    /// Creates a new `CountSketch` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator used.
+    /// * `m` - A `usize` representing the sketch dimension. It must be a positive integer.
+    /// * `n` - A `usize` representing the dimension of the sketched vectors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CountSketch)` - Returns the `m x n` sketch if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `m` is 0.
+    pub fn new(rng: &mut Rng, m: usize, n: usize) -> Result<CountSketch, RngError> {
+        RngError::check_positive(m as f64)?;
+
+        let mut buckets: Vec<usize> = Vec::with_capacity(n);
+        let mut signs: Vec<f64> = Vec::with_capacity(n);
+        for _ in 0_usize..n {
+            buckets.push(rng.gen_below(m as u64) as usize);
+            signs.push(random_sign(rng));
+        }
+
+        Ok(CountSketch { m, buckets, signs })
+    }
+
+    /// Applies the sketch to a given vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of length `n`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of length `m` representing `Sx`.
+    pub fn apply(&self, x: &[f64]) -> Vec<f64> {
+        let mut result: Vec<f64> = vec![0_f64; self.m];
+
+        for ((&bucket, &sign), &value) in self.buckets.iter().zip(self.signs.iter()).zip(x.iter()) {
+            result[bucket] += sign * value;
+        }
+        result
+    }
+
+    /// Returns the sketching matrix in dense form.
+    ///
+    /// # Returns
+    ///
+    /// The `m x n` sketching matrix in row-major order.
+    pub fn to_dense(&self) -> DenseSketch {
+        let mut matrix: Vec<Vec<f64>> = vec![vec![0_f64; self.buckets.len()]; self.m];
+
+        for (j, (&bucket, &sign)) in self.buckets.iter().zip(self.signs.iter()).enumerate() {
+            matrix[bucket][j] = sign;
+        }
+        DenseSketch { matrix }
+    }
+}
+
+/// Generates 1 or -1 with equal probability.
+fn random_sign(rng: &mut Rng) -> f64 {
+    if rng.generate() < 0.5_f64 {
+        1_f64
+    } else {
+        -1_f64
+    }
+}
@@ -0,0 +1,169 @@
+//! This module contains the implementation of the `SparseRandom` struct and its methods.
+
+use crate::math;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating sparse random matrices in the coordinate (COO) format.
+///
+/// Every entry of a `rows x cols` matrix is non-zero independently with probability `density`.
+/// The values of the non-zero entries are generated by a given distribution.
+/// A sparse random vector is a matrix with a single column.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate the positions of the non-zero entries.
+/// * `rows` - The number of rows. Must be a positive integer.
+/// * `cols` - The number of columns. Must be a positive integer.
+/// * `density` - The probability of an entry being non-zero. Must be a probability.
+/// * `values` - The distribution of the non-zero values.
+///
+/// # Notes
+///
+/// Instead of drawing a uniform number for every entry, the gaps between consecutive non-zero entries are generated.
+/// They are Geometric distributed, so the generation takes `O(rows * cols * density)` time
+/// and even huge matrices with a small density are generated quickly.
+pub struct SparseRandom<D: RngTrait> {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The number of rows.
+    rows: usize,
+
+    /// The number of columns.
+    cols: usize,
+
+    /// The probability of an entry being non-zero.
+    density: f64,
+
+    /// The distribution of the non-zero values.
+    values: D,
+}
+
+impl<D: RngTrait> SparseRandom<D> {
+    /// Creates a new `SparseRandom` instance with given parameters.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - A `usize` representing the number of rows. It must be a positive integer.
+    /// * `cols` - A `usize` representing the number of columns. It must be a positive integer.
+    /// * `density` - A `f64` representing the probability of an entry being non-zero. It must be between 0 and 1.
+    /// * `values` - Any distribution implementing `RngTrait`, used for the non-zero values.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SparseRandom)` - Returns an instance of `SparseRandom` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `rows` or `cols` is 0 or a `ProbabilityError` if `density` is not a probability.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the number of entries `rows * cols` overflows a `usize`.
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        density: f64,
+        values: D,
+    ) -> Result<SparseRandom<D>, RngError> {
+        RngError::check_positive(rows as f64)?;
+        RngError::check_positive(cols as f64)?;
+        RngError::check_probability(density)?;
+        rows.checked_mul(cols).ok_or(RngError::interval(
+            rows as f64 * cols as f64,
+            1_f64,
+            usize::MAX as f64,
+        ))?;
+
+        Ok(SparseRandom {
+            rng: Rng::new(),
+            rows,
+            cols,
+            density,
+            values,
+        })
+    }
+
+    /// Generates a sparse random matrix.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(usize, usize, f64)>` of `(row, column, value)` triplets, sorted in row-major order.
+    pub fn generate(&mut self) -> Vec<(usize, usize, f64)> {
+        let positions: Vec<usize> = self.generate_positions();
+        let values: Vec<f64> = self.values.generate_multiple(positions.len());
+
+        positions
+            .into_iter()
+            .zip(values)
+            .map(|(position, value)| (position / self.cols, position % self.cols, value))
+            .collect()
+    }
+
+    /// Returns a mutable reference to the distribution of the non-zero values.
+    ///
+    /// The values are generated by the random number generator of this distribution.
+    /// To reproduce a matrix, both the `SparseRandom` and this distribution have to be seeded.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the value distribution.
+    pub fn values_mut(&mut self) -> &mut D {
+        &mut self.values
+    }
+
+    /// Returns the seed used to initialize the random number generator of the positions.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Sets the seed of the random number generator of the positions to a given number.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng.set_seed(seed);
+    }
+
+    /// Resets the random number generator of the positions to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.rng.restart();
+    }
+
+    /// Generates the row-major linear indices of the non-zero entries.
+    ///
+    /// The gap to the next non-zero entry is generated by
+    /// ```text
+    /// floor(ln(U) / ln(1 - density))
+    /// ```
+    /// where `U` is a uniformly distributed random variable between [0, 1].
+    /// `ln(1 - density)` is calculated with `ln_1p`, so it does not round to 0 for tiny densities.
+    /// An infinite gap, e.g. for `U = 0`, lies beyond the last entry.
+    fn generate_positions(&mut self) -> Vec<usize> {
+        let size: usize = self.rows * self.cols;
+
+        if self.density <= 0_f64 {
+            return Vec::new();
+        } else if self.density >= 1_f64 {
+            return (0_usize..size).collect();
+        }
+
+        let mut positions: Vec<usize> = Vec::with_capacity((size as f64 * self.density) as usize);
+        let log_complement: f64 = math::ln_1p(-self.density);
+        let mut position: f64 = -1_f64;
+
+        loop {
+            let gap: f64 = (math::ln(self.rng.generate()) / log_complement).floor();
+            if !gap.is_finite() {
+                return positions;
+            }
+            position += gap + 1_f64;
+            if position >= size as f64 {
+                return positions;
+            }
+            positions.push(position as usize);
+        }
+    }
+}
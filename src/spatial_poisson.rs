@@ -0,0 +1,235 @@
+//! This module contains the implementation of the `SpatialPoisson` and `MaternCluster` structs and their methods.
+//!
+//! Both generate random point clouds in a rectangle `[0, width] x [0, height]`,
+//! e.g. the locations of plants, animals or events in ecological and geospatial simulations.
+//! `SpatialPoisson` places the points completely at random, `MaternCluster` groups them around random centres.
+
+use std::f64::consts::PI;
+
+use crate::auto_seed_methods;
+use crate::math;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating homogeneous Poisson point processes in a rectangle.
+///
+/// The number of points is Poisson distributed with the mean `rate * width * height`
+/// and the points are independently uniformly distributed in `[0, width] x [0, height]`.
+/// Hence, the numbers of points in disjoint regions are independent and Poisson distributed with the mean `rate * area`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `rate` - The expected number of points per unit area. Must be a positive number.
+/// * `width` - The width of the rectangle. Must be a positive number.
+/// * `height` - The height of the rectangle. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct SpatialPoisson {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The expected number of points per unit area.
+    rate: f64,
+
+    /// The width of the rectangle.
+    width: f64,
+
+    /// The height of the rectangle.
+    height: f64,
+}
+
+auto_seed_methods!(SpatialPoisson);
+
+impl SpatialPoisson {
+    /// Creates a new `SpatialPoisson` instance for a given rate and rectangle.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - A `f64` representing the expected number of points per unit area. It must be a positive number.
+    /// * `width` - A `f64` representing the width of the rectangle. It must be a positive number.
+    /// * `height` - A `f64` representing the height of the rectangle. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpatialPoisson)` - Returns an instance of `SpatialPoisson` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if any parameter is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(rate: f64, width: f64, height: f64) -> Result<SpatialPoisson, RngError> {
+        RngError::check_finite(rate)?;
+        RngError::check_finite(width)?;
+        RngError::check_finite(height)?;
+        RngError::check_positive(rate)?;
+        RngError::check_positive(width)?;
+        RngError::check_positive(height)?;
+
+        Ok(SpatialPoisson {
+            rng: Rng::new(),
+            rate,
+            width,
+            height,
+        })
+    }
+
+    /// Generates a random point cloud.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<[f64; 2]>` containing the points in no particular order.
+    pub fn generate(&mut self) -> Vec<[f64; 2]> {
+        let number: u64 = self.rng.gen_poisson(self.rate * self.width * self.height);
+
+        (0_u64..number)
+            .map(|_| {
+                [
+                    self.rng.generate() * self.width,
+                    self.rng.generate() * self.height,
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the expected number of points in the rectangle.
+    ///
+    /// # Returns
+    ///
+    /// The mean `rate * width * height` as a `f64`.
+    pub fn expected_count(&self) -> f64 {
+        self.rate * self.width * self.height
+    }
+}
+
+/// A struct for generating Matérn cluster processes in a rectangle.
+///
+/// The centres of the clusters (parents) form a homogeneous Poisson point process with the rate `parent_rate`.
+/// Every parent has a Poisson distributed number of points (children) with the mean `mean_children`,
+/// which are uniformly distributed in the disk of radius `radius` around it.
+/// Only the children inside `[0, width] x [0, height]` are returned.
+/// The parents are generated in the rectangle extended by `radius` on every side,
+/// so clusters near the border are cut off instead of missing.
+///
+/// The expected number of points per unit area is `parent_rate * mean_children`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `parent_rate` - The expected number of clusters per unit area. Must be a positive number.
+/// * `mean_children` - The expected number of points per cluster. Must be a positive number.
+/// * `radius` - The radius of the clusters. Must be a positive number.
+/// * `width` - The width of the rectangle. Must be a positive number.
+/// * `height` - The height of the rectangle. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct MaternCluster {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The expected number of clusters per unit area.
+    parent_rate: f64,
+
+    /// The expected number of points per cluster.
+    mean_children: f64,
+
+    /// The radius of the clusters.
+    radius: f64,
+
+    /// The width of the rectangle.
+    width: f64,
+
+    /// The height of the rectangle.
+    height: f64,
+}
+
+auto_seed_methods!(MaternCluster);
+
+impl MaternCluster {
+    /// Creates a new `MaternCluster` instance for given cluster parameters and rectangle.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_rate` - A `f64` representing the expected number of clusters per unit area. It must be a positive number.
+    /// * `mean_children` - A `f64` representing the expected number of points per cluster. It must be a positive number.
+    /// * `radius` - A `f64` representing the radius of the clusters. It must be a positive number.
+    /// * `width` - A `f64` representing the width of the rectangle. It must be a positive number.
+    /// * `height` - A `f64` representing the height of the rectangle. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MaternCluster)` - Returns an instance of `MaternCluster` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if any parameter is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(
+        parent_rate: f64,
+        mean_children: f64,
+        radius: f64,
+        width: f64,
+        height: f64,
+    ) -> Result<MaternCluster, RngError> {
+        RngError::check_finite(parent_rate)?;
+        RngError::check_finite(mean_children)?;
+        RngError::check_finite(radius)?;
+        RngError::check_finite(width)?;
+        RngError::check_finite(height)?;
+        RngError::check_positive(parent_rate)?;
+        RngError::check_positive(mean_children)?;
+        RngError::check_positive(radius)?;
+        RngError::check_positive(width)?;
+        RngError::check_positive(height)?;
+
+        Ok(MaternCluster {
+            rng: Rng::new(),
+            parent_rate,
+            mean_children,
+            radius,
+            width,
+            height,
+        })
+    }
+
+    /// Generates a random clustered point cloud.
+    ///
+    /// A child is placed at the polar coordinates
+    /// ```text
+    /// r = radius sqrt(U), φ = 2π V
+    /// ```
+    /// around its parent, where `U` and `V` are independently uniformly distributed on [0, 1].
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<[f64; 2]>` containing the points, grouped by cluster.
+    pub fn generate(&mut self) -> Vec<[f64; 2]> {
+        let extended_width: f64 = self.width + 2_f64 * self.radius;
+        let extended_height: f64 = self.height + 2_f64 * self.radius;
+        let parents: u64 = self
+            .rng
+            .gen_poisson(self.parent_rate * extended_width * extended_height);
+
+        let mut points: Vec<[f64; 2]> = Vec::new();
+        for _ in 0_u64..parents {
+            let parent_x: f64 = self.rng.generate() * extended_width - self.radius;
+            let parent_y: f64 = self.rng.generate() * extended_height - self.radius;
+            let children: u64 = self.rng.gen_poisson(self.mean_children);
+
+            for _ in 0_u64..children {
+                let distance: f64 = self.radius * self.rng.generate().sqrt();
+                let angle: f64 = 2_f64 * PI * self.rng.generate();
+                let x: f64 = parent_x + distance * math::cos(angle);
+                let y: f64 = parent_y + distance * math::sin(angle);
+
+                if (0_f64..=self.width).contains(&x) && (0_f64..=self.height).contains(&y) {
+                    points.push([x, y]);
+                }
+            }
+        }
+        points
+    }
+
+    /// Returns the expected number of points in the rectangle.
+    ///
+    /// # Returns
+    ///
+    /// The mean `parent_rate * mean_children * width * height` as a `f64`.
+    pub fn expected_count(&self) -> f64 {
+        self.parent_rate * self.mean_children * self.width * self.height
+    }
+}
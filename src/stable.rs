@@ -0,0 +1,147 @@
+//! This module contains the implementation of the `Stable` struct and its methods.
+
+use std::f64::consts::{FRAC_2_PI, FRAC_PI_2, PI};
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::math;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a stable distribution.
+///
+/// Stable distributions are the limits of normalized sums of independent random variables,
+/// and with `α < 2` they have heavy tails with `P(|X| > x) ~ x^(-α)`.
+/// The parameters are the stability (α), the skewness (β), the scale (σ) and the location (μ)
+/// in the 1-parametrization of Nolan, where the characteristic function is
+/// ```text
+/// E[exp(i t X)] = exp(-σ^α |t|^α (1 - i β sign(t) tan(πα/2)) + i μ t)    for α ≠ 1
+/// E[exp(i t X)] = exp(-σ |t| (1 + i β 2/π sign(t) ln|t|) + i μ t)        for α = 1
+/// ```
+/// Special cases are the Normal distribution with variance `2 σ²` (α = 2), the Cauchy distribution (α = 1, β = 0)
+/// and the Levy distribution (α = 1/2, β = 1), which is also implemented on its own in `Levy`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `alpha` - The stability (α) of the distribution. Must be in (0, 2].
+/// * `beta` - The skewness (β) of the distribution. Must be in [-1, 1].
+/// * `scale` - The scale (σ) of the distribution. Must be a positive number.
+/// * `location` - The location (μ) of the distribution.
+#[derive(Debug, Clone)]
+pub struct Stable {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The stability (α) of the distribution.
+    alpha: f64,
+
+    /// The skewness (β) of the distribution.
+    beta: f64,
+
+    /// The scale (σ) of the distribution.
+    scale: f64,
+
+    /// The location (μ) of the distribution.
+    location: f64,
+}
+
+auto_rng_trait!(Stable);
+auto_builder!(
+    Stable,
+    StableBuilder {
+        alpha: f64,
+        beta: f64,
+        scale: f64,
+        location: f64
+    }
+);
+auto_params!(Stable {
+    alpha: f64,
+    beta: f64,
+    scale: f64,
+    location: f64
+});
+auto_display!(Stable { "α" = alpha, "β" = beta, "σ" = scale, "μ" = location });
+
+impl Stable {
+    /// Creates a new `Stable` instance with a given stability, skewness, scale and location.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - A `f64` representing the stability (α). It must be in (0, 2].
+    /// * `beta` - A `f64` representing the skewness (β). It must be in [-1, 1].
+    /// * `scale` - A `f64` representing the scale (σ). It must be a positive number.
+    /// * `location` - A `f64` representing the location (μ).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Stable)` - Returns an instance of `Stable` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `alpha` or `scale` is less than or equal to 0 or an `IntervalError` if `alpha` is bigger than 2 or `beta` is not in [-1, 1]. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(alpha: f64, beta: f64, scale: f64, location: f64) -> Result<Stable, RngError> {
+        RngError::check_finite(scale)?;
+        RngError::check_finite(location)?;
+        RngError::check_positive(alpha)?;
+        RngError::check_interval(alpha, 0_f64, 2_f64)?;
+        RngError::check_interval(beta, -1_f64, 1_f64)?;
+        RngError::check_positive(scale)?;
+
+        Ok(Stable {
+            rng: Rng::new(),
+            alpha,
+            beta,
+            scale,
+            location,
+        })
+    }
+
+    /// Generates a random value from the stable distribution.
+    ///
+    /// This uses the algorithm of Chambers, Mallows and Stuck.
+    /// With `V` uniformly distributed on (-π/2, π/2) and `W` Exponential(1) distributed,
+    /// a standard stable random variable is given by
+    /// ```text
+    /// X = S sin(α (V + B)) / cos(V)^(1/α) (cos(V - α (V + B)) / W)^((1 - α)/α)    for α ≠ 1
+    /// X = 2/π ((π/2 + β V) tan(V) - β ln(π/2 W cos(V) / (π/2 + β V)))          for α = 1
+    /// ```
+    /// with `B = atan(β tan(πα/2)) / α` and `S = (1 + β² tan²(πα/2))^(1/(2α))`.
+    /// Then it is scaled and shifted according to the 1-parametrization.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the stable distribution.
+    pub fn generate(&mut self) -> f64 {
+        // Exclude the boundaries, where the formulas are singular
+        let mut uni: f64 = self.rng.generate();
+        while uni <= 0_f64 || uni >= 1_f64 {
+            uni = self.rng.generate();
+        }
+        let v: f64 = PI * (uni - 0.5_f64);
+        let w: f64 = -math::ln(1_f64 - self.rng.generate());
+
+        if self.alpha == 1_f64 {
+            let shifted: f64 = FRAC_PI_2 + self.beta * v;
+            let x: f64 = FRAC_2_PI
+                * (shifted * math::tan(v)
+                    - self.beta * math::ln(FRAC_PI_2 * w * math::cos(v) / shifted));
+
+            self.scale * x
+                + FRAC_2_PI * self.beta * self.scale * math::ln(self.scale)
+                + self.location
+        } else {
+            let tan: f64 = self.beta * math::tan(FRAC_PI_2 * self.alpha);
+            let b: f64 = math::atan(tan) / self.alpha;
+            let s: f64 = math::powf(1_f64 + tan * tan, 0.5_f64 / self.alpha);
+            let angle: f64 = self.alpha * (v + b);
+
+            let x: f64 = s * math::sin(angle) / math::powf(math::cos(v), 1_f64 / self.alpha)
+                * math::powf(math::cos(v - angle) / w, (1_f64 - self.alpha) / self.alpha);
+
+            self.scale * x + self.location
+        }
+    }
+}
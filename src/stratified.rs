@@ -0,0 +1,222 @@
+//! This module contains the implementation of the `Stratified` struct and its methods.
+
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A wrapper generating random variables of a distribution by stratified inverse transform sampling.
+///
+/// The interval [0, 1) is split into `k` strata of equal width.
+/// Every pass of `k` values draws exactly one uniformly distributed number from each stratum
+/// ```text
+/// Uᵢ = (i + Vᵢ) / k    for i = 0, ..., k - 1
+/// ```
+/// with independent `Vᵢ` uniformly distributed on [0, 1), and applies the quantile function of the wrapped distribution.
+/// The values of a pass are returned in a random order, so consecutive values do not follow the strata.
+///
+/// Every value still has the wrapped distribution, but each pass covers the whole range of probabilities evenly.
+/// The variance of the mean of whole passes is therefore never bigger than with independent values,
+/// and much smaller for smooth functions of the values, which reduces the variance of Monte Carlo estimates.
+/// As `Stratified` implements `RngTrait` like the wrapped distribution, no other code has to change.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `distribution` - The wrapped distribution, whose quantile function is applied.
+/// * `strata` - The number of strata `k`.
+/// * `pass` - The uniformly distributed numbers of the current pass, which have not been used yet.
+///
+/// # Notes
+///
+/// The values of a pass are dependent, so the variance reduction only holds for sample sizes divisible by `k`,
+/// and the sample variance overestimates the variance of the mean.
+#[derive(Debug, Clone)]
+pub struct Stratified<D: Quantile> {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The wrapped distribution.
+    distribution: D,
+
+    /// The number of strata.
+    strata: usize,
+
+    /// The unused uniformly distributed numbers of the current pass.
+    pass: Vec<f64>,
+}
+
+impl<D: Quantile> Stratified<D> {
+    /// Creates a new `Stratified` instance wrapping a given distribution.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    /// The same wrapper is also built by `Quantile::stratify`.
+    ///
+    /// # Arguments
+    ///
+    /// * `distribution` - A distribution implementing `Quantile`.
+    /// * `strata` - A `usize` representing the number of strata `k`. It must be at least 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Stratified)` - Returns an instance of `Stratified` if the number of strata is valid.
+    /// * `Err(RngError)` - Returns an `IntervalError` if the number of strata is 0.
+    pub fn new(distribution: D, strata: usize) -> Result<Stratified<D>, RngError> {
+        RngError::check_interval(strata as f64, 1_f64, usize::MAX as f64)?;
+
+        Ok(Stratified {
+            rng: Rng::new(),
+            distribution,
+            strata,
+            pass: Vec::with_capacity(strata),
+        })
+    }
+
+    /// Returns the number of strata.
+    ///
+    /// # Returns
+    ///
+    /// The number of strata `k` as a `usize`.
+    pub fn strata(&self) -> usize {
+        self.strata
+    }
+
+    /// Returns the wrapped distribution.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the wrapped distribution.
+    pub fn distribution(&self) -> &D {
+        &self.distribution
+    }
+
+    /// Generates a random value of the wrapped distribution from the next stratified uniformly distributed number.
+    ///
+    /// A new pass is drawn once all `k` numbers of the previous one are used.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value generated from the wrapped distribution.
+    pub fn generate(&mut self) -> f64 {
+        if self.pass.is_empty() {
+            self.draw_pass();
+        }
+
+        let uniform: f64 = self.pass.pop().unwrap_or_default();
+        self.distribution.quantile(uniform)
+    }
+
+    /// Draws one uniformly distributed number from each stratum and shuffles them with the Fisher-Yates shuffle.
+    fn draw_pass(&mut self) {
+        let width: f64 = 1_f64 / self.strata as f64;
+        for i in 0_usize..self.strata {
+            // Rounding can reach 1 in the last stratum, whose quantile may be infinite
+            let uniform: f64 = (i as f64 + self.rng.generate()) * width;
+            self.pass.push(uniform.min(1_f64 - f64::EPSILON / 2_f64));
+        }
+
+        for i in (1_usize..self.strata).rev() {
+            let j: usize = self.rng.gen_below(i as u64 + 1_u64) as usize;
+            self.pass.swap(i, j);
+        }
+    }
+}
+
+impl<D: Quantile> RngTrait for Stratified<D> {
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number and starts a new pass.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    fn set_seed(&mut self, seed: u64) {
+        self.rng.set_seed(seed);
+        self.pass.clear();
+    }
+
+    /// Sets the number of values discarded after seeding or restarting the generator, restarts it and starts a new pass.
+    ///
+    /// # Arguments
+    ///
+    /// * steps - A `u64` representing the number of discarded uniformly distributed values.
+    fn set_burn_in(&mut self, steps: u64) {
+        self.rng.set_burn_in(steps);
+        self.pass.clear();
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed and starts a new pass.
+    fn restart(&mut self) {
+        self.rng.restart();
+        self.pass.clear();
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed.
+    ///
+    /// Just a wrapper for the `restart` method.
+    fn reset(&mut self) {
+        self.restart();
+    }
+
+    /// Returns the number of uniformly distributed numbers consumed since the seed was set or the generator was restarted.
+    ///
+    /// This method requires the `draw-count` feature.
+    ///
+    /// # Returns
+    ///
+    /// The number of consumed numbers as a `u64`, including those of the unused rest of the pass.
+    #[cfg(feature = "draw-count")]
+    fn draws_used(&self) -> u64 {
+        self.rng.draw_count()
+    }
+
+    /// Fills a given slice with random numbers of the wrapped distribution.
+    ///
+    /// This calls the `generate` method once for every entry of the slice.
+    ///
+    /// # Arguments
+    ///
+    /// * buffer - A mutable slice of `f64` which is overwritten completely.
+    fn fill(&mut self, buffer: &mut [f64]) {
+        for random in buffer.iter_mut() {
+            *random = self.generate();
+            debug_assert!(!random.is_nan(), "generated a NaN value");
+        }
+    }
+
+    /// Generates multiple random numbers of the wrapped distribution.
+    ///
+    /// This fills a new `Vec<f64>` using the `fill` method.
+    ///
+    /// # Arguments
+    ///
+    /// * number - A usize of the number of random numbers in the `Vec`.
+    ///
+    /// # Returns
+    ///
+    /// A Vector of `f64` values randomly generated according to the wrapped distribution.
+    fn generate_multiple(&mut self, number: usize) -> Vec<f64> {
+        let mut randoms: Vec<f64> = vec![0_f64; number];
+        self.fill(&mut randoms);
+        randoms
+    }
+
+    /// Generates a fixed number of random numbers of the wrapped distribution on the stack.
+    ///
+    /// This fills an array using the `fill` method without allocating, unlike `generate_multiple`.
+    ///
+    /// # Returns
+    ///
+    /// An array of `N` `f64` values randomly generated according to the wrapped distribution.
+    fn generate_array<const N: usize>(&mut self) -> [f64; N] {
+        let mut randoms: [f64; N] = [0_f64; N];
+        self.fill(&mut randoms);
+        randoms
+    }
+}
@@ -0,0 +1,80 @@
+//! This module contains the implementation of the `StreamManager` struct and its methods.
+
+use crate::hashing::fnv1a;
+use crate::rng::Rng;
+
+/// A struct handing out named, reproducible random number streams derived from a single master seed.
+///
+/// Every name selects a position in the sequence of the master `Rng`, given by the FNV-1a hash of the name.
+/// The stream of the name starts at this position, which is reached with `Rng::jump` in logarithmic time.
+/// Since the period of the generator is `2^64`, streams of different names practically never overlap.
+///
+/// The same name always results in the same stream, independent of the order or number of requested streams.
+/// This allows common random numbers: when comparing simulation scenarios,
+/// every source of randomness (e.g. `"demand"` or `"service"`) uses the same numbers in every scenario,
+/// so the differences of the results are caused by the scenarios and not by the noise.
+///
+/// # Fields
+///
+/// * `seed` - The master seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamManager {
+    /// The master seed.
+    seed: u64,
+}
+
+impl StreamManager {
+    /// Creates a new `StreamManager` instance with a given master seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` representing the master seed.
+    ///
+    /// # Returns
+    ///
+    /// A `StreamManager` instance.
+    pub fn new(seed: u64) -> StreamManager {
+        StreamManager { seed }
+    }
+
+    /// Returns the master seed.
+    ///
+    /// # Returns
+    ///
+    /// The master seed as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the seed of the stream with a given name.
+    ///
+    /// Seeding a distribution with it via `set_seed` makes the distribution use the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the stream.
+    ///
+    /// # Returns
+    ///
+    /// The seed of the stream as a `u64`.
+    pub fn stream_seed(&self, name: &str) -> u64 {
+        let mut rng: Rng = Rng::new_seed(self.seed);
+        rng.jump(fnv1a(name.as_bytes()));
+        Rng::seed_of_state(rng.state)
+    }
+
+    /// Returns a new random number generator for the stream with a given name.
+    ///
+    /// Every call returns a generator starting at the beginning of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the stream.
+    ///
+    /// # Returns
+    ///
+    /// A `Rng` producing the values of the stream.
+    pub fn get(&self, name: &str) -> Rng {
+        Rng::new_seed(self.stream_seed(name))
+    }
+}
@@ -1,27 +1,51 @@
 //! This module contains the implementation of the `StudentsT` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::math;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
+/// The algorithms available to generate values of a `StudentsT` distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StudentsTAlgorithm {
+    /// Bailey's polar method. It takes `O(1)` time and is valid for all degrees of freedom.
+    Bailey,
+
+    /// The ratio of a standard normal value and the root of a sum of `k` squared standard normal values.
+    /// It takes `O(k)` time and is only valid for integer degrees of freedom, but serves as a simple reference.
+    NormalSum,
+}
+
 /// A struct for generating random variables from a StudentsT distribution.
 ///
-/// This struct uses a uniformly distributed random number generator (`Rng`) to generate values from a ChiSquared and standard normal distribution.
-/// The `gen` method generates a random variate according to the Normal distribution.
+/// This struct uses a uniformly distributed random number generator (`Rng`) to generate values
+/// from the StudentsT distribution with real-valued degrees of freedom `k`.
+/// The `gen` method generates a random variate according to the StudentsT distribution.
 ///
 /// # Fields
 ///
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `k` - The degrees of freedom (k) the distribution.
+/// * `algorithm` - The algorithm used to generate values.
+#[derive(Debug, Clone)]
 pub struct StudentsT {
     /// The uniformly distributed random number generator.
     rng: Rng,
 
-    /// The degrees of freedom (k) the distribution.
-    k: i32,
+    /// The degrees of freedom (k) the distribution. Must be a positive number.
+    k: f64,
+
+    /// The algorithm used to generate values.
+    algorithm: StudentsTAlgorithm,
 }
 
 auto_rng_trait!(StudentsT);
+auto_builder!(StudentsT, StudentsTBuilder { k: f64 });
+auto_params!(StudentsT { k: f64 } keep algorithm);
+auto_display!(StudentsT { "k" = k });
 
 impl StudentsT {
     /// Creates a new `StudentsT` instance with a given degrees of freedom.
@@ -30,37 +54,106 @@ impl StudentsT {
     ///
     /// # Arguments
     ///
-    /// * `k` - A `i32` representing the degrees of freedom (k) of the StudentsT distribution.
-    /// It must be a positive integer.
+    /// * `k` - A `f64` representing the degrees of freedom (k) of the StudentsT distribution.
+    /// It must be a positive number, but does not need to be an integer.
     ///
     /// # Returns
     ///
     /// * `Ok(StudentsT)` - Returns an instance of `StudentsT` if the degree of freedom is positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if the degree of freedom is less than or equal to 0.
-    pub fn new(k: i32) -> Result<StudentsT, RngError> {
-        RngError::check_positive(k as f64)?;
+    /// * `Err(RngError)` - Returns a `PositiveError` if the degree of freedom is less than or equal to 0. Returns a `NotFiniteError` if it is infinite or NaN.
+    pub fn new(k: f64) -> Result<StudentsT, RngError> {
+        RngError::check_finite(k)?;
+        RngError::check_positive(k)?;
+
+        Ok(StudentsT {
+            rng: Rng::new(),
+            k,
+            algorithm: StudentsTAlgorithm::Bailey,
+        })
+    }
+
+    /// Sets the algorithm used to generate values.
+    ///
+    /// By default `StudentsTAlgorithm::Bailey` is used.
+    /// Overriding it is useful to validate Bailey's method against the textbook definition or to compare their speed.
+    /// `StudentsTAlgorithm::NormalSum` falls back to `Bailey` if `k` is not an integer, where it is not valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - The `StudentsTAlgorithm` to use.
+    pub fn set_algorithm(&mut self, algorithm: StudentsTAlgorithm) {
+        self.algorithm = algorithm;
+    }
 
-        Ok(StudentsT { rng: Rng::new(), k })
+    /// Returns the algorithm used to generate values.
+    ///
+    /// # Returns
+    ///
+    /// The `StudentsTAlgorithm` that is used for the degrees of freedom.
+    pub fn algorithm(&self) -> StudentsTAlgorithm {
+        match self.algorithm {
+            StudentsTAlgorithm::NormalSum if self.k.fract() == 0_f64 => {
+                StudentsTAlgorithm::NormalSum
+            }
+            _ => StudentsTAlgorithm::Bailey,
+        }
     }
 
     /// Generates a random value from the StudentsT distribution.
     ///
-    /// This method generates a random variate according to the StudentsT distribution using the formula:
-    /// ```text
-    /// X = Z / sqrt(χ² / k)
-    /// ```
-    /// where `Z` is standard normal distributed und `χ²` is ChiSquared distributed with `k` degrees of freedom.
+    /// The algorithm is chosen by `StudentsT::algorithm`.
     ///
     /// # Returns
     ///
     /// A `f64` value generated from the StudentsT distribution.
     pub fn generate(&mut self) -> f64 {
+        match self.algorithm() {
+            StudentsTAlgorithm::NormalSum => self.generate_normal_sum(),
+            StudentsTAlgorithm::Bailey => self.generate_bailey(),
+        }
+    }
+
+    /// Generates a random value with Bailey's polar method.
+    ///
+    /// A point `(U, V)` is drawn uniformly from the unit disc, i.e. `U` and `V` are uniformly distributed on [-1, 1]
+    /// and rejected unless `W = U² + V² < 1`. Then
+    /// ```text
+    /// X = U sqrt(k (W^(-2/k) - 1) / W)
+    /// ```
+    /// is StudentsT distributed with `k` degrees of freedom.
+    /// On average `4 / π ≈ 1.27` points are drawn per value, independently of `k`.
+    fn generate_bailey(&mut self) -> f64 {
+        loop {
+            let u: f64 = 2_f64 * self.rng.generate() - 1_f64;
+            let v: f64 = 2_f64 * self.rng.generate() - 1_f64;
+            let w: f64 = u * u + v * v;
+            if w < 1_f64 && w > 0_f64 {
+                return u * (self.k * (math::powf(w, -2_f64 / self.k) - 1_f64) / w).sqrt();
+            }
+        }
+    }
+
+    /// Generates a random value using the formula:
+    /// ```text
+    /// X = Z / sqrt(χ² / k)
+    /// ```
+    /// where `Z` is standard normal distributed und `χ²` is the sum of `k` squared standard normal values.
+    fn generate_normal_sum(&mut self) -> f64 {
         let mut sum: f64 = 0_f64;
 
-        for _ in 0_i32..self.k {
+        for _ in 0_u64..self.k as u64 {
             sum += self.rng.gen_standard_normal().powi(2_i32);
         }
 
-        self.rng.gen_standard_normal() / (sum / self.k as f64).sqrt()
+        self.rng.gen_standard_normal() / (sum / self.k).sqrt()
+    }
+}
+
+impl Default for StudentsT {
+    /// Creates a `StudentsT` instance with 1 degree of freedom, which is standard Cauchy distributed.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        StudentsT::new(1_f64).unwrap()
     }
 }
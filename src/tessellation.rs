@@ -0,0 +1,246 @@
+//! This module contains the implementation of the `HardCore` and `PolygonSampler` structs and their methods.
+//!
+//! Both are helpers for random tessellations.
+//! `HardCore` generates seed points which keep a minimum distance to each other, e.g. as the sites of a Voronoi diagram.
+//! `PolygonSampler` generates points uniformly distributed inside a polygonal cell of such a diagram.
+
+use crate::auto_seed_methods;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating point sets with a minimum spacing in a rectangle.
+///
+/// This is a hard-core point process generated by Matérn type II thinning.
+/// A number of candidate points is placed uniformly in `[0, width] x [0, height]`
+/// and every candidate receives a uniformly distributed mark.
+/// A candidate is kept if there is no other candidate with a smaller mark closer than `min_distance`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `width` - The width of the rectangle. Must be a positive number.
+/// * `height` - The height of the rectangle. Must be a positive number.
+/// * `min_distance` - The minimal distance between two points. Must be a positive number.
+#[derive(Debug, Clone)]
+pub struct HardCore {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The width of the rectangle.
+    width: f64,
+
+    /// The height of the rectangle.
+    height: f64,
+
+    /// The minimal distance between two points.
+    min_distance: f64,
+
+    /// The square of the minimal distance.
+    /// This is used to avoid square roots when comparing distances.
+    min_distance_squared: f64,
+}
+
+auto_seed_methods!(HardCore);
+
+impl HardCore {
+    /// Creates a new `HardCore` instance for a given rectangle and minimal distance.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - A `f64` representing the width of the rectangle. It must be a positive number.
+    /// * `height` - A `f64` representing the height of the rectangle. It must be a positive number.
+    /// * `min_distance` - A `f64` representing the minimal distance between two points. It must be a positive number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HardCore)` - Returns an instance of `HardCore` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if any parameter is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn new(width: f64, height: f64, min_distance: f64) -> Result<HardCore, RngError> {
+        RngError::check_finite(width)?;
+        RngError::check_finite(height)?;
+        RngError::check_finite(min_distance)?;
+        RngError::check_positive(width)?;
+        RngError::check_positive(height)?;
+        RngError::check_positive(min_distance)?;
+
+        Ok(HardCore {
+            rng: Rng::new(),
+            width,
+            height,
+            min_distance,
+            min_distance_squared: min_distance * min_distance,
+        })
+    }
+
+    /// Generates a set of points with the minimal spacing.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - A `usize` representing the number of candidate points before the thinning.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<[f64; 2]>` containing the kept points. No two of them are closer than `min_distance`.
+    ///
+    /// # Notes
+    ///
+    /// Every candidate is compared with every other candidate, so this takes `O(candidates²)` time.
+    /// The number of kept points is bounded by the packing density, so increasing `candidates` far beyond
+    /// `width * height / min_distance²` only costs time.
+    pub fn generate(&mut self, candidates: usize) -> Vec<[f64; 2]> {
+        let mut points: Vec<[f64; 2]> = Vec::with_capacity(candidates);
+        let mut marks: Vec<f64> = Vec::with_capacity(candidates);
+
+        for _ in 0_usize..candidates {
+            points.push([
+                self.rng.generate() * self.width,
+                self.rng.generate() * self.height,
+            ]);
+            marks.push(self.rng.generate());
+        }
+
+        let mut kept: Vec<[f64; 2]> = Vec::new();
+        for i in 0_usize..candidates {
+            let dominated: bool = (0_usize..candidates).any(|j| {
+                j != i
+                    && marks[j] < marks[i]
+                    && Self::distance_squared(points[i], points[j]) < self.min_distance_squared
+            });
+            if !dominated {
+                kept.push(points[i]);
+            }
+        }
+        kept
+    }
+
+    /// Calculates the squared euclidean distance between two points.
+    fn distance_squared(a: [f64; 2], b: [f64; 2]) -> f64 {
+        (a[0] - b[0]).powi(2_i32) + (a[1] - b[1]).powi(2_i32)
+    }
+}
+
+/// A struct for generating points uniformly distributed inside a convex polygon.
+///
+/// The polygon is split into triangles fanning out from the first vertex.
+/// A triangle is chosen with a probability proportional to its area
+/// and a uniformly distributed point inside of it is generated.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `vertices` - The vertices of the polygon in order.
+/// * `cumulative_areas` - The cumulative areas of the triangles, normalized to end with 1.
+///
+/// # Notes
+///
+/// The polygon has to be convex, which is always the case for the cells of a Voronoi diagram.
+/// For non-convex polygons the fan triangulation may cover points outside the polygon.
+#[derive(Debug, Clone)]
+pub struct PolygonSampler {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The vertices of the polygon in order.
+    vertices: Vec<[f64; 2]>,
+
+    /// The normalized cumulative areas of the fan triangles.
+    cumulative_areas: Vec<f64>,
+}
+
+auto_seed_methods!(PolygonSampler);
+
+impl PolygonSampler {
+    /// Creates a new `PolygonSampler` instance for a given convex polygon.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - A `Vec<[f64; 2]>` containing the vertices of the polygon in clockwise or counterclockwise order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PolygonSampler)` - Returns an instance of `PolygonSampler` if the polygon is valid.
+    /// * `Err(RngError)` - Returns an `EmptyError` if there are no vertices or a `PositiveError` if the area of the polygon is 0.
+    pub fn new(vertices: Vec<[f64; 2]>) -> Result<PolygonSampler, RngError> {
+        RngError::check_empty(&vertices)?;
+
+        let mut cumulative_areas: Vec<f64> =
+            Vec::with_capacity(vertices.len().saturating_sub(2_usize));
+        let mut total: f64 = 0_f64;
+        for i in 1_usize..vertices.len().saturating_sub(1_usize) {
+            total += Self::triangle_area(vertices[0], vertices[i], vertices[i + 1_usize]);
+            cumulative_areas.push(total);
+        }
+        RngError::check_positive(total)?;
+
+        for area in cumulative_areas.iter_mut() {
+            *area /= total;
+        }
+
+        Ok(PolygonSampler {
+            rng: Rng::new(),
+            vertices,
+            cumulative_areas,
+        })
+    }
+
+    /// Generates a point uniformly distributed inside the polygon.
+    ///
+    /// Inside the chosen triangle `(A, B, C)` the point is generated by
+    /// ```text
+    /// A + U (B - A) + V (C - A)
+    /// ```
+    /// where `U` and `V` are uniformly distributed and reflected to `(1 - U, 1 - V)` if `U + V > 1`.
+    ///
+    /// # Returns
+    ///
+    /// A `[f64; 2]` uniformly distributed inside the polygon.
+    pub fn generate(&mut self) -> [f64; 2] {
+        let uni: f64 = self.rng.generate();
+        let index: usize = self
+            .cumulative_areas
+            .partition_point(|&area| area < uni)
+            .min(self.cumulative_areas.len() - 1_usize);
+
+        let a: [f64; 2] = self.vertices[0];
+        let b: [f64; 2] = self.vertices[index + 1_usize];
+        let c: [f64; 2] = self.vertices[index + 2_usize];
+
+        let mut u: f64 = self.rng.generate();
+        let mut v: f64 = self.rng.generate();
+        if u + v > 1_f64 {
+            u = 1_f64 - u;
+            v = 1_f64 - v;
+        }
+
+        [
+            a[0] + u * (b[0] - a[0]) + v * (c[0] - a[0]),
+            a[1] + u * (b[1] - a[1]) + v * (c[1] - a[1]),
+        ]
+    }
+
+    /// Generates multiple points uniformly distributed inside the polygon.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` representing the number of points.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<[f64; 2]>` of points uniformly distributed inside the polygon.
+    pub fn generate_multiple(&mut self, number: usize) -> Vec<[f64; 2]> {
+        (0_usize..number).map(|_| self.generate()).collect()
+    }
+
+    /// Calculates the area of a triangle.
+    ///
+    /// # Returns
+    ///
+    /// The non-negative area of the triangle spanned by `a`, `b` and `c`.
+    fn triangle_area(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+        ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() * 0.5_f64
+    }
+}
@@ -0,0 +1,49 @@
+//! This module contains the `TieBreaking` enum shared by the discrete distributions sampled by inversion.
+
+/// The rules for a uniformly distributed number `U` lying exactly on a step of the cumulative distribution function `F`.
+///
+/// The discrete distributions sampled by inversion generate the value `k` whose step of `F` contains `U`.
+/// If `U` equals `F(k)`, the value is either `k` or the next value of the support,
+/// and systems replicating each other's samples have to agree on this choice.
+///
+/// `U` is a multiple of `2^-53` in [0, 1), so a tie is only possible for steps on this grid,
+/// e.g. for `RandInt` with a power of two values or for `Binomial` and `Geometric` with `p = 0.5`.
+/// It happens with a probability of `2^-53` per step, so the rules change the probability of every value by at most `2^-53`.
+///
+/// * `Upper` assigns the values the half-open intervals `[F(k - 1), F(k))`, which exactly partition the range [0, 1) of `U`.
+///   Up to the rounding of `F` to the grid, the values are generated without bias.
+/// * `Lower` assigns the values the intervals `(F(k - 1), F(k)]` and `U = 0` to the smallest value of positive probability.
+///   Every step on the grid moves `2^-53` of probability to the value below it,
+///   so the smallest value is generated slightly too often and the largest one on a step of the grid slightly too rarely.
+///   This is the definition of the quantile function, e.g. used by `Quantile::quantile`,
+///   and of inversion in many other systems, which draw `U` from (0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreaking {
+    /// A tie generates the next value, i.e. the value is the smallest `k` with `F(k) > U`. This is the default.
+    Upper,
+
+    /// A tie generates the value of the step, i.e. the value is the smallest `k` with `F(k) >= U`.
+    Lower,
+}
+
+impl TieBreaking {
+    /// Returns whether a value with the cumulative probability `cdf` is smaller than the value generated for `uniform`.
+    ///
+    /// The generated value is the first one for which this is false, e.g. found by `slice::partition_point`.
+    pub(crate) fn precedes(self, cdf: f64, uniform: f64) -> bool {
+        match self {
+            TieBreaking::Upper => cdf <= uniform,
+            TieBreaking::Lower => cdf < uniform,
+        }
+    }
+
+    /// Returns the index of the unit cell `[i, i + 1)` containing a non-negative position, where a tie is broken by the rule.
+    ///
+    /// It is `floor(x)` for `Upper` and `ceil(x) - 1` for `Lower`, but at least 0.
+    pub(crate) fn cell(self, position: f64) -> f64 {
+        match self {
+            TieBreaking::Upper => position.floor(),
+            TieBreaking::Lower => (position.ceil() - 1_f64).max(0_f64),
+        }
+    }
+}
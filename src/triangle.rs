@@ -1,6 +1,12 @@
 //! This module contains the implementation of the `Triangle` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::auxiliary::{fit_three_point, mean, variance};
+use crate::density::Density;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -23,6 +29,7 @@ use crate::rng_error::RngError;
 /// The calculations could be speed up a little bit by also adding `b - a`, `c - a` and `b - c` to the fields,
 /// because they are calculated multiple times in the `gen` method.
 /// The increase in performance would probably be negligible.
+#[derive(Debug, Clone)]
 pub struct Triangle {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -41,6 +48,20 @@ pub struct Triangle {
 }
 
 auto_rng_trait!(Triangle);
+auto_builder!(
+    Triangle,
+    TriangleBuilder {
+        a: f64,
+        b: f64,
+        c: f64
+    }
+);
+auto_params!(Triangle {
+    a: f64,
+    b: f64,
+    c: f64
+});
+auto_display!(Triangle { "a" = a, "b" = b, "c" = c });
 
 impl Triangle {
     /// Creates a new `Triangle` instance with a given alpha and Triangle.
@@ -56,8 +77,11 @@ impl Triangle {
     /// # Returns
     ///
     /// * `Ok(Triangle)` - Returns an instance of `Triangle` if the parameters are valid.
-    /// * `Err(RngError)` - Returns a `OrderError` or `IntervalError` if the parameters are invalid.
+    /// * `Err(RngError)` - Returns a `OrderError` or `IntervalError` if the parameters are invalid. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(a: f64, b: f64, c: f64) -> Result<Self, RngError> {
+        RngError::check_finite(a)?;
+        RngError::check_finite(b)?;
+        RngError::check_finite(c)?;
         RngError::check_order(a, b)?;
         RngError::check_interval(c, a, b)?;
 
@@ -72,6 +96,30 @@ impl Triangle {
         })
     }
 
+    /// Creates a new symmetric `Triangle` instance with a given center and half width.
+    ///
+    /// The distribution has the support `[center - half_width, center + half_width]` and the mode `center`,
+    /// e.g. for estimates like "5 days, give or take 2".
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - A `f64` representing the mode, which is also the mean and the median.
+    /// * `half_width` - A `f64` representing the distance of the bounds to the center. It must be positive.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Triangle)` - Returns an instance of `Triangle` if the parameters are valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the half width is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
+    pub fn symmetric(center: f64, half_width: f64) -> Result<Self, RngError> {
+        RngError::check_finite(center)?;
+        RngError::check_finite(half_width)?;
+        RngError::check_positive(half_width)?;
+
+        Triangle::new(center - half_width, center + half_width, center)
+    }
+
     /// Generates a random value from the Triangle distribution.
     ///
     /// # Returns
@@ -105,4 +153,178 @@ impl Triangle {
     fn calculate_distribution_c(a: f64, b: f64, c: f64) -> f64 {
         (c - a) / (b - a)
     }
+
+    /// Estimates the parameters of a `Triangle` distribution from samples using the method of moments.
+    ///
+    /// With the width `w = b - a` and the relative position of the mode `r = (c - a) / w`,
+    /// the mean and the variance of the distribution are
+    /// ```text
+    /// E[X] = a + w (1 + r) / 3
+    /// Var[X] = w² (1 - r + r²) / 18
+    /// ```
+    /// Two moments cannot determine three parameters, so the shape `r` is estimated from the position
+    /// of the sample mean `m` between the smallest and the largest sample, which does not depend on the scale:
+    /// ```text
+    /// r = 3 (m - min) / (max - min) - 1
+    /// ```
+    /// clamped to [0, 1]. Then `w` and `a` are solved from the sample mean and the sample variance `v`, which are matched exactly:
+    /// ```text
+    /// w = sqrt(18 v / (1 - r + r²))
+    /// a = m - w (1 + r) / 3
+    /// ```
+    /// Unlike the smallest and the largest sample, the fitted bounds are not biased towards the inside of the support.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Triangle)` - Returns the fitted instance of `Triangle`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or an `OrderError` if all samples are equal.
+    pub fn fit_moments(samples: &[f64]) -> Result<Self, RngError> {
+        RngError::check_empty(samples)?;
+
+        let minimum: f64 = samples.iter().copied().fold(f64::INFINITY, f64::min);
+        let maximum: f64 = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        RngError::check_order(minimum, maximum)?;
+
+        let sample_mean: f64 = mean(samples);
+        let sample_variance: f64 = variance(samples, sample_mean);
+        let r: f64 =
+            (3_f64 * (sample_mean - minimum) / (maximum - minimum) - 1_f64).clamp(0_f64, 1_f64);
+
+        let width: f64 = (18_f64 * sample_variance / (1_f64 - r + r * r)).sqrt();
+        let a: f64 = sample_mean - width * (1_f64 + r) / 3_f64;
+        Triangle::new(a, a + width, a + r * width)
+    }
+
+    /// Creates a new `Triangle` instance from a three-point estimate.
+    ///
+    /// Other than the common practice of using the P10 and P90 values as the bounds `a` and `b`,
+    /// the parameters are chosen such that the 10 %, 50 % and 90 % quantiles of the distribution match the estimates exactly.
+    /// The relative position of the mode `(c - a) / (b - a)` is found by bisection,
+    /// then `a` and `b` follow from the P10 and P90 values.
+    ///
+    /// This is only possible if the estimate is not too skewed, i.e.
+    /// ```text
+    /// 0.38 <= (P50 - P10) / (P90 - P10) <= 0.62
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `p10` - A `f64` representing the 10 % quantile.
+    /// * `p50` - A `f64` representing the median. It must be bigger than `p10`.
+    /// * `p90` - A `f64` representing the 90 % quantile. It must be bigger than `p50`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Triangle)` - Returns an instance of `Triangle` if the estimates are valid.
+    /// * `Err(RngError)` - Returns an `OrderError` if the estimates are not increasing or an `IntervalError` if they are too skewed.
+    pub fn from_p10_p50_p90(p10: f64, p50: f64, p90: f64) -> Result<Self, RngError> {
+        let (a, width, mode) = fit_three_point(p10, p50, p90, |p, mode| {
+            if p < mode {
+                (p * mode).sqrt()
+            } else {
+                1_f64 - ((1_f64 - p) * (1_f64 - mode)).sqrt()
+            }
+        })?;
+
+        Triangle::new(a, a + width, a + mode * width)
+    }
+}
+
+impl Default for Triangle {
+    /// Creates a symmetric `Triangle` instance on [0, 1] with the mode 0.5.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Triangle::new(0_f64, 1_f64, 0.5_f64).unwrap()
+    }
+}
+
+impl Quantile for Triangle {
+    /// Evaluates the quantile function of the Triangle distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = a + sqrt(p (b - a) (c - a))          for p < F(c)
+    /// Q(p) = b - sqrt((1 - p) (b - a) (b - c))    for p >= F(c)
+    /// ```
+    /// where `F(c) = (c - a) / (b - a)` is the value of the distribution function at the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        if p < self.distribution_c {
+            self.a + (p * (self.b - self.a) * (self.c - self.a)).sqrt()
+        } else {
+            self.b - ((1_f64 - p) * (self.b - self.a) * (self.b - self.c)).sqrt()
+        }
+    }
+}
+
+impl Density for Triangle {
+    /// Evaluates the density of the Triangle distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 2 (x - a) / ((b - a) (c - a))    for a <= x < c
+    /// f(x) = 2 (b - x) / ((b - a) (b - c))    for c <= x <= b
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            0_f64
+        } else if x < self.c {
+            2_f64 * (x - self.a) / ((self.b - self.a) * (self.c - self.a))
+        } else if x > self.c {
+            2_f64 * (self.b - x) / ((self.b - self.a) * (self.b - self.c))
+        } else {
+            2_f64 / (self.b - self.a)
+        }
+    }
+
+    /// Evaluates the distribution function of the Triangle distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = (x - a)² / ((b - a) (c - a))        for a <= x < c
+    /// F(x) = 1 - (b - x)² / ((b - a) (b - c))    for c <= x <= b
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.a {
+            0_f64
+        } else if x >= self.b {
+            1_f64
+        } else if x < self.c {
+            (x - self.a) * (x - self.a) / ((self.b - self.a) * (self.c - self.a))
+        } else {
+            1_f64 - (self.b - x) * (self.b - x) / ((self.b - self.a) * (self.b - self.c))
+        }
+    }
 }
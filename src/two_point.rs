@@ -0,0 +1,117 @@
+//! This module contains the implementation of the `TwoPoint` struct and its methods.
+
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
+use crate::auto_rng_trait;
+use crate::quantile::Quantile;
+use crate::rng::{Rng, RngTrait};
+use crate::rng_error::RngError;
+
+/// A struct for generating random variables from a two-point distribution.
+///
+/// The value `b` is generated with the probability `p` and the value `a` otherwise,
+/// i.e. the distribution is a Bernoulli distribution scaled to the values `a` and `b`:
+/// ```text
+/// X = a + (b - a) B    with B ~ Bernoulli(p)
+/// ```
+/// Typical uses are all-or-nothing outcomes like a loss that occurs or not, or a machine that works or fails.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `a` - The value generated with the probability `1 - p`.
+/// * `b` - The value generated with the probability `p`. Must be bigger than a.
+/// * `p` - The probability of `b`. Must be between 0 and 1.
+#[derive(Debug, Clone)]
+pub struct TwoPoint {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// The lower value of the distribution.
+    a: f64,
+
+    /// The upper value of the distribution.
+    b: f64,
+
+    /// The probability of the upper value.
+    p: f64,
+}
+
+auto_rng_trait!(TwoPoint);
+auto_builder!(
+    TwoPoint,
+    TwoPointBuilder {
+        a: f64,
+        b: f64,
+        p: f64
+    }
+);
+auto_params!(TwoPoint {
+    a: f64,
+    b: f64,
+    p: f64
+});
+auto_display!(TwoPoint { "a" = a, "b" = b, "p" = p });
+
+impl TwoPoint {
+    /// Creates a new `TwoPoint` instance with two values and the probability of the upper one.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A `f64` representing the lower value.
+    /// * `b` - A `f64` representing the upper value. It must be bigger than a.
+    /// * `p` - A `f64` representing the probability of `b`. It must be between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TwoPoint)` - Returns an instance of `TwoPoint` if the parameters are valid.
+    /// * `Err(RngError)` - Returns an `OrderError` if `a` is not smaller than `b` or a `ProbabilityError` if `p` is not a probability. Returns a `NotFiniteError` if a value is infinite or NaN.
+    pub fn new(a: f64, b: f64, p: f64) -> Result<TwoPoint, RngError> {
+        RngError::check_finite(a)?;
+        RngError::check_finite(b)?;
+        RngError::check_order(a, b)?;
+        RngError::check_probability(p)?;
+
+        Ok(TwoPoint {
+            rng: Rng::new(),
+            a,
+            b,
+            p,
+        })
+    }
+
+    /// Generates a random value from the two-point distribution.
+    ///
+    /// # Returns
+    ///
+    /// * `b` - If a uniformly distributed number is less than `p`.
+    /// * `a` - Otherwise.
+    pub fn generate(&mut self) -> f64 {
+        if self.rng.generate() < self.p {
+            self.b
+        } else {
+            self.a
+        }
+    }
+}
+
+impl Default for TwoPoint {
+    /// Creates a `TwoPoint` instance of the values 0 and 1 with the probability 0.5, i.e. a fair coin.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        TwoPoint::new(0_f64, 1_f64, 0.5_f64).unwrap()
+    }
+}
+
+impl Quantile for TwoPoint {
+    /// Evaluates the quantile function of the two-point distribution.
+    ///
+    /// The quantile of a probability `q` is `a` for `q <= 1 - p` and `b` otherwise.
+    fn quantile(&self, p: f64) -> f64 {
+        if p <= 1_f64 - self.p { self.a } else { self.b }
+    }
+}
@@ -1,12 +1,19 @@
 //! This module contains the implementation of the `Uniform` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
 /// A struct for generating random variables from a uniform distribution between a and b.
 ///
 /// This struct uses a uniformly distributed random number generator (`Rng`) between 0 and 1 to simulate the Uniform distribution.
+#[derive(Debug, Clone)]
 pub struct Uniform {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -19,6 +26,9 @@ pub struct Uniform {
 }
 
 auto_rng_trait!(Uniform);
+auto_builder!(Uniform, UniformBuilder { a: f64, b: f64 });
+auto_params!(Uniform { a: f64, b: f64 });
+auto_display!(Uniform { "a" = a, "b" = b });
 
 impl Uniform {
     /// Creates a new `Uniform` instance with a specified probability.
@@ -33,8 +43,10 @@ impl Uniform {
     /// # Returns
     ///
     /// * `Ok(Uniform)` - Returns an instance of `Uniform` if the bounds are valid.
-    /// * `Err(RngError)` - Returns a `OderError` if the bounds are equal or wrongly ordered.
+    /// * `Err(RngError)` - Returns a `OderError` if the bounds are equal or wrongly ordered. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(a: f64, b: f64) -> Result<Uniform, RngError> {
+        RngError::check_finite(a)?;
+        RngError::check_finite(b)?;
         RngError::check_order(a, b)?;
 
         Ok(Uniform {
@@ -58,4 +70,106 @@ impl Uniform {
 
         self.a + (self.b - self.a) * uni
     }
+
+    /// Estimates the bounds of a `Uniform` distribution from samples using maximum likelihood.
+    ///
+    /// The estimators are the smallest and the largest sample.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Uniform)` - Returns the fitted instance of `Uniform`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or an `OrderError` if all samples are equal.
+    pub fn fit(samples: &[f64]) -> Result<Uniform, RngError> {
+        RngError::check_empty(samples)?;
+
+        let a: f64 = samples.iter().copied().fold(f64::INFINITY, f64::min);
+        let b: f64 = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Uniform::new(a, b)
+    }
+}
+
+impl Default for Uniform {
+    /// Creates a standard `Uniform` instance on [0, 1].
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Uniform::new(0_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for Uniform {
+    /// Evaluates the quantile function of the Uniform distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = a + (b - a) p
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.a + (self.b - self.a) * p
+    }
+}
+
+impl Density for Uniform {
+    /// Evaluates the density of the Uniform distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = 1 / (b - a)    for a <= x <= b
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            0_f64
+        } else {
+            1_f64 / (self.b - self.a)
+        }
+    }
+
+    /// Evaluates the distribution function of the Uniform distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = (x - a) / (b - a)    for a <= x <= b
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        ((x - self.a) / (self.b - self.a)).clamp(0_f64, 1_f64)
+    }
+
+    /// Calculates the differential entropy of the Uniform distribution in nats.
+    ///
+    /// ```text
+    /// H = ln(b - a)
+    /// ```
+    fn entropy(&self) -> f64 {
+        math::ln(self.b - self.a)
+    }
 }
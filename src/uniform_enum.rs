@@ -0,0 +1,143 @@
+//! This module contains the `VariantCount` trait and the implementation of the `UniformEnum` struct and its methods.
+
+use std::marker::PhantomData;
+
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A trait for enums with a fixed number of variants, which can be constructed from their index.
+///
+/// For enums without fields it is implemented with the `impl_variant_count` macro.
+///
+/// ```
+/// use random::{UniformEnum, impl_variant_count};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// impl_variant_count!(Suit { Clubs, Diamonds, Hearts, Spades });
+///
+/// let mut suits: UniformEnum<Suit> = UniformEnum::new().unwrap();
+/// let suit: Suit = suits.generate();
+/// ```
+pub trait VariantCount: Sized {
+    /// The number of variants.
+    const VARIANT_COUNT: usize;
+
+    /// Returns the variant with a given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - A `usize` smaller than `VARIANT_COUNT`.
+    ///
+    /// # Returns
+    ///
+    /// The variant with the given index.
+    fn from_variant_index(index: usize) -> Self;
+}
+
+/// Implements `VariantCount` for an enum without fields.
+///
+/// The variants are listed in braces after the name of the enum.
+/// Their index is their position in this list.
+#[macro_export]
+macro_rules! impl_variant_count {
+    ($t:ident { $($variant:ident),+ $(,)? }) => {
+        impl $crate::VariantCount for $t {
+            const VARIANT_COUNT: usize = [$(stringify!($variant)),+].len();
+
+            fn from_variant_index(index: usize) -> Self {
+                [$($t::$variant),+]
+                    .into_iter()
+                    .nth(index)
+                    .expect("the variant index must be smaller than the number of variants")
+            }
+        }
+    };
+}
+
+/// A struct for generating uniformly distributed variants of an enum.
+///
+/// Every variant has exactly the same probability.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+/// * `variants` - A marker for the type of the enum.
+pub struct UniformEnum<E: VariantCount> {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+
+    /// A marker for the type of the enum.
+    variants: PhantomData<E>,
+}
+
+impl<E: VariantCount> UniformEnum<E> {
+    /// Creates a new `UniformEnum` instance.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(UniformEnum)` - Returns an instance of `UniformEnum` if the enum has variants.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the enum has no variants.
+    pub fn new() -> Result<UniformEnum<E>, RngError> {
+        RngError::check_positive(E::VARIANT_COUNT as f64)?;
+
+        Ok(UniformEnum {
+            rng: Rng::new(),
+            variants: PhantomData,
+        })
+    }
+
+    /// Generates a random variant.
+    ///
+    /// # Returns
+    ///
+    /// A uniformly distributed variant of the enum.
+    pub fn generate(&mut self) -> E {
+        let index: u64 = self.rng.gen_below(E::VARIANT_COUNT as u64);
+        E::from_variant_index(index as usize)
+    }
+
+    /// Generates multiple random variants.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` of the number of variants.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of uniformly distributed variants.
+    pub fn generate_multiple(&mut self, number: usize) -> Vec<E> {
+        (0_usize..number).map(|_| self.generate()).collect()
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng.set_seed(seed);
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.rng.restart();
+    }
+}
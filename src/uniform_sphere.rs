@@ -0,0 +1,225 @@
+//! This module contains the implementation of the `UniformSphere` and `UniformBall` structs and their methods.
+
+use std::f64::consts::PI;
+
+use crate::math;
+use crate::rng::Rng;
+use crate::rng_error::RngError;
+
+/// A struct for generating uniformly distributed points on the unit sphere in `N` dimensions.
+///
+/// The unit sphere is the surface `x₁² + ... + x_N² = 1`.
+/// For `N = 2` this is the unit circle, for `N = 3` the surface of the ball in space.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+pub struct UniformSphere<const N: usize> {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+}
+
+/// A struct for generating uniformly distributed points on the unit circle.
+pub type UniformCircle = UniformSphere<2>;
+
+impl<const N: usize> UniformSphere<N> {
+    /// Creates a new `UniformSphere` instance.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(UniformSphere)` - Returns an instance of `UniformSphere` if the dimension is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the dimension `N` is 0.
+    pub fn new() -> Result<UniformSphere<N>, RngError> {
+        RngError::check_positive(N as f64)?;
+
+        Ok(UniformSphere { rng: Rng::new() })
+    }
+
+    /// Generates a uniformly distributed point on the unit sphere.
+    ///
+    /// * For `N = 1` the point is -1 or 1 with equal probability.
+    /// * For `N = 2` the point is `(cos φ, sin φ)` with an angle `φ` uniformly distributed on [0, 2π].
+    /// * For `N = 3` Marsaglia's method is used: With `(U, V)` uniformly distributed in the unit disk and `S = U² + V²`
+    ///   ```text
+    ///   X = (2 U sqrt(1 - S), 2 V sqrt(1 - S), 1 - 2 S)
+    ///   ```
+    /// * Otherwise, a vector of independent standard normal values is normalized, which is uniform by rotational symmetry.
+    ///
+    /// # Returns
+    ///
+    /// A `[f64; N]` of length 1.
+    pub fn generate(&mut self) -> [f64; N] {
+        generate_on_sphere(&mut self.rng)
+    }
+
+    /// Generates multiple uniformly distributed points on the unit sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` of the number of points.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of points on the unit sphere.
+    pub fn generate_multiple(&mut self, number: usize) -> Vec<[f64; N]> {
+        (0_usize..number).map(|_| self.generate()).collect()
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng.set_seed(seed);
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.rng.restart();
+    }
+}
+
+/// A struct for generating uniformly distributed points inside the unit ball in `N` dimensions.
+///
+/// The unit ball is the set `x₁² + ... + x_N² <= 1`.
+///
+/// # Fields
+///
+/// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
+pub struct UniformBall<const N: usize> {
+    /// The uniformly distributed random number generator.
+    rng: Rng,
+}
+
+impl<const N: usize> UniformBall<N> {
+    /// Creates a new `UniformBall` instance.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(UniformBall)` - Returns an instance of `UniformBall` if the dimension is valid.
+    /// * `Err(RngError)` - Returns a `PositiveError` if the dimension `N` is 0.
+    pub fn new() -> Result<UniformBall<N>, RngError> {
+        RngError::check_positive(N as f64)?;
+
+        Ok(UniformBall { rng: Rng::new() })
+    }
+
+    /// Generates a uniformly distributed point inside the unit ball.
+    ///
+    /// A uniformly distributed point on the unit sphere is scaled by the radius
+    /// ```text
+    /// R = U^(1 / N)
+    /// ```
+    /// where `U` is uniformly distributed on [0, 1], because the volume inside radius `r` is proportional to `r^N`.
+    ///
+    /// # Returns
+    ///
+    /// A `[f64; N]` of length at most 1.
+    pub fn generate(&mut self) -> [f64; N] {
+        let mut point: [f64; N] = generate_on_sphere(&mut self.rng);
+        let radius: f64 = math::powf(self.rng.generate(), 1_f64 / N as f64);
+
+        for coordinate in point.iter_mut() {
+            *coordinate *= radius;
+        }
+        point
+    }
+
+    /// Generates multiple uniformly distributed points inside the unit ball.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A `usize` of the number of points.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of points inside the unit ball.
+    pub fn generate_multiple(&mut self, number: usize) -> Vec<[f64; N]> {
+        (0_usize..number).map(|_| self.generate()).collect()
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Sets the seed of the random number generator to a given number.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng.set_seed(seed);
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.rng.restart();
+    }
+}
+
+/// Generates a uniformly distributed point on the unit sphere in `N` dimensions.
+fn generate_on_sphere<const N: usize>(rng: &mut Rng) -> [f64; N] {
+    let mut point: [f64; N] = [0_f64; N];
+
+    match N {
+        1_usize => {
+            point[0] = if rng.generate() < 0.5_f64 {
+                -1_f64
+            } else {
+                1_f64
+            };
+        }
+        2_usize => {
+            let angle: f64 = 2_f64 * PI * rng.generate();
+            point[0] = math::cos(angle);
+            point[1] = math::sin(angle);
+        }
+        3_usize => loop {
+            let u: f64 = 2_f64 * rng.generate() - 1_f64;
+            let v: f64 = 2_f64 * rng.generate() - 1_f64;
+            let s: f64 = u * u + v * v;
+
+            if s < 1_f64 {
+                let factor: f64 = 2_f64 * (1_f64 - s).sqrt();
+                point[0] = u * factor;
+                point[1] = v * factor;
+                point[2] = 1_f64 - 2_f64 * s;
+                break;
+            }
+        },
+        _ => loop {
+            for coordinate in point.iter_mut() {
+                *coordinate = rng.gen_standard_normal();
+            }
+            let norm: f64 = point.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+            // The zero vector has no direction
+            if norm > 0_f64 {
+                for coordinate in point.iter_mut() {
+                    *coordinate /= norm;
+                }
+                break;
+            }
+        },
+    }
+    point
+}
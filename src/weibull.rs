@@ -1,7 +1,13 @@
 //! This module contains the implementation of the `Weibull` struct and its methods.
 
+use crate::auto_builder;
+use crate::auto_display;
+use crate::auto_params;
 use crate::auto_rng_trait;
-use crate::auxiliary::simple_ln;
+use crate::auxiliary::{mean, simple_ln, variance};
+use crate::density::Density;
+use crate::math;
+use crate::quantile::Quantile;
 use crate::rng::{Rng, RngTrait};
 use crate::rng_error::RngError;
 
@@ -16,6 +22,7 @@ use crate::rng_error::RngError;
 /// * `rng` - A `Rng` used to generate uniformly distributed random numbers.
 /// * `shape` - The shape (k) of the Weibull distribution. Must be a positive number.
 /// * `scale` - The scale (λ) of the Weibull distribution. Must be a positive number.
+#[derive(Debug, Clone)]
 pub struct Weibull {
     /// The uniformly distributed random number generator.
     rng: Rng,
@@ -28,6 +35,26 @@ pub struct Weibull {
 }
 
 auto_rng_trait!(Weibull);
+auto_builder!(
+    Weibull,
+    WeibullBuilder {
+        shape: f64,
+        scale: f64
+    }
+);
+auto_params!(Weibull {
+    shape: f64,
+    scale: f64
+});
+auto_display!(Weibull { "k" = shape, "λ" = scale });
+
+impl Weibull {
+    /// The maximal number of Newton iterations used by `fit`.
+    const FIT_ITERATIONS: usize = 100_usize;
+
+    /// The relative tolerance at which the Newton iterations of `fit` stop.
+    const FIT_TOLERANCE: f64 = 1e-12_f64;
+}
 
 impl Weibull {
     /// Creates a new `Weibull` instance with a given rate.
@@ -42,8 +69,10 @@ impl Weibull {
     /// # Returns
     ///
     /// * `Ok(Weibull)` - Returns an instance of `Weibull` if `shape` and `scale` are positive.
-    /// * `Err(RngError)` - Returns a `PositiveError` if `shape` or `scale` is less than or equal to 0.
+    /// * `Err(RngError)` - Returns a `PositiveError` if `shape` or `scale` is less than or equal to 0. Returns a `NotFiniteError` if a parameter is infinite or NaN.
     pub fn new(shape: f64, scale: f64) -> Result<Weibull, RngError> {
+        RngError::check_finite(shape)?;
+        RngError::check_finite(scale)?;
         RngError::check_positive(shape)?;
         RngError::check_positive(scale)?;
 
@@ -72,6 +101,168 @@ impl Weibull {
     pub fn generate(&mut self) -> f64 {
         let uni: f64 = self.rng.generate();
 
-        self.scale * (-simple_ln(uni)).powf(1_f64 / self.shape)
+        self.scale * math::powf(-simple_ln(uni), 1_f64 / self.shape)
+    }
+
+    /// Estimates the parameters of a `Weibull` distribution from samples using maximum likelihood.
+    ///
+    /// The maximum likelihood estimator of the shape `k` solves
+    /// ```text
+    /// Σ x_i^k ln x_i / Σ x_i^k - 1/k - 1/n Σ ln x_i = 0
+    /// ```
+    /// This equation is solved with Newton's method, starting from `k = 1.2 / s` where `s` is the standard deviation of the `ln x_i`.
+    /// The scale is then estimated by
+    /// ```text
+    /// λ = (1/n Σ x_i^k)^(1/k)
+    /// ```
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - A slice of positive `f64` values the distribution is fitted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Weibull)` - Returns the fitted instance of `Weibull`.
+    /// * `Err(RngError)` - Returns an `EmptyError` if `samples` is empty or a `PositiveError` if a sample is not positive or all samples are equal.
+    pub fn fit(samples: &[f64]) -> Result<Weibull, RngError> {
+        RngError::check_empty(samples)?;
+        for &sample in samples {
+            RngError::check_positive(sample)?;
+        }
+
+        let logs: Vec<f64> = samples.iter().map(|&x| math::ln(x)).collect();
+        let log_mean: f64 = mean(&logs);
+        let log_variance: f64 = variance(&logs, log_mean);
+        RngError::check_positive(log_variance)?;
+
+        let mut shape: f64 = 1.2_f64 / log_variance.sqrt();
+        for _ in 0_usize..Self::FIT_ITERATIONS {
+            let mut sum: f64 = 0_f64;
+            let mut sum_log: f64 = 0_f64;
+            let mut sum_log_squared: f64 = 0_f64;
+            for (x, log) in samples.iter().zip(logs.iter()) {
+                let power: f64 = math::powf(*x, shape);
+                sum += power;
+                sum_log += power * log;
+                sum_log_squared += power * log * log;
+            }
+
+            let value: f64 = sum_log / sum - 1_f64 / shape - log_mean;
+            let derivative: f64 =
+                (sum_log_squared * sum - sum_log * sum_log) / (sum * sum) + 1_f64 / (shape * shape);
+            let step: f64 = value / derivative;
+            shape = (shape - step).max(0.5_f64 * shape);
+            if step.abs() < Self::FIT_TOLERANCE * shape {
+                break;
+            }
+        }
+
+        let power_mean: f64 =
+            samples.iter().map(|x| math::powf(*x, shape)).sum::<f64>() / samples.len() as f64;
+        Weibull::new(shape, math::powf(power_mean, 1_f64 / shape))
+    }
+}
+
+impl Default for Weibull {
+    /// Creates a standard `Weibull` instance with shape 1 and scale 1, which is Exponential distributed with rate 1.
+    ///
+    /// This method initializes the underlying random number generator using a system-generated seed.
+    fn default() -> Self {
+        Weibull::new(1_f64, 1_f64).unwrap()
+    }
+}
+
+impl Quantile for Weibull {
+    /// Evaluates the quantile function of the Weibull distribution.
+    ///
+    /// The quantile function is given by
+    /// ```text
+    /// Q(p) = scale (-ln(1 - p))^(1 / shape)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - A `f64` between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the `p`-quantile of the distribution.
+    fn quantile(&self, p: f64) -> f64 {
+        self.scale * math::powf(-math::ln(1_f64 - p), 1_f64 / self.shape)
+    }
+}
+
+impl Density for Weibull {
+    /// Evaluates the density of the Weibull distribution.
+    ///
+    /// The density is given by
+    /// ```text
+    /// f(x) = k/λ (x/λ)^(k - 1) exp(-(x/λ)^k)    for x >= 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing the density at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            return 0_f64;
+        }
+
+        let z: f64 = x / self.scale;
+        self.shape / self.scale
+            * math::powf(z, self.shape - 1_f64)
+            * math::exp(-math::powf(z, self.shape))
+    }
+
+    /// Evaluates the logarithm of the density of the Weibull distribution.
+    ///
+    /// The logarithm is calculated directly, so it does not underflow in the tails:
+    /// ```text
+    /// ln f(x) = ln(k / λ) + (k - 1) ln(x / λ) - (x / λ)ᵏ
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the logarithm of the density is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `ln f(x)`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            return f64::NEG_INFINITY;
+        }
+
+        let z: f64 = x / self.scale;
+        math::ln(self.shape / self.scale) + (self.shape - 1_f64) * math::ln(z)
+            - math::powf(z, self.shape)
+    }
+
+    /// Evaluates the distribution function of the Weibull distribution.
+    ///
+    /// The distribution function is given by
+    /// ```text
+    /// F(x) = 1 - exp(-(x/λ)^k)    for x >= 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A `f64` at which the distribution function is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` representing `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0_f64 {
+            0_f64
+        } else {
+            1_f64 - math::exp(-math::powf(x / self.scale, self.shape))
+        }
     }
 }
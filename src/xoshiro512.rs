@@ -0,0 +1,216 @@
+//! This module contains the implementation of the `Xoshiro512` struct and its methods.
+
+use crate::rng::{Rng, UniformSource};
+
+/// A uniformly distributed random number generator with a long period, implementing xoshiro512** of Blackman and Vigna.
+///
+/// The `Rng` is a 64 bit LCG with the period `2^64`, whose low bits are weak and whose consecutive values lie on few hyperplanes.
+/// Simulations drawing billions of values come close enough to this period for its structure to show.
+/// The xoshiro512** generator has a state of 512 bits, the period `2^512 - 1` and passes all known statistical test batteries.
+/// It is selected by passing it as a `UniformSource`, e.g. to `Quantile::sample`, instead of the `Rng`.
+///
+/// With `jump` and `long_jump` a generator is advanced by `2^256` or `2^384` values,
+/// so up to `2^256` non-overlapping streams can be derived from a single seed for parallel simulations.
+///
+/// # Fields
+///
+/// * `seed` - The seed of the random number generator.
+/// * `state` - The current state of the random number generator. It is never all zeros.
+///
+/// # Notes
+///
+/// The `Xoshiro512` is not cryptographically secure.
+#[derive(Debug, Clone)]
+pub struct Xoshiro512 {
+    /// The seed of the random number generator.
+    seed: u64,
+
+    /// The current state of the random number generator.
+    state: [u64; 8],
+}
+
+impl Xoshiro512 {
+    /// The polynomial of the jump by `2^256` values, used in the `jump` method.
+    const JUMP: [u64; 8] = [
+        0x33ED89B6E7A353F9_u64,
+        0x760083D7955323BE_u64,
+        0x2837F2FBB5F22FAE_u64,
+        0x4B8C5674D309511C_u64,
+        0xB11AC47A7BA28C25_u64,
+        0xF1BE7667092BCC1C_u64,
+        0x53851EFDB6DF0AAF_u64,
+        0x1EBBC8B23EAF25DB_u64,
+    ];
+
+    /// The polynomial of the jump by `2^384` values, used in the `long_jump` method.
+    const LONG_JUMP: [u64; 8] = [
+        0x11467FEF8F921D28_u64,
+        0xA2A819F2E79C8EA8_u64,
+        0xA8299FC284B3959A_u64,
+        0xB4D347340CA63EE1_u64,
+        0x1CB0940BEDBFF6CE_u64,
+        0xD956C5C4FA1F8E17_u64,
+        0x915E38FD4EDA93BC_u64,
+        0x5B3CCDFA5D7DACA5_u64,
+    ];
+
+    /// The scale of the high 53 bits to a value between 0 and 1, i.e. `2^(-53)`.
+    const INV_2_POW_53: f64 = 1_f64 / (1_u64 << 53_u32) as f64;
+}
+
+impl Xoshiro512 {
+    /// Creates a new `Xoshiro512` instance using the system time as the seed.
+    ///
+    /// # Returns
+    ///
+    /// A new `Xoshiro512` instance.
+    pub fn new() -> Xoshiro512 {
+        Xoshiro512::new_seed(Rng::new().seed())
+    }
+
+    /// Creates a new `Xoshiro512` instance using a specified seed.
+    ///
+    /// The 512 bits of the state are filled with `Rng::derive_seed(seed, i)` for `i = 0, ..., 7`,
+    /// which are distinct, so the state is never all zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A `u64` value used to initialize the state.
+    ///
+    /// # Returns
+    ///
+    /// A new `Xoshiro512` instance initialized with the given seed.
+    pub fn new_seed(seed: u64) -> Xoshiro512 {
+        Xoshiro512 {
+            seed,
+            state: Xoshiro512::initial_state(seed),
+        }
+    }
+
+    /// Generates a uniformly distributed random number in the range [0, 1).
+    ///
+    /// The high 53 bits of the next `u64` are scaled by `2^(-53)`, so every value is a multiple of `2^(-53)`.
+    ///
+    /// # Returns
+    ///
+    /// A random `f64` value in the range [0, 1).
+    pub fn generate(&mut self) -> f64 {
+        (self.next_u64() >> 11_u32) as f64 * Self::INV_2_POW_53
+    }
+
+    /// Generates the next uniformly distributed random `u64`.
+    ///
+    /// The state `s₀, ..., s₇` is updated by the linear transformation
+    /// ```text
+    /// s₂ ^= s₀, s₅ ^= s₁, s₁ ^= s₂, s₇ ^= s₃, s₃ ^= s₄, s₄ ^= s₅, s₀ ^= s₆, s₆ ^= s₇ ⊕ (s₁ << 11), s₇ = rotl(s₇, 21)
+    /// ```
+    /// and the output is scrambled from the old `s₁` by
+    /// ```text
+    /// rotl(5 s₁, 7) 9
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A random `u64` value.
+    pub fn next_u64(&mut self) -> u64 {
+        let s: &mut [u64; 8] = &mut self.state;
+        let result: u64 = s[1]
+            .wrapping_mul(5_u64)
+            .rotate_left(7_u32)
+            .wrapping_mul(9_u64);
+        let t: u64 = s[1] << 11_u32;
+
+        s[2] ^= s[0];
+        s[5] ^= s[1];
+        s[1] ^= s[2];
+        s[7] ^= s[3];
+        s[3] ^= s[4];
+        s[4] ^= s[5];
+        s[0] ^= s[6];
+        s[6] ^= s[7];
+        s[6] ^= t;
+        s[7] = s[7].rotate_left(21_u32);
+
+        result
+    }
+
+    /// Advances the generator by `2^256` values.
+    ///
+    /// Calling this method `k` times on copies of one generator results in `k` non-overlapping streams of length `2^256`.
+    pub fn jump(&mut self) {
+        self.jump_by(&Self::JUMP);
+    }
+
+    /// Advances the generator by `2^384` values.
+    ///
+    /// This separates groups of up to `2^128` streams created by `jump`.
+    pub fn long_jump(&mut self) {
+        self.jump_by(&Self::LONG_JUMP);
+    }
+
+    /// Returns the seed used to initialize the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The seed value as a `u64`.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Sets the seed of the random number generator to a given number.
+    ///
+    /// # Arguments
+    ///
+    /// * seed - A `u64` representing the new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.state = Xoshiro512::initial_state(seed);
+    }
+
+    /// Resets the random number generator to start from the beginning using the initial seed.
+    pub fn restart(&mut self) {
+        self.state = Xoshiro512::initial_state(self.seed);
+    }
+
+    /// Expands a seed into the 512 bits of the state.
+    fn initial_state(seed: u64) -> [u64; 8] {
+        let mut state: [u64; 8] = [0_u64; 8];
+        for (index, word) in state.iter_mut().enumerate() {
+            *word = Rng::derive_seed(seed, index as u64);
+        }
+        state
+    }
+
+    /// Advances the generator by the power of the transition matrix given by a jump polynomial.
+    ///
+    /// The state after the jump is the sum of the states after `k` steps for the coefficients `k` of the polynomial which are 1.
+    fn jump_by(&mut self, polynomial: &[u64; 8]) {
+        let mut state: [u64; 8] = [0_u64; 8];
+
+        for &word in polynomial {
+            for bit in 0_u32..64_u32 {
+                if (word >> bit) & 1_u64 == 1_u64 {
+                    for (sum, &value) in state.iter_mut().zip(self.state.iter()) {
+                        *sum ^= value;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = state;
+    }
+}
+
+impl Default for Xoshiro512 {
+    /// Creates a new `Xoshiro512` instance using the system time as the seed.
+    fn default() -> Self {
+        Xoshiro512::new()
+    }
+}
+
+impl UniformSource for Xoshiro512 {
+    /// Returns the next uniformly distributed number using `Xoshiro512::generate`.
+    fn next_uniform(&mut self) -> f64 {
+        self.generate()
+    }
+}
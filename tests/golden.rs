@@ -0,0 +1,681 @@
+//! Golden-value regression tests pinning the first samples of every distribution for a fixed seed.
+//!
+//! A change of an algorithm, of the order of the uniformly distributed numbers or of the arithmetic fails them.
+//! With the `strict-reproducible` feature the samples are compared bit by bit, which holds on every platform.
+//! Otherwise the transcendental functions of the platform are used, which may differ in the last bits,
+//! so the samples are compared up to a relative error of `1e-12`.
+//! Intentional changes of a sampler must update its golden values.
+
+use random::{
+    Bernoulli, Beta, BetaBinomial, Binomial, Categorical, ChiSquared, DiscreteUniform,
+    ExactDiscrete, Exponential, Fisher, Frechet, Gamma, GammaPoisson, GeneralizedExtremeValue,
+    GeneralizedPareto, Geometric, Gumbel, Gumbel2, HalfNormal, Laplace, Levy, LogGamma, LogNormal,
+    Logistic, MaxwellBoltzmann, Nakagami, Normal, NormalTail, Pareto, Pert, Poisson,
+    PoissonAlgorithm, RandInt, Rayleigh, Rice, Rng, RngTrait, Stable, StudentsT, TieBreaking,
+    Triangle, TwoPoint, Uniform, Weibull,
+};
+
+/// The seed of every sequence.
+const SEED: u64 = 42_u64;
+
+/// Asserts that the first samples for the seed `SEED` equal the golden values.
+fn assert_golden<D: RngTrait>(name: &str, mut distribution: D, golden: &[f64]) {
+    distribution.set_seed(SEED);
+    let samples: Vec<f64> = distribution.generate_multiple(golden.len());
+
+    for (i, (sample, expected)) in samples.iter().zip(golden.iter()).enumerate() {
+        let equal: bool = if cfg!(feature = "strict-reproducible") {
+            sample.to_bits() == expected.to_bits()
+        } else {
+            (sample - expected).abs() <= 1e-12_f64 * expected.abs().max(1_f64)
+        };
+        assert!(
+            equal,
+            "{name}: sample {i} is {sample:?} instead of {expected:?}"
+        );
+    }
+}
+
+/// Returns the first sample of a distribution seeded so that its first uniformly distributed number is `uniform`.
+///
+/// The seed starts the generator one state before the one whose high 53 bits are `uniform`, a multiple of `2^-53`,
+/// as the burn-in of `2^64 - 1` states goes back by one state.
+fn first_sample<D: RngTrait>(mut distribution: D, uniform: f64) -> f64 {
+    let state: u64 = ((uniform * 2_f64.powi(53_i32)) as u64) << 11_u32;
+    distribution.set_seed(Rng::seed_of_state(state));
+    distribution.set_burn_in(u64::MAX);
+    distribution.generate_multiple(1_usize)[0]
+}
+
+#[test]
+fn bernoulli() {
+    assert_golden(
+        "bernoulli",
+        Bernoulli::new(0.3_f64).unwrap(),
+        &[0_f64, 0_f64, 1_f64, 0_f64, 1_f64, 0_f64],
+    );
+}
+
+#[test]
+fn beta() {
+    assert_golden(
+        "beta",
+        Beta::new(2_f64, 3_f64).unwrap(),
+        &[
+            0.7713764604549228_f64,
+            0.2887392665301118_f64,
+            0.20263068476086749_f64,
+            0.549676459104559_f64,
+            0.5852888549347696_f64,
+            0.551568942449492_f64,
+        ],
+    );
+}
+
+#[test]
+fn beta_binomial() {
+    assert_golden(
+        "beta_binomial",
+        BetaBinomial::new(20_i32, 2_f64, 3_f64).unwrap(),
+        &[17_f64, 6_f64, 14_f64, 5_f64, 5_f64, 1_f64],
+    );
+}
+
+#[test]
+fn binomial_inversion() {
+    assert_golden(
+        "binomial_inversion",
+        Binomial::new(20_i32, 0.3_f64).unwrap(),
+        &[7_f64, 5_f64, 4_f64, 5_f64, 5_f64, 12_f64],
+    );
+}
+
+#[test]
+fn binomial_btpe() {
+    assert_golden(
+        "binomial_btpe",
+        Binomial::new(100_i32, 0.4_f64).unwrap(),
+        &[42_f64, 40_f64, 38_f64, 45_f64, 39_f64, 42_f64],
+    );
+}
+
+#[test]
+fn categorical() {
+    assert_golden(
+        "categorical",
+        Categorical::new(vec![0.2_f64, 0.5_f64, 0.3_f64]).unwrap(),
+        &[1_f64, 1_f64, 0_f64, 1_f64, 1_f64, 2_f64],
+    );
+}
+
+#[test]
+fn chi_squared() {
+    assert_golden(
+        "chi_squared",
+        ChiSquared::new(4_i32).unwrap(),
+        &[
+            9.540137330771286_f64,
+            1.4565965905362548_f64,
+            3.851684362002212_f64,
+            6.792030184543322_f64,
+            2.603783637372334_f64,
+            7.451686163012327_f64,
+        ],
+    );
+}
+
+#[test]
+fn discrete_uniform() {
+    assert_golden(
+        "discrete_uniform",
+        DiscreteUniform::new(0_f64, 1_f64, 0.25_f64).unwrap(),
+        &[0.75_f64, 0.5_f64, 0.25_f64, 0.5_f64, 1_f64, 1_f64],
+    );
+}
+
+#[test]
+fn exact_discrete() {
+    assert_golden(
+        "exact_discrete",
+        ExactDiscrete::new(&[1_u64, 2_u64, 3_u64]).unwrap(),
+        &[0_f64, 1_f64, 1_f64, 1_f64, 1_f64, 1_f64],
+    );
+}
+
+#[test]
+fn exponential() {
+    assert_golden(
+        "exponential",
+        Exponential::new(2_f64).unwrap(),
+        &[
+            0.1928142071595452_f64,
+            0.46243354413776583_f64,
+            0.8318036637487495_f64,
+            0.44471544153354003_f64,
+            0.6140844946491619_f64,
+            0.0015751754225682986_f64,
+        ],
+    );
+}
+
+#[test]
+fn fisher() {
+    assert_golden(
+        "fisher",
+        Fisher::new(5_f64, 10_f64).unwrap(),
+        &[
+            3.7931984595194175_f64,
+            0.6755093986881638_f64,
+            0.4512160732339314_f64,
+            1.5696358406195252_f64,
+            1.8356426402348507_f64,
+            1.3630872591975072_f64,
+        ],
+    );
+}
+
+#[test]
+fn frechet() {
+    assert_golden(
+        "frechet",
+        Frechet::new(1_f64, 5_f64, 1_f64).unwrap(),
+        &[
+            2.2099464165180707_f64,
+            2.0157435770066727_f64,
+            1.9032120859502617_f64,
+            2.0237112870135863_f64,
+            1.959728257216394_f64,
+            4.164650724516404_f64,
+        ],
+    );
+}
+
+#[test]
+fn gamma() {
+    assert_golden(
+        "gamma",
+        Gamma::new(3_f64, 2_f64).unwrap(),
+        &[
+            12.600572853113443_f64,
+            2.8275428096687625_f64,
+            5.982357161996886_f64,
+            9.48797812412013_f64,
+            4.394749810318892_f64,
+            10.246114394829267_f64,
+        ],
+    );
+}
+
+#[test]
+fn gamma_poisson() {
+    assert_golden(
+        "gamma_poisson",
+        GammaPoisson::new(2.5_f64, 0.5_f64).unwrap(),
+        &[12_f64, 0_f64, 4_f64, 1_f64, 0_f64, 9_f64],
+    );
+}
+
+#[test]
+fn generalized_extreme_value() {
+    assert_golden(
+        "generalized_extreme_value",
+        GeneralizedExtremeValue::new(0_f64, 1_f64, 0.1_f64).unwrap(),
+        &[
+            0.9997571582441578_f64,
+            0.07841105725033767_f64,
+            -0.4962518809292916_f64,
+            0.11786264834057045_f64,
+            -0.20342681403153895_f64,
+            7.78951499090591_f64,
+        ],
+    );
+}
+
+#[test]
+fn generalized_pareto() {
+    assert_golden(
+        "generalized_pareto",
+        GeneralizedPareto::new(0_f64, 1_f64, 0.1_f64).unwrap(),
+        &[
+            1.206968371179492_f64,
+            0.518124979510182_f64,
+            0.2122691080688735_f64,
+            0.5433913238122912_f64,
+            0.3525541361746844_f64,
+            7.792316636526622_f64,
+        ],
+    );
+}
+
+#[test]
+fn geometric() {
+    assert_golden(
+        "geometric",
+        Geometric::new(0.25_f64).unwrap(),
+        &[4_f64, 2_f64, 1_f64, 2_f64, 2_f64, 21_f64],
+    );
+}
+
+#[test]
+fn gumbel() {
+    assert_golden(
+        "gumbel",
+        Gumbel::new(0_f64, 1_f64).unwrap(),
+        &[
+            0.9528803737936352_f64,
+            0.0781046623296282_f64,
+            -0.5089894249019371_f64,
+            0.11717270293598839_f64,
+            -0.20552549995208413_f64,
+            5.7602134695174_f64,
+        ],
+    );
+}
+
+#[test]
+fn gumbel2() {
+    assert_golden(
+        "gumbel2",
+        Gumbel2::new(5_f64, 1_f64).unwrap(),
+        &[
+            1.2099464165180707_f64,
+            1.0157435770066727_f64,
+            0.9032120859502617_f64,
+            1.0237112870135863_f64,
+            0.959728257216394_f64,
+            3.1646507245164046_f64,
+        ],
+    );
+}
+
+#[test]
+fn half_normal() {
+    assert_golden(
+        "half_normal",
+        HalfNormal::new(2_f64).unwrap(),
+        &[
+            3.251709257630902_f64,
+            1.867972674029389_f64,
+            2.540840575446314_f64,
+            0.7290891903734895_f64,
+            0.3823311114425976_f64,
+            2.074133357656101_f64,
+        ],
+    );
+}
+
+#[test]
+fn laplace() {
+    assert_golden(
+        "laplace",
+        Laplace::new(0_f64, 1_f64).unwrap(),
+        &[
+            0.4463596259939363_f64,
+            -0.23172008234721694_f64,
+            -0.9704609627849148_f64,
+            -0.19628375024502584_f64,
+            -0.535021871413315_f64,
+            5.068669034271433_f64,
+        ],
+    );
+}
+
+#[test]
+fn levy() {
+    assert_golden(
+        "levy",
+        Levy::new(0_f64, 1_f64).unwrap(),
+        &[
+            0.3783002048232428_f64,
+            1.1463545359576108_f64,
+            0.6195910831293122_f64,
+            7.524864303023365_f64,
+            27.364071258422452_f64,
+            0.9297937851349614_f64,
+        ],
+    );
+}
+
+#[test]
+fn loggamma() {
+    assert_golden(
+        "loggamma",
+        LogGamma::new(2_i32, 0.2_f64).unwrap(),
+        &[
+            1.3894820679980104_f64,
+            1.1593248711380817_f64,
+            3.3928137382149783_f64,
+            1.785836010112436_f64,
+            1.2966941805265224_f64,
+            1.5898598795676717_f64,
+        ],
+    );
+}
+
+#[test]
+fn logistic() {
+    assert_golden(
+        "logistic",
+        Logistic::new(0_f64, 1_f64).unwrap(),
+        &[
+            0.7538790625003017_f64,
+            -0.4197186708341839_f64,
+            -1.4535614053320784_f64,
+            -0.3602896943565307_f64,
+            -0.8816883401346015_f64,
+            5.7586657758274145_f64,
+        ],
+    );
+}
+
+#[test]
+fn lognormal() {
+    assert_golden(
+        "lognormal",
+        LogNormal::new(0_f64, 0.25_f64).unwrap(),
+        &[
+            2.2544979608704296_f64,
+            0.6268843729537369_f64,
+            0.5298241373279392_f64,
+            0.8333743836781972_f64,
+            1.1002998987360062_f64,
+            1.6795624942556149_f64,
+        ],
+    );
+}
+
+#[test]
+fn maxwell_boltzmann() {
+    assert_golden(
+        "maxwell_boltzmann",
+        MaxwellBoltzmann::new(1_f64).unwrap(),
+        &[
+            2.2648844251372613_f64,
+            1.1157707326819177_f64,
+            2.2942349603495513_f64,
+            1.8217599151923851_f64,
+            2.648994255235495_f64,
+            0.9227836670971735_f64,
+        ],
+    );
+}
+
+#[test]
+fn nakagami() {
+    assert_golden(
+        "nakagami",
+        Nakagami::new(2_f64, 1_f64).unwrap(),
+        &[
+            1.5443556367277653_f64,
+            0.6034477173990003_f64,
+            0.9812854276409861_f64,
+            1.3030761858524738_f64,
+            0.8068121896346655_f64,
+            1.3648888382403461_f64,
+        ],
+    );
+}
+
+#[test]
+fn normal() {
+    assert_golden(
+        "normal",
+        Normal::new(1_f64, 4_f64).unwrap(),
+        &[
+            4.2517092576309015_f64,
+            -0.8679726740293889_f64,
+            -1.540840575446314_f64,
+            0.2709108096265105_f64,
+            1.3823311114425976_f64,
+            3.074133357656101_f64,
+        ],
+    );
+}
+
+#[test]
+fn normal_tail() {
+    assert_golden(
+        "normal_tail",
+        NormalTail::new(1_f64).unwrap(),
+        &[
+            1.3308857308718058_f64,
+            2.080195821309859_f64,
+            1.4673201962889197_f64,
+            1.2403848424229822_f64,
+            2.7078212846826366_f64,
+            1.3545417523842918_f64,
+        ],
+    );
+}
+
+#[test]
+fn pareto() {
+    assert_golden(
+        "pareto",
+        Pareto::new(1_f64, 5_f64).unwrap(),
+        &[
+            1.0801778277044336_f64,
+            1.2031864561852434_f64,
+            1.3947587559276635_f64,
+            1.194689329910005_f64,
+            1.2784313235229456_f64,
+            1.0006302687049313_f64,
+        ],
+    );
+}
+
+#[test]
+fn pert() {
+    assert_golden(
+        "pert",
+        Pert::new(0_f64, 4_f64, 1_f64).unwrap(),
+        &[
+            2.7559485929082896_f64,
+            0.9679524736806083_f64,
+            0.6715072277642021_f64,
+            1.808665004078794_f64,
+            1.9817249917106794_f64,
+            1.6568674678742088_f64,
+        ],
+    );
+}
+
+#[test]
+fn poisson_small() {
+    assert_golden(
+        "poisson_small",
+        Poisson::new(3_f64).unwrap(),
+        &[4_f64, 2_f64, 1_f64, 2_f64, 2_f64, 9_f64],
+    );
+}
+
+#[test]
+fn poisson_large() {
+    assert_golden(
+        "poisson_large",
+        Poisson::new(50_f64).unwrap(),
+        &[54_f64, 43_f64, 51_f64, 51_f64, 48_f64, 56_f64],
+    );
+}
+
+#[test]
+fn rayleigh() {
+    assert_golden(
+        "rayleigh",
+        Rayleigh::new(1_f64).unwrap(),
+        &[
+            0.8782125796356228_f64,
+            1.3600497216482796_f64,
+            1.824066415016848_f64,
+            1.3337402839042507_f64,
+            1.5672717051273124_f64,
+            0.0793780700693083_f64,
+        ],
+    );
+}
+
+#[test]
+fn rice() {
+    assert_golden(
+        "rice",
+        Rice::new(1_f64, 1_f64).unwrap(),
+        &[
+            2.7870132775790024_f64,
+            0.4538941439280117_f64,
+            1.5793614777726703_f64,
+            2.8667838817425073_f64,
+            1.3785124412870096_f64,
+            2.2891046613259056_f64,
+        ],
+    );
+}
+
+#[test]
+fn stable() {
+    assert_golden(
+        "stable",
+        Stable::new(1.5_f64, 0.5_f64, 1_f64, 0_f64).unwrap(),
+        &[
+            0.3623910973605144_f64,
+            -1.4396580191104962_f64,
+            -2.506185294889442_f64,
+            -0.19873208358088099_f64,
+            -0.1490920551984867_f64,
+            -1.0855666583430428_f64,
+        ],
+    );
+}
+
+#[test]
+fn students_t() {
+    assert_golden(
+        "students_t",
+        StudentsT::new(5_f64).unwrap(),
+        &[
+            1.9583012540579998_f64,
+            -1.3898985029562876_f64,
+            0.20230413476304715_f64,
+            1.3560763904679083_f64,
+            -0.33078729909874915_f64,
+            1.2904740197724514_f64,
+        ],
+    );
+}
+
+#[test]
+fn triangle() {
+    assert_golden(
+        "triangle",
+        Triangle::new(0_f64, 3_f64, 1_f64).unwrap(),
+        &[
+            1.6144095098689903_f64,
+            1.0972400985710224_f64,
+            0.7538985120665722_f64,
+            1.1199301030205395_f64,
+            0.9372751871054834_f64,
+            2.8626232837006658_f64,
+        ],
+    );
+}
+
+#[test]
+fn two_point() {
+    assert_golden(
+        "two_point",
+        TwoPoint::new(-1_f64, 2_f64, 0.4_f64).unwrap(),
+        &[-1_f64, 2_f64, 2_f64, -1_f64, 2_f64, -1_f64],
+    );
+}
+
+#[test]
+fn uniform() {
+    assert_golden(
+        "uniform",
+        Uniform::new(-1_f64, 3_f64).unwrap(),
+        &[
+            1.7200926624390056_f64,
+            0.5863365050093252_f64,
+            -0.2421827113384114_f64,
+            0.6435581216477608_f64,
+            0.171313035151492_f64,
+            2.9874184252125415_f64,
+        ],
+    );
+}
+
+#[test]
+fn weibull() {
+    assert_golden(
+        "weibull",
+        Weibull::new(2_f64, 1_f64).unwrap(),
+        &[
+            0.6209900703836798_f64,
+            0.961700380928375_f64,
+            1.2898097313930486_f64,
+            0.9430967990903668_f64,
+            1.108228450657326_f64,
+            0.05612877162350882_f64,
+        ],
+    );
+}
+
+#[test]
+fn tie_breaking() {
+    // The uniform number lies exactly on the step of the cumulative distribution function between the two values
+    let mut rand_int: RandInt = RandInt::new(1_i32, 4_i32).unwrap();
+    assert_eq!(first_sample(rand_int.clone(), 0.5_f64), 3_f64);
+    rand_int.set_tie_breaking(TieBreaking::Lower);
+    assert_eq!(first_sample(rand_int, 0.5_f64), 2_f64);
+
+    let mut binomial: Binomial = Binomial::new(2_i32, 0.5_f64).unwrap();
+    assert_eq!(first_sample(binomial.clone(), 0.25_f64), 1_f64);
+    binomial.set_tie_breaking(TieBreaking::Lower);
+    assert_eq!(first_sample(binomial, 0.25_f64), 0_f64);
+
+    let mut geometric: Geometric = Geometric::new(0.5_f64).unwrap();
+    assert_eq!(first_sample(geometric.clone(), 0.5_f64), 2_f64);
+    geometric.set_tie_breaking(TieBreaking::Lower);
+    assert_eq!(first_sample(geometric, 0.5_f64), 1_f64);
+
+    // The probability of 0 is `exp(-ln 2) = 0.5`
+    for algorithm in [PoissonAlgorithm::Inversion, PoissonAlgorithm::Table] {
+        let mut poisson: Poisson = Poisson::new(std::f64::consts::LN_2).unwrap();
+        poisson.set_algorithm(algorithm);
+        assert_eq!(first_sample(poisson.clone(), 0.5_f64), 1_f64);
+        poisson.set_tie_breaking(TieBreaking::Lower);
+        assert_eq!(first_sample(poisson, 0.5_f64), 0_f64);
+    }
+
+    // Values of probability 0 are never generated, even for `U = 0`
+    let mut binomial: Binomial = Binomial::new(3_i32, 1_f64).unwrap();
+    binomial.set_tie_breaking(TieBreaking::Lower);
+    assert_eq!(first_sample(binomial, 0_f64), 3_f64);
+}
+
+#[test]
+fn tie_breaking_bias() {
+    // Every multiple of 1/8 is a tie of RandInt(0, 7). Upper generates each value once from the ties,
+    // Lower moves every tie to the value below, so 0 is generated twice and 7 never
+    let mut upper: [usize; 8] = [0_usize; 8];
+    let mut lower: [usize; 8] = [0_usize; 8];
+    for step in 0_i32..8_i32 {
+        let uniform: f64 = step as f64 / 8_f64;
+        let mut rand_int: RandInt = RandInt::new(0_i32, 7_i32).unwrap();
+        upper[first_sample(rand_int.clone(), uniform) as usize] += 1_usize;
+        rand_int.set_tie_breaking(TieBreaking::Lower);
+        lower[first_sample(rand_int.clone(), uniform) as usize] += 1_usize;
+
+        // Both rules agree on the uniform number right below the tie
+        if step > 0_i32 {
+            let below: f64 = uniform - 2_f64.powi(-53_i32);
+            assert_eq!(first_sample(rand_int, below), (step - 1_i32) as f64);
+        }
+    }
+    assert_eq!(upper, [1_usize; 8]);
+    assert_eq!(
+        lower,
+        [
+            2_usize, 1_usize, 1_usize, 1_usize, 1_usize, 1_usize, 1_usize, 0_usize
+        ]
+    );
+}
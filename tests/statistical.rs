@@ -0,0 +1,905 @@
+//! Statistical regression tests comparing large samples of every distribution with the theory.
+//!
+//! The seeds are fixed, so the tests are deterministic, but a sampler which is skewed by a change,
+//! e.g. a less accurate logarithm or a new algorithm, fails them.
+//! They are slow and only run with `cargo test --features slow-tests`.
+
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+use random::maxent::{self, MaxEnt, MomentConstraint};
+use random::mcmc::{
+    AdaptiveMetropolis, FullConditional, GibbsSampler, MetropolisHastings, Sampler, SliceSampler,
+};
+use random::{
+    Bernoulli, Beta, BetaBinomial, Binomial, BinomialAlgorithm, Categorical, ChiSquared,
+    Degenerate, Density, DiscreteDistribution, DiscreteUniform, ExactDiscrete, Exponential, Fisher,
+    Frechet, Gamma, GammaPoisson, GeneralizedExtremeValue, GeneralizedPareto, Geometric,
+    GeometricConvention, Gumbel, Gumbel2, HalfNormal, ImportanceSampler, Laplace, Levy, LogGamma,
+    LogNormal, Logistic, MaxwellBoltzmann, Nakagami, Normal, NormalTail, Pareto, Pert, Poisson,
+    PoissonAlgorithm, Quantile, Rayleigh, Rice, RngTrait, Stable, Stratified, StudentsT,
+    StudentsTAlgorithm, TabulatedQuantile, Triangle, TwoPoint, Uniform, Weibull,
+};
+
+/// The seed of every sample.
+const SEED: u64 = 20_240_601_u64;
+
+/// The size of every sample.
+const SAMPLE_SIZE: usize = 200_000_usize;
+
+/// The number of standard errors an estimated moment may deviate from the theoretical one.
+const MAX_STANDARD_ERRORS: f64 = 5_f64;
+
+/// The critical value of the scaled Kolmogorov-Smirnov statistic `sqrt(n) D` at the level 0.001.
+const KS_CRITICAL: f64 = 1.95_f64;
+
+/// The Euler-Mascheroni constant.
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9_f64;
+
+/// Draws a sample of size `SAMPLE_SIZE` with the seed `SEED`.
+fn sample<D: RngTrait>(distribution: &mut D) -> Vec<f64> {
+    distribution.set_seed(SEED);
+    distribution.generate_multiple(SAMPLE_SIZE)
+}
+
+/// Asserts that the sample mean and variance agree with the theoretical ones.
+///
+/// The standard error of the variance is estimated from the fourth central moment of the sample.
+fn assert_moments(name: &str, samples: &[f64], mean: f64, variance: f64) {
+    let n: f64 = samples.len() as f64;
+    let sample_mean: f64 = samples.iter().sum::<f64>() / n;
+    let sample_variance: f64 = samples
+        .iter()
+        .map(|x| (x - sample_mean).powi(2))
+        .sum::<f64>()
+        / (n - 1_f64);
+    let fourth_moment: f64 = samples
+        .iter()
+        .map(|x| (x - sample_mean).powi(4))
+        .sum::<f64>()
+        / n;
+
+    let mean_error: f64 = (variance / n).sqrt();
+    assert!(
+        (sample_mean - mean).abs() <= MAX_STANDARD_ERRORS * mean_error,
+        "{name}: sample mean {sample_mean} differs from {mean}"
+    );
+
+    let variance_error: f64 = ((fourth_moment - sample_variance.powi(2)) / n).sqrt();
+    assert!(
+        (sample_variance - variance).abs() <= MAX_STANDARD_ERRORS * variance_error,
+        "{name}: sample variance {sample_variance} differs from {variance}"
+    );
+}
+
+/// Asserts that the Kolmogorov-Smirnov statistic of the sample against the distribution function is small.
+fn assert_ks<D: Density>(name: &str, samples: &[f64], distribution: &D) {
+    let mut sorted: Vec<f64> = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n: f64 = sorted.len() as f64;
+
+    let statistic: f64 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let cdf: f64 = distribution.cdf(x);
+            (cdf - i as f64 / n).max((i + 1_usize) as f64 / n - cdf)
+        })
+        .fold(0_f64, f64::max);
+
+    assert!(
+        statistic * n.sqrt() <= KS_CRITICAL,
+        "{name}: Kolmogorov-Smirnov statistic {statistic} is too big"
+    );
+}
+
+/// Asserts that the Kolmogorov-Smirnov statistic of a sample of integers against the distribution function is small.
+///
+/// The distribution function is compared at every integer in the range of the sample and right below it.
+/// For a discrete distribution the critical value is conservative.
+fn assert_discrete_ks<D: DiscreteDistribution>(name: &str, samples: &[f64], distribution: &D) {
+    let mut sorted: Vec<f64> = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n: f64 = sorted.len() as f64;
+    let (first, last): (i32, i32) = (sorted[0] as i32, sorted[sorted.len() - 1_usize] as i32);
+
+    let statistic: f64 = (first - 1_i32..=last)
+        .map(|k| {
+            let count: usize = sorted.partition_point(|&x| x <= k as f64);
+            (distribution.cdf(k) - count as f64 / n).abs()
+        })
+        .fold(0_f64, f64::max);
+
+    assert!(
+        statistic * n.sqrt() <= KS_CRITICAL,
+        "{name}: Kolmogorov-Smirnov statistic {statistic} is too big"
+    );
+}
+
+/// Asserts both the moments and the Kolmogorov-Smirnov statistic of a distribution with a density.
+fn assert_continuous<D: RngTrait + Density>(
+    name: &str,
+    mut distribution: D,
+    mean: f64,
+    variance: f64,
+) {
+    let samples: Vec<f64> = sample(&mut distribution);
+    assert_moments(name, &samples, mean, variance);
+    assert_ks(name, &samples, &distribution);
+}
+
+#[test]
+fn uniform() {
+    assert_continuous(
+        "Uniform",
+        Uniform::new(-1_f64, 3_f64).unwrap(),
+        1_f64,
+        16_f64 / 12_f64,
+    );
+}
+
+#[test]
+fn exponential() {
+    assert_continuous(
+        "Exponential",
+        Exponential::new(2_f64).unwrap(),
+        0.5_f64,
+        0.25_f64,
+    );
+}
+
+#[test]
+fn normal() {
+    assert_continuous("Normal", Normal::new(1_f64, 4_f64).unwrap(), 1_f64, 4_f64);
+}
+
+#[test]
+fn tabulated_quantile() {
+    // The interpolation error of 4096 cells is far below the sampling error
+    let normal: Normal = Normal::new(1_f64, 4_f64).unwrap();
+    let mut tabulated: TabulatedQuantile = normal.clone().tabulate(4_096_usize).unwrap();
+    let samples: Vec<f64> = sample(&mut tabulated);
+    assert_moments("TabulatedQuantile", &samples, 1_f64, 4_f64);
+    assert_ks("TabulatedQuantile", &samples, &normal);
+}
+
+#[test]
+fn stratified() {
+    let normal: Normal = Normal::new(1_f64, 4_f64).unwrap();
+    let mut stratified: Stratified<Normal> = normal.clone().stratify(1_000_usize).unwrap();
+    let samples: Vec<f64> = sample(&mut stratified);
+    assert_moments("Stratified", &samples, 1_f64, 4_f64);
+    assert_ks("Stratified", &samples, &normal);
+
+    // The means of whole passes vary much less than the means of independent values,
+    // only the unbounded last stratum keeps about 1 / k of the variance
+    let variance_of_means = |samples: &[f64]| -> f64 {
+        let means: Vec<f64> = samples
+            .chunks(100_usize)
+            .map(|chunk| chunk.iter().sum::<f64>() / chunk.len() as f64)
+            .collect();
+        let mean: f64 = means.iter().sum::<f64>() / means.len() as f64;
+        means.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (means.len() - 1_usize) as f64
+    };
+    let exponential: Exponential = Exponential::new(1_f64).unwrap();
+    let independent: Vec<f64> = sample(&mut exponential.clone());
+    let passes: Vec<f64> = sample(&mut exponential.stratify(100_usize).unwrap());
+    assert!(
+        variance_of_means(&passes) < 0.05_f64 * variance_of_means(&independent),
+        "Stratified: the variance of the mean is not reduced"
+    );
+}
+
+#[test]
+fn importance_sampler() {
+    // The tail probability P(X > 5) of the standard Normal distribution, which plain sampling almost never hits
+    let standard: Normal = Normal::new(0_f64, 1_f64).unwrap();
+    let mut sampler: ImportanceSampler =
+        ImportanceSampler::new(Normal::new(5_f64, 1_f64).unwrap(), standard.clone());
+    sampler.set_seed(SEED);
+    let estimate: f64 = sampler.estimate(|x| f64::from(u8::from(x > 5_f64)), SAMPLE_SIZE);
+    let exact: f64 = 1_f64 - standard.cdf(5_f64);
+    assert!(
+        (estimate / exact - 1_f64).abs() < 0.02_f64,
+        "ImportanceSampler: estimate {estimate} differs from {exact}"
+    );
+
+    // For a proposal with twice the standard deviation E[w] = 1 and E[w²] = 4 / sqrt(7)
+    let mut sampler: ImportanceSampler =
+        ImportanceSampler::new(Normal::new(0_f64, 4_f64).unwrap(), standard);
+    sampler.set_seed(SEED);
+    let weights: Vec<f64> = sampler
+        .generate_multiple(SAMPLE_SIZE)
+        .into_iter()
+        .map(|(_, weight)| weight)
+        .collect();
+    let second_moment: f64 = 4_f64 / 7_f64.sqrt();
+    assert_moments(
+        "ImportanceSampler weights",
+        &weights,
+        1_f64,
+        second_moment - 1_f64,
+    );
+
+    let ratio: f64 = sampler.effective_sample_size() / sampler.count() as f64;
+    assert!(
+        (ratio - 1_f64 / second_moment).abs() < 0.01_f64,
+        "ImportanceSampler: effective sample size ratio {ratio} differs from {}",
+        1_f64 / second_moment
+    );
+}
+
+/// The logarithm of the unnormalized density of the `Rayleigh(1)` distribution, the target of the Markov chains.
+fn ln_rayleigh(x: f64) -> f64 {
+    if x > 0_f64 {
+        x.ln() - 0.5_f64 * x * x
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+/// Asserts that the thinned states of a Markov chain with the target `ln_rayleigh` follow the `Rayleigh(1)` distribution.
+fn assert_rayleigh_chain<S: Sampler>(name: &str, sampler: &mut S, thinning: usize) {
+    let samples: Vec<f64> = sampler
+        .chain()
+        .burn_in(1_000_usize)
+        .thinning(thinning)
+        .take(SAMPLE_SIZE)
+        .collect();
+    assert_moments(name, &samples, (PI / 2_f64).sqrt(), (4_f64 - PI) / 2_f64);
+    assert_ks(name, &samples, &Rayleigh::new(1_f64).unwrap());
+}
+
+#[test]
+fn metropolis_hastings() {
+    // A symmetric and a shifted proposal, which needs the Hastings correction
+    for shift in [0_f64, 0.5_f64] {
+        let proposal: Normal = Normal::new(shift, 1_f64).unwrap();
+        let mut sampler: MetropolisHastings =
+            MetropolisHastings::new(ln_rayleigh, proposal, 1_f64).unwrap();
+        sampler.set_seed(SEED);
+        let name: String = format!("MetropolisHastings with shift {shift}");
+        assert_rayleigh_chain(&name, &mut sampler, 20_usize);
+    }
+}
+
+#[test]
+fn slice_sampler() {
+    // The width only changes the number of evaluations, not the distribution
+    for width in [0.1_f64, 1_f64, 10_f64] {
+        let mut sampler: SliceSampler = SliceSampler::new(ln_rayleigh, width, 1_f64).unwrap();
+        sampler.set_seed(SEED);
+        let name: String = format!("SliceSampler with width {width}");
+        assert_rayleigh_chain(&name, &mut sampler, 5_usize);
+    }
+}
+
+#[test]
+fn adaptive_metropolis() {
+    // Far too small and far too big initial scales are adapted to about 2.4 times the standard deviation
+    let optimal_scale: f64 = 2.4_f64 * ((4_f64 - PI) / 2_f64).sqrt();
+    for initial_scale in [0.01_f64, 100_f64] {
+        let mut sampler: AdaptiveMetropolis =
+            AdaptiveMetropolis::new(ln_rayleigh, initial_scale, 1_f64).unwrap();
+        sampler.set_seed(SEED);
+        let name: String = format!("AdaptiveMetropolis with initial scale {initial_scale}");
+        assert_rayleigh_chain(&name, &mut sampler, 20_usize);
+
+        let scale: f64 = sampler.proposal_scale();
+        assert!(
+            (scale / optimal_scale - 1_f64).abs() < 0.05_f64,
+            "{name}: proposal scale {scale} differs from {optimal_scale}"
+        );
+    }
+}
+
+/// Returns the full conditional `N(ρ z, 1 - ρ²)` of a standard bivariate Normal distribution given the other variable `z`.
+fn bivariate_normal_conditional(other: &'static str) -> FullConditional {
+    Box::new(move |values, rng| {
+        let rho: f64 = values["rho"];
+        let conditional: Normal = Normal::new(rho * values[other], 1_f64 - rho * rho).unwrap();
+        Quantile::sample(&conditional, rng)
+    })
+}
+
+#[test]
+fn gibbs_sampler() {
+    // The correlation is a fixed value, the sweeps have an autocorrelation of ρ² = 0.64
+    let conditionals: BTreeMap<String, FullConditional> = BTreeMap::from([
+        ("x".to_string(), bivariate_normal_conditional("y")),
+        ("y".to_string(), bivariate_normal_conditional("x")),
+    ]);
+    let initial: BTreeMap<String, f64> = BTreeMap::from([
+        ("rho".to_string(), 0.8_f64),
+        ("x".to_string(), 5_f64),
+        ("y".to_string(), -5_f64),
+    ]);
+    let mut sampler: GibbsSampler = GibbsSampler::new(conditionals, initial).unwrap();
+    sampler.set_seed(SEED);
+    sampler.burn_in(1_000_usize);
+    sampler.run(20_usize * SAMPLE_SIZE);
+    assert_eq!(sampler.trace("rho"), None);
+
+    let thinned = |name: &str| -> Vec<f64> {
+        sampler
+            .trace(name)
+            .unwrap()
+            .iter()
+            .step_by(20_usize)
+            .copied()
+            .collect()
+    };
+    let (x, y): (Vec<f64>, Vec<f64>) = (thinned("x"), thinned("y"));
+    assert_eq!(x.len(), SAMPLE_SIZE);
+    for (name, samples) in [("GibbsSampler x", &x), ("GibbsSampler y", &y)] {
+        assert_moments(name, samples, 0_f64, 1_f64);
+        assert_ks(name, samples, &Normal::new(0_f64, 1_f64).unwrap());
+    }
+
+    // The products of the standard Normal values have the mean ρ and the variance 1 + ρ²
+    let correlation: f64 = x.iter().zip(&y).map(|(x, y)| x * y).sum::<f64>() / SAMPLE_SIZE as f64;
+    let tolerance: f64 = MAX_STANDARD_ERRORS * (1.64_f64 / SAMPLE_SIZE as f64).sqrt();
+    assert!(
+        (correlation - 0.8_f64).abs() < tolerance,
+        "GibbsSampler: correlation {correlation} differs from 0.8"
+    );
+}
+
+#[test]
+fn lognormal() {
+    // μ = 0 and σ² = 1/4 of the underlying Normal distribution
+    let mean: f64 = 0.125_f64.exp();
+    let variance: f64 = (0.25_f64.exp() - 1_f64) * 0.25_f64.exp();
+    assert_continuous(
+        "LogNormal",
+        LogNormal::new(0_f64, 0.25_f64).unwrap(),
+        mean,
+        variance,
+    );
+}
+
+#[test]
+fn weibull() {
+    // Γ(3/2) = sqrt(π) / 2
+    let mean: f64 = PI.sqrt() / 2_f64;
+    assert_continuous(
+        "Weibull",
+        Weibull::new(2_f64, 1_f64).unwrap(),
+        mean,
+        1_f64 - mean * mean,
+    );
+}
+
+#[test]
+fn pareto() {
+    assert_continuous(
+        "Pareto",
+        Pareto::new(1_f64, 5_f64).unwrap(),
+        1.25_f64,
+        5_f64 / 48_f64,
+    );
+}
+
+#[test]
+fn logistic() {
+    assert_continuous(
+        "Logistic",
+        Logistic::new(0_f64, 1_f64).unwrap(),
+        0_f64,
+        PI * PI / 3_f64,
+    );
+}
+
+#[test]
+fn gumbel() {
+    assert_continuous(
+        "Gumbel",
+        Gumbel::new(0_f64, 1_f64).unwrap(),
+        EULER_GAMMA,
+        PI * PI / 6_f64,
+    );
+}
+
+#[test]
+fn rayleigh() {
+    let mean: f64 = (PI / 2_f64).sqrt();
+    assert_continuous(
+        "Rayleigh",
+        Rayleigh::new(1_f64).unwrap(),
+        mean,
+        (4_f64 - PI) / 2_f64,
+    );
+}
+
+#[test]
+fn laplace() {
+    assert_continuous("Laplace", Laplace::new(0_f64, 1_f64).unwrap(), 0_f64, 2_f64);
+}
+
+#[test]
+fn triangle() {
+    assert_continuous(
+        "Triangle",
+        Triangle::new(0_f64, 3_f64, 1_f64).unwrap(),
+        4_f64 / 3_f64,
+        7_f64 / 18_f64,
+    );
+    assert_continuous(
+        "Triangle::symmetric",
+        Triangle::symmetric(5_f64, 2_f64).unwrap(),
+        5_f64,
+        4_f64 / 6_f64,
+    );
+}
+
+#[test]
+fn triangle_fit_moments() {
+    // The fitted distribution matches the sample mean and variance and recovers the parameters
+    let samples: Vec<f64> = sample(&mut Triangle::new(0_f64, 3_f64, 1_f64).unwrap());
+    let (a, b, c): (f64, f64, f64) = Triangle::fit_moments(&samples).unwrap().params();
+    for (name, fitted, expected) in [("a", a, 0_f64), ("b", b, 3_f64), ("c", c, 1_f64)] {
+        assert!(
+            (fitted - expected).abs() < 0.02_f64,
+            "Triangle::fit_moments: {name} = {fitted} differs from {expected}"
+        );
+    }
+}
+
+#[test]
+fn maxent() {
+    // The density is constant on the cells of width 0.01, which adds their variance 0.01² / 12
+    let constraints: [MomentConstraint; 2] = [
+        MomentConstraint::Mean(3_f64),
+        MomentConstraint::Variance(4_f64),
+    ];
+    let mut distribution: MaxEnt = maxent::fit((0_f64, 10_f64), &constraints).unwrap();
+    let samples: Vec<f64> = sample(&mut distribution);
+    assert_moments("MaxEnt", &samples, 3_f64, 4_f64 + 1e-4_f64 / 12_f64);
+
+    // The grid cannot reach a mean outside of the outer midpoints or a variance beyond the two outer midpoints
+    for constraints in [
+        vec![MomentConstraint::Mean(0.001_f64)],
+        vec![
+            MomentConstraint::Mean(5_f64),
+            MomentConstraint::Variance(24.99_f64),
+        ],
+    ] {
+        assert!(
+            maxent::fit((0_f64, 10_f64), &constraints).is_err(),
+            "{constraints:?}"
+        );
+    }
+}
+
+#[test]
+fn pert() {
+    // α = 2 and β = 4 on [0, 4]
+    assert_continuous(
+        "Pert",
+        Pert::new(0_f64, 4_f64, 1_f64).unwrap(),
+        4_f64 / 3_f64,
+        128_f64 / 252_f64,
+    );
+}
+
+#[test]
+fn half_normal() {
+    let mean: f64 = 2_f64 * (2_f64 / PI).sqrt();
+    let variance: f64 = 4_f64 * (1_f64 - 2_f64 / PI);
+    assert_continuous(
+        "HalfNormal",
+        HalfNormal::new(2_f64).unwrap(),
+        mean,
+        variance,
+    );
+}
+
+#[test]
+fn maxwell_boltzmann() {
+    let mean: f64 = 2_f64 * (2_f64 / PI).sqrt();
+    let variance: f64 = (3_f64 * PI - 8_f64) / PI;
+    assert_continuous(
+        "MaxwellBoltzmann",
+        MaxwellBoltzmann::new(1_f64).unwrap(),
+        mean,
+        variance,
+    );
+}
+
+#[test]
+fn generalized_extreme_value() {
+    // Γ(0.9) and Γ(0.8) for the shape ξ = 0.1
+    let gamma_1: f64 = 1.068_628_702_119_319_4_f64;
+    let gamma_2: f64 = 1.164_229_713_725_303_4_f64;
+    let mean: f64 = (gamma_1 - 1_f64) / 0.1_f64;
+    let variance: f64 = (gamma_2 - gamma_1 * gamma_1) / 0.01_f64;
+    let distribution: GeneralizedExtremeValue =
+        GeneralizedExtremeValue::new(0_f64, 1_f64, 0.1_f64).unwrap();
+    assert_continuous("GeneralizedExtremeValue", distribution, mean, variance);
+}
+
+#[test]
+fn generalized_pareto() {
+    let mean: f64 = 1_f64 / 0.9_f64;
+    let variance: f64 = 1_f64 / (0.81_f64 * 0.8_f64);
+    let distribution: GeneralizedPareto = GeneralizedPareto::new(0_f64, 1_f64, 0.1_f64).unwrap();
+    assert_continuous("GeneralizedPareto", distribution, mean, variance);
+}
+
+#[test]
+fn levy() {
+    // The Levy distribution has no finite moments
+    let mut distribution: Levy = Levy::new(0_f64, 1_f64).unwrap();
+    let samples: Vec<f64> = sample(&mut distribution);
+    assert_ks("Levy", &samples, &distribution);
+}
+
+#[test]
+fn frechet() {
+    let mut distribution: Frechet = Frechet::new(1_f64, 5_f64, 1_f64).unwrap();
+    let samples: Vec<f64> = sample(&mut distribution);
+    assert_ks("Frechet", &samples, &distribution);
+}
+
+#[test]
+fn gumbel2() {
+    // Γ(0.8) and Γ(0.6) for the shape 5
+    let gamma_1: f64 = 1.164_229_713_725_303_4_f64;
+    let gamma_2: f64 = 1.489_192_248_812_817_f64;
+    let samples: Vec<f64> = sample(&mut Gumbel2::new(5_f64, 1_f64).unwrap());
+    assert_moments("Gumbel2", &samples, gamma_1, gamma_2 - gamma_1 * gamma_1);
+}
+
+#[test]
+fn gamma() {
+    for shape in [0.5_f64, 2.5_f64, 3_f64, 1_000_f64] {
+        let samples: Vec<f64> = sample(&mut Gamma::new(shape, 2_f64).unwrap());
+        let mean: f64 = 2_f64 * shape;
+        assert_moments(&format!("Gamma({shape}, 2)"), &samples, mean, 2_f64 * mean);
+    }
+}
+
+#[test]
+fn gamma_fit() {
+    // The maximum likelihood estimate recovers a shape that is not an integer
+    let samples: Vec<f64> = sample(&mut Gamma::new(2.5_f64, 2_f64).unwrap());
+    let (shape, scale): (f64, f64) = Gamma::fit(&samples).unwrap().params();
+    for (name, fitted, expected) in [("shape", shape, 2.5_f64), ("scale", scale, 2_f64)] {
+        assert!(
+            (fitted - expected).abs() < 0.05_f64,
+            "Gamma::fit: {name} = {fitted} differs from {expected}"
+        );
+    }
+}
+
+#[test]
+fn beta() {
+    for (a, b) in [(2_f64, 3_f64), (0.5_f64, 2.5_f64), (600_f64, 900_f64)] {
+        let samples: Vec<f64> = sample(&mut Beta::new(a, b).unwrap());
+        let mean: f64 = a / (a + b);
+        let variance: f64 = a * b / ((a + b).powi(2) * (a + b + 1_f64));
+        assert_moments(&format!("Beta({a}, {b})"), &samples, mean, variance);
+    }
+}
+
+#[test]
+fn beta_fit_moments() {
+    // The method of moments recovers shapes that are not integers
+    let samples: Vec<f64> = sample(&mut Beta::new(0.5_f64, 2.5_f64).unwrap());
+    let (alpha, beta): (f64, f64) = Beta::fit_moments(&samples).unwrap().params();
+    for (name, fitted, expected) in [("alpha", alpha, 0.5_f64), ("beta", beta, 2.5_f64)] {
+        assert!(
+            (fitted - expected).abs() < 0.05_f64,
+            "Beta::fit_moments: {name} = {fitted} differs from {expected}"
+        );
+    }
+}
+
+#[test]
+fn chi_squared() {
+    for k in [1_i32, 4_i32, 150_i32] {
+        let mean: f64 = k as f64;
+        let mut distribution: ChiSquared = ChiSquared::new(k).unwrap();
+        let samples: Vec<f64> = sample(&mut distribution);
+        assert_moments(&format!("ChiSquared({k})"), &samples, mean, 2_f64 * mean);
+
+        // The definition as a sum of squared normals cross-checks the Gamma sampler
+        let samples: Vec<f64> = (0_usize..SAMPLE_SIZE)
+            .map(|_| distribution.generate_normal_sum())
+            .collect();
+        let name: String = format!("ChiSquared({k}) as a sum of normals");
+        assert_moments(&name, &samples, mean, 2_f64 * mean);
+    }
+}
+
+#[test]
+fn students_t() {
+    for k in [5_f64, 6.5_f64] {
+        for algorithm in [StudentsTAlgorithm::NormalSum, StudentsTAlgorithm::Bailey] {
+            let mut distribution: StudentsT = StudentsT::new(k).unwrap();
+            distribution.set_algorithm(algorithm);
+            let samples: Vec<f64> = sample(&mut distribution);
+            let name: String = format!("StudentsT({k}) with {:?}", distribution.algorithm());
+            assert_moments(&name, &samples, 0_f64, k / (k - 2_f64));
+        }
+    }
+}
+
+#[test]
+fn fisher() {
+    for (m, n) in [(5_f64, 10_f64), (4.5_f64, 12.5_f64), (1_000_f64, 1_000_f64)] {
+        let samples: Vec<f64> = sample(&mut Fisher::new(m, n).unwrap());
+        let mean: f64 = n / (n - 2_f64);
+        let variance: f64 =
+            2_f64 * n * n * (m + n - 2_f64) / (m * (n - 2_f64).powi(2) * (n - 4_f64));
+        assert_moments(&format!("Fisher({m}, {n})"), &samples, mean, variance);
+    }
+}
+
+#[test]
+fn loggamma() {
+    // E[X^k] = (1 - k θ)^(-α)
+    for (shape, scale) in [(2_i32, 0.2_f64), (800_i32, 0.001_f64)] {
+        let mean: f64 = (1_f64 - scale).powi(-shape);
+        let variance: f64 = (1_f64 - 2_f64 * scale).powi(-shape) - mean * mean;
+        let samples: Vec<f64> = sample(&mut LogGamma::new(shape, scale).unwrap());
+        let name: String = format!("LogGamma({shape}, {scale})");
+        assert_moments(&name, &samples, mean, variance);
+    }
+}
+
+#[test]
+fn nakagami() {
+    // Γ(5/2) / Γ(2) sqrt(Ω / m)
+    let mean: f64 = 0.75_f64 * PI.sqrt() * 0.5_f64.sqrt();
+    let samples: Vec<f64> = sample(&mut Nakagami::new(2_f64, 1_f64).unwrap());
+    assert_moments("Nakagami", &samples, mean, 1_f64 - mean * mean);
+}
+
+#[test]
+fn rice() {
+    // The squared values are non-central ChiSquared distributed with E[X²] = 2σ² + ν² and Var[X²] = 4σ²(σ² + ν²)
+    let squares: Vec<f64> = sample(&mut Rice::new(1_f64, 1_f64).unwrap())
+        .iter()
+        .map(|x| x * x)
+        .collect();
+    assert_moments("Rice", &squares, 3_f64, 8_f64);
+}
+
+#[test]
+fn stable() {
+    // α = 2 is the Normal distribution with variance 2σ²
+    let samples: Vec<f64> = sample(&mut Stable::new(2_f64, 0_f64, 1_f64, 0_f64).unwrap());
+    assert_moments("Stable", &samples, 0_f64, 2_f64);
+}
+
+#[test]
+fn normal_tail() {
+    // φ(t) / (1 - Φ(t)) and 1 + t m - m² for t = 1
+    let mean: f64 = 1.525_135_276_160_981_f64;
+    let variance: f64 = 1_f64 + mean - mean * mean;
+    let samples: Vec<f64> = sample(&mut NormalTail::new(1_f64).unwrap());
+    assert_moments("NormalTail", &samples, mean, variance);
+
+    // For a large threshold t (X - t) is asymptotically Exponential with rate 1
+    let threshold: f64 = 1e4_f64;
+    let excess: Vec<f64> = sample(&mut NormalTail::new(threshold).unwrap())
+        .into_iter()
+        .map(|x| threshold * (x - threshold))
+        .collect();
+    assert_moments("NormalTail with t = 1e4", &excess, 1_f64, 1_f64);
+
+    // The square of the threshold overflows, the excess is below the spacing of the floating point numbers
+    let threshold: f64 = 1e160_f64;
+    let samples: Vec<f64> = sample(&mut NormalTail::new(threshold).unwrap());
+    assert!(samples.iter().all(|&x| x > threshold && x.is_finite()));
+}
+
+#[test]
+fn bernoulli() {
+    let samples: Vec<f64> = sample(&mut Bernoulli::new(0.3_f64).unwrap());
+    assert_moments("Bernoulli", &samples, 0.3_f64, 0.21_f64);
+
+    // The bits of a fair coin come from the raw 64-bit output
+    // Their sample variance is always close to 1/4, so only the mean is compared
+    let mut coin: Bernoulli = Bernoulli::coin();
+    coin.set_seed(SEED);
+    let ones: usize = coin
+        .bits(SAMPLE_SIZE)
+        .into_iter()
+        .filter(|&bit| bit)
+        .count();
+    let frequency: f64 = ones as f64 / SAMPLE_SIZE as f64;
+    let mean_error: f64 = (0.25_f64 / SAMPLE_SIZE as f64).sqrt();
+    assert!(
+        (frequency - 0.5_f64).abs() <= MAX_STANDARD_ERRORS * mean_error,
+        "Bernoulli bits: frequency {frequency} differs from 0.5"
+    );
+}
+
+#[test]
+fn two_point() {
+    // a + (b - a) Bernoulli(p)
+    let samples: Vec<f64> = sample(&mut TwoPoint::new(-1_f64, 2_f64, 0.4_f64).unwrap());
+    assert_moments("TwoPoint", &samples, 0.2_f64, 9_f64 * 0.24_f64);
+}
+
+#[test]
+fn degenerate() {
+    let samples: Vec<f64> = sample(&mut Degenerate::new(2.5_f64).unwrap());
+    assert!(samples.iter().all(|&x| x == 2.5_f64));
+}
+
+#[test]
+fn binomial() {
+    for (n, p) in [(20_i32, 0.3_f64), (100_i32, 0.4_f64)] {
+        for algorithm in [
+            BinomialAlgorithm::Table,
+            BinomialAlgorithm::Inversion,
+            BinomialAlgorithm::Btpe,
+        ] {
+            let mut distribution: Binomial = Binomial::new(n, p).unwrap();
+            distribution.set_algorithm(algorithm);
+            let samples: Vec<f64> = sample(&mut distribution);
+            let name: String = format!("Binomial({n}, {p}) with {algorithm:?}");
+            assert_moments(&name, &samples, n as f64 * p, n as f64 * p * (1_f64 - p));
+        }
+    }
+}
+
+#[test]
+fn poisson() {
+    for rate in [4_f64, 50_f64] {
+        for algorithm in [
+            PoissonAlgorithm::Inversion,
+            PoissonAlgorithm::Ptrs,
+            PoissonAlgorithm::Table,
+        ] {
+            let mut distribution: Poisson = Poisson::new(rate).unwrap();
+            distribution.set_algorithm(algorithm);
+            let samples: Vec<f64> = sample(&mut distribution);
+            let name: String = format!("Poisson({rate}) with {algorithm:?}");
+            assert_moments(&name, &samples, rate, rate);
+        }
+    }
+}
+
+#[test]
+fn binomial_crossover() {
+    // n min(p, 1 - p) right below, at and right above the crossover of 30, also mirrored for p > 0.5
+    for (p, automatic) in [
+        (0.29_f64, BinomialAlgorithm::Table),
+        (0.3_f64, BinomialAlgorithm::Btpe),
+        (0.31_f64, BinomialAlgorithm::Btpe),
+        (0.7_f64, BinomialAlgorithm::Btpe),
+    ] {
+        let n: i32 = 100_i32;
+        let mut distribution: Binomial = Binomial::new(n, p).unwrap();
+        assert_eq!(distribution.algorithm(), automatic, "Binomial({n}, {p})");
+
+        for algorithm in [
+            BinomialAlgorithm::Table,
+            BinomialAlgorithm::Inversion,
+            BinomialAlgorithm::Btpe,
+        ] {
+            distribution.set_algorithm(algorithm);
+            let samples: Vec<f64> = sample(&mut distribution);
+            let name: String = format!("Binomial({n}, {p}) with {algorithm:?}");
+            assert_moments(&name, &samples, n as f64 * p, n as f64 * p * (1_f64 - p));
+            assert_discrete_ks(&name, &samples, &distribution);
+        }
+    }
+}
+
+#[test]
+fn binomial_large_n() {
+    // Below the crossover a huge n is sampled by inversion instead of a table with n + 1 entries
+    let n: i32 = 100_000_000_i32;
+    for p in [1e-8_f64, 1e-7_f64, 1_f64 - 1e-7_f64] {
+        let mut distribution: Binomial = Binomial::new(n, p).unwrap();
+        assert_eq!(
+            distribution.algorithm(),
+            BinomialAlgorithm::Inversion,
+            "Binomial({n}, {p})"
+        );
+
+        let samples: Vec<f64> = sample(&mut distribution);
+        let name: String = format!("Binomial({n}, {p})");
+        assert_moments(&name, &samples, n as f64 * p, n as f64 * p * (1_f64 - p));
+
+        // The distribution function is summed from 0, so the failures are compared for p > 0.5
+        if p > 0.5_f64 {
+            let failures: Vec<f64> = samples.iter().map(|&x| n as f64 - x).collect();
+            let mirrored: Binomial = Binomial::new(n, 1_f64 - p).unwrap();
+            assert_discrete_ks(&name, &failures, &mirrored);
+        } else {
+            assert_discrete_ks(&name, &samples, &distribution);
+        }
+    }
+
+    // The table is kept right below the limit
+    let distribution: Binomial = Binomial::new(Binomial::TABLE_LIMIT - 1_i32, 0.01_f64).unwrap();
+    assert_eq!(distribution.algorithm(), BinomialAlgorithm::Table);
+}
+
+#[test]
+fn poisson_crossover() {
+    // PTRS is only valid from the crossover on, below it falls back to the inversion
+    for rate in [9.5_f64, Poisson::CROSSOVER, 10.5_f64] {
+        let mut distribution: Poisson = Poisson::new(rate).unwrap();
+        let automatic: PoissonAlgorithm = if rate < Poisson::CROSSOVER {
+            PoissonAlgorithm::Inversion
+        } else {
+            PoissonAlgorithm::Ptrs
+        };
+        assert_eq!(distribution.algorithm(), automatic, "Poisson({rate})");
+
+        for algorithm in [
+            PoissonAlgorithm::Inversion,
+            PoissonAlgorithm::Ptrs,
+            PoissonAlgorithm::Table,
+        ] {
+            distribution.set_algorithm(algorithm);
+            let samples: Vec<f64> = sample(&mut distribution);
+            let name: String = format!("Poisson({rate}) with {algorithm:?}");
+            assert_moments(&name, &samples, rate, rate);
+            assert_discrete_ks(&name, &samples, &distribution);
+        }
+    }
+}
+
+#[test]
+fn geometric() {
+    // The number of trials up to and including the first success
+    let mut geometric: Geometric = Geometric::new(0.25_f64).unwrap();
+    let samples: Vec<f64> = sample(&mut geometric);
+    assert_moments("Geometric", &samples, 4_f64, 12_f64);
+
+    // The number of failures before the first success
+    geometric.set_convention(GeometricConvention::Failures);
+    let samples: Vec<f64> = sample(&mut geometric);
+    assert_moments("Geometric failures", &samples, 3_f64, 12_f64);
+
+    // A small probability, where `1 - p` is not exact
+    let samples: Vec<f64> = sample(&mut Geometric::new(1e-6_f64).unwrap());
+    assert_moments(
+        "Geometric small p",
+        &samples,
+        1e6_f64,
+        (1_f64 - 1e-6_f64) * 1e12_f64,
+    );
+
+    // The first trial is always a success
+    let samples: Vec<f64> = sample(&mut Geometric::new(1_f64).unwrap());
+    assert!(samples.iter().all(|&x| x == 1_f64), "Geometric p = 1");
+}
+
+#[test]
+fn beta_binomial() {
+    let samples: Vec<f64> = sample(&mut BetaBinomial::new(20_i32, 2_f64, 3_f64).unwrap());
+    assert_moments("BetaBinomial", &samples, 8_f64, 20_f64);
+}
+
+#[test]
+fn gamma_poisson() {
+    let samples: Vec<f64> = sample(&mut GammaPoisson::new(2.5_f64, 0.5_f64).unwrap());
+    assert_moments("GammaPoisson", &samples, 5_f64, 15_f64);
+}
+
+#[test]
+fn categorical() {
+    let samples: Vec<f64> = sample(&mut Categorical::new(vec![0.2_f64, 0.5_f64, 0.3_f64]).unwrap());
+    assert_moments("Categorical", &samples, 1.1_f64, 0.49_f64);
+}
+
+#[test]
+fn exact_discrete() {
+    let samples: Vec<f64> = sample(&mut ExactDiscrete::new(&[1_u64, 2_u64, 3_u64]).unwrap());
+    assert_moments("ExactDiscrete", &samples, 4_f64 / 3_f64, 5_f64 / 9_f64);
+}
+
+#[test]
+fn discrete_uniform() {
+    let samples: Vec<f64> = sample(&mut DiscreteUniform::new(0_f64, 1_f64, 0.25_f64).unwrap());
+    assert_moments("DiscreteUniform", &samples, 0.5_f64, 0.125_f64);
+}